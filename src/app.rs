@@ -1,14 +1,12 @@
 use std::{
     cmp::{max, min},
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    fs,
     // io::Write,
     path::PathBuf,
     time::Instant,
 };
 
-use unicode_segmentation::GraphemeCursor;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
-
 use typed_builder::*;
 
 use data_farmer::*;
@@ -18,6 +16,10 @@ pub use states::*;
 
 use crate::{
     canvas, constants,
+    options::layout_options::{
+        get_sizes_mut, get_widget_mut, get_widget_positions, Row, WidgetPosition,
+    },
+    options::replace_layout_in_config_text,
     options::Config,
     options::ConfigFlags,
     options::WidgetIdEnabled,
@@ -35,7 +37,7 @@ pub mod states;
 
 const MAX_SEARCH_LENGTH: usize = 200;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AxisScaling {
     Log,
     Linear,
@@ -47,8 +49,6 @@ pub enum AxisScaling {
 pub struct AppConfigFields {
     pub update_rate_in_milliseconds: u64,
     pub temperature_type: temperature::TemperatureType,
-    pub use_dot: bool,
-    pub left_legend: bool,
     pub show_average_cpu: bool,
     pub use_current_cpu_total: bool,
     pub use_basic_mode: bool,
@@ -66,6 +66,10 @@ pub struct AppConfigFields {
     pub network_unit_type: DataUnit,
     pub network_scale_type: AxisScaling,
     pub network_use_binary_prefix: bool,
+    pub normalize_load_avg: bool,
+    pub cpu_grouping: CpuGrouping,
+    pub extra_filesystems: data_harvester::disks::ExtraFilesystems,
+    pub disk_history_window: u64,
 }
 
 /// For filtering out information
@@ -74,6 +78,7 @@ pub struct DataFilters {
     pub disk_filter: Option<Filter>,
     pub mount_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
+    pub sensor_filter: Option<Filter>,
     pub net_filter: Option<Filter>,
 }
 
@@ -81,6 +86,29 @@ pub struct DataFilters {
 pub struct Filter {
     pub is_list_ignored: bool,
     pub list: Vec<regex::Regex>,
+    pub field: FilterField,
+}
+
+impl Filter {
+    /// Returns whether an entry with the given value should be kept.  A denylist keeps
+    /// everything that doesn't match, while an allowlist only keeps what does match.
+    pub fn keep_entry(&self, value: &str) -> bool {
+        self.is_list_ignored != self.list.iter().any(|r| r.is_match(value))
+    }
+}
+
+/// Which part of an entry a [`Filter`] matches against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FilterField {
+    /// The entry's name, i.e. the disk name, mount point, sensor, or interface name.
+    #[default]
+    Name,
+    /// The filesystem type of a disk, such as "ext4" or "tmpfs".
+    FileSystem,
+    /// The comma-separated options a disk is mounted with, such as "rw,relatime".
+    MountOptions,
+    /// The type of a network interface, one of "loopback", "virtual", or "physical".
+    InterfaceType,
 }
 
 #[derive(TypedBuilder)]
@@ -133,6 +161,13 @@ pub struct App {
     #[builder(default = false, setter(skip))]
     pub did_config_fail_to_save: bool,
 
+    #[builder(default, setter(skip))]
+    pub layout_edit_state: LayoutEditState,
+
+    /// The widgets in the current layout that were hidden to make room for others.
+    #[builder(default, setter(skip))]
+    pub hidden_widget_ids: HashSet<u64>,
+
     #[cfg(target_family = "unix")]
     #[builder(default, setter(skip))]
     pub user_table: processes::UserTable,
@@ -142,14 +177,19 @@ pub struct App {
     pub net_state: NetState,
     pub proc_state: ProcState,
     pub temp_state: TempState,
+    pub temp_graph_state: TempGraphState,
+    pub sensor_state: SensorState,
     pub disk_state: DiskState,
     pub battery_state: BatteryState,
+    pub status_bar_state: StatusBarState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
+    pub layout_tab_state: LayoutTabState,
     pub app_config_fields: AppConfigFields,
     pub widget_map: HashMap<u64, BottomWidget>,
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
     pub filters: DataFilters,
+    pub saved_filters: Vec<SavedFilter>,
     pub config: Config,
     pub config_path: Option<PathBuf>,
 }
@@ -190,6 +230,7 @@ impl App {
         self.reset_cpu_zoom();
         self.reset_mem_zoom();
         self.reset_net_zoom();
+        self.reset_temp_graph_zoom();
 
         // Reset data
         self.data_collection.reset();
@@ -221,7 +262,7 @@ impl App {
         } else if self.is_config_open {
             self.close_config_screen();
         } else {
-            match self.current_widget.widget_type {
+            match self.current_widget.widget_type.clone() {
                 BottomWidgetType::Proc => {
                     if let Some(current_proc_state) = self
                         .proc_state
@@ -255,6 +296,15 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Temp | BottomWidgetType::Disk
+                    if self.edit_table_search(|search_state| {
+                        search_state.search_state.is_enabled = false;
+                        false
+                    }) =>
+                {
+                    self.is_force_redraw = true;
+                    return;
+                }
                 BottomWidgetType::ProcSort => {
                     if let Some(current_proc_state) = self
                         .proc_state
@@ -281,10 +331,84 @@ impl App {
     }
 
     pub fn is_in_search_widget(&self) -> bool {
-        matches!(
-            self.current_widget.widget_type,
-            BottomWidgetType::ProcSearch
-        )
+        match self.current_widget.widget_type {
+            BottomWidgetType::ProcSearch => true,
+            BottomWidgetType::Temp | BottomWidgetType::Disk => {
+                self.get_table_search_state().is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the search state of the currently selected temperature or disk widget, if its search is open.
+    fn get_table_search_state(&self) -> Option<&TableSearchState> {
+        let search_state = match self.current_widget.widget_type {
+            BottomWidgetType::Temp => self
+                .temp_state
+                .get_widget_state(self.current_widget.widget_id)
+                .map(|temp_widget_state| &temp_widget_state.search_state),
+            BottomWidgetType::Disk => self
+                .disk_state
+                .get_widget_state(self.current_widget.widget_id)
+                .map(|disk_widget_state| &disk_widget_state.search_state),
+            _ => None,
+        };
+
+        search_state.filter(|search_state| search_state.is_search_enabled())
+    }
+
+    /// Applies `edit` to the open search of the currently selected temperature or disk widget, and
+    /// updates the table if it returns true.  Returns whether there was an open search to edit.
+    fn edit_table_search(&mut self, edit: impl FnOnce(&mut TableSearchState) -> bool) -> bool {
+        let widget_id = self.current_widget.widget_id;
+        match self.current_widget.widget_type {
+            BottomWidgetType::Temp => {
+                if let Some(temp_widget_state) = self.temp_state.get_mut_widget_state(widget_id) {
+                    if temp_widget_state.search_state.is_search_enabled() {
+                        if edit(&mut temp_widget_state.search_state) {
+                            temp_widget_state.update_query();
+                            self.temp_state.force_update = Some(widget_id);
+                        }
+                        return true;
+                    }
+                }
+            }
+            BottomWidgetType::Disk => {
+                if let Some(disk_widget_state) = self.disk_state.get_mut_widget_state(widget_id) {
+                    if disk_widget_state.search_state.is_search_enabled() {
+                        if edit(&mut disk_widget_state.search_state) {
+                            disk_widget_state.update_query();
+                            self.disk_state.force_update = Some(widget_id);
+                        }
+                        return true;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Applies `edit` to the open search of the currently selected process, temperature, or disk
+    /// widget, and updates the widget if it returns true.  Returns whether there was an open
+    /// search to edit.
+    fn edit_search(&mut self, edit: impl FnOnce(&mut AppSearchState) -> bool) -> bool {
+        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
+            let proc_widget_id = self.current_widget.widget_id - 1;
+            if let Some(proc_widget_state) = self.proc_state.get_mut_widget_state(proc_widget_id) {
+                if proc_widget_state.is_search_enabled() {
+                    if edit(&mut proc_widget_state.process_search_state.search_state) {
+                        proc_widget_state.update_query();
+                        self.proc_state.force_update = Some(proc_widget_id);
+                    }
+                    return true;
+                }
+            }
+            false
+        } else {
+            self.edit_table_search(|search_state| edit(&mut search_state.search_state))
+        }
     }
 
     fn reset_multi_tap_keys(&mut self) {
@@ -403,6 +527,24 @@ impl App {
                         self.is_force_redraw = true;
                     }
                 }
+                BottomWidgetType::Temp => {
+                    if let Some(temp_widget_state) = self
+                        .temp_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        temp_widget_state.search_state.search_state.is_enabled = true;
+                        self.is_force_redraw = true;
+                    }
+                }
+                BottomWidgetType::Disk => {
+                    if let Some(disk_widget_state) = self
+                        .disk_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        disk_widget_state.search_state.search_state.is_enabled = true;
+                        self.is_force_redraw = true;
+                    }
+                }
                 _ => {}
             }
         }
@@ -439,6 +581,27 @@ impl App {
 
                 self.is_force_redraw = true;
             }
+            BottomWidgetType::Temp => {
+                if let Some(temp_widget_state) = self
+                    .temp_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    temp_widget_state
+                        .sort_state
+                        .cycle_sort_index(constants::TEMP_HEADERS.len());
+                    self.temp_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
+            BottomWidgetType::Disk => {
+                if let Some(disk_widget_state) = self
+                    .disk_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    let num_columns = disk_widget_state.columns.len();
+                    disk_widget_state.sort_state.cycle_sort_index(num_columns);
+                    self.disk_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
             _ => {}
         }
     }
@@ -460,6 +623,26 @@ impl App {
                     self.proc_state.force_update = Some(widget_id);
                 }
             }
+            BottomWidgetType::Temp => {
+                if let Some(temp_widget_state) = self
+                    .temp_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    temp_widget_state.sort_state.is_sort_descending =
+                        !temp_widget_state.sort_state.is_sort_descending;
+                    self.temp_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
+            BottomWidgetType::Disk => {
+                if let Some(disk_widget_state) = self
+                    .disk_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    disk_widget_state.sort_state.is_sort_descending =
+                        !disk_widget_state.sort_state.is_sort_descending;
+                    self.disk_state.force_update = Some(self.current_widget.widget_id);
+                }
+            }
             _ => {}
         }
     }
@@ -508,7 +691,14 @@ impl App {
     pub fn toggle_ignore_case(&mut self) {
         let is_in_search_widget = self.is_in_search_widget();
         let mut is_case_sensitive: Option<bool> = None;
-        if let Some(proc_widget_state) = self
+        let mut search_widget_id = self.current_widget.widget_id - 1;
+        if self.edit_table_search(|search_state| {
+            search_state.search_toggle_ignore_case();
+            is_case_sensitive = Some(!search_state.is_ignoring_case);
+            true
+        }) {
+            search_widget_id = self.current_widget.widget_id;
+        } else if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
@@ -530,7 +720,7 @@ impl App {
             if let Some(flags) = &mut self.config.flags {
                 if let Some(map) = &mut flags.search_case_enabled_widgets_map {
                     // Just update the map.
-                    let mapping = map.entry(self.current_widget.widget_id - 1).or_default();
+                    let mapping = map.entry(search_widget_id).or_default();
                     *mapping = is_ignoring_case;
 
                     flags.search_case_enabled_widgets =
@@ -538,7 +728,7 @@ impl App {
                 } else {
                     // Map doesn't exist yet... initialize ourselves.
                     let mut map = HashMap::default();
-                    map.insert(self.current_widget.widget_id - 1, is_ignoring_case);
+                    map.insert(search_widget_id, is_ignoring_case);
                     flags.search_case_enabled_widgets =
                         Some(WidgetIdEnabled::create_from_hashmap(&map));
                     flags.search_case_enabled_widgets_map = Some(map);
@@ -546,7 +736,7 @@ impl App {
             } else {
                 // Must initialize it ourselves...
                 let mut map = HashMap::default();
-                map.insert(self.current_widget.widget_id - 1, is_ignoring_case);
+                map.insert(search_widget_id, is_ignoring_case);
 
                 self.config.flags = Some(
                     ConfigFlags::builder()
@@ -563,7 +753,14 @@ impl App {
     pub fn toggle_search_whole_word(&mut self) {
         let is_in_search_widget = self.is_in_search_widget();
         let mut is_searching_whole_word: Option<bool> = None;
-        if let Some(proc_widget_state) = self
+        let mut search_widget_id = self.current_widget.widget_id - 1;
+        if self.edit_table_search(|search_state| {
+            search_state.search_toggle_whole_word();
+            is_searching_whole_word = Some(search_state.is_searching_whole_word);
+            true
+        }) {
+            search_widget_id = self.current_widget.widget_id;
+        } else if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
@@ -588,7 +785,7 @@ impl App {
             if let Some(flags) = &mut self.config.flags {
                 if let Some(map) = &mut flags.search_whole_word_enabled_widgets_map {
                     // Just update the map.
-                    let mapping = map.entry(self.current_widget.widget_id - 1).or_default();
+                    let mapping = map.entry(search_widget_id).or_default();
                     *mapping = is_searching_whole_word;
 
                     flags.search_whole_word_enabled_widgets =
@@ -596,7 +793,7 @@ impl App {
                 } else {
                     // Map doesn't exist yet... initialize ourselves.
                     let mut map = HashMap::default();
-                    map.insert(self.current_widget.widget_id - 1, is_searching_whole_word);
+                    map.insert(search_widget_id, is_searching_whole_word);
                     flags.search_whole_word_enabled_widgets =
                         Some(WidgetIdEnabled::create_from_hashmap(&map));
                     flags.search_whole_word_enabled_widgets_map = Some(map);
@@ -604,7 +801,7 @@ impl App {
             } else {
                 // Must initialize it ourselves...
                 let mut map = HashMap::default();
-                map.insert(self.current_widget.widget_id - 1, is_searching_whole_word);
+                map.insert(search_widget_id, is_searching_whole_word);
 
                 self.config.flags = Some(
                     ConfigFlags::builder()
@@ -623,7 +820,14 @@ impl App {
     pub fn toggle_search_regex(&mut self) {
         let is_in_search_widget = self.is_in_search_widget();
         let mut is_searching_with_regex: Option<bool> = None;
-        if let Some(proc_widget_state) = self
+        let mut search_widget_id = self.current_widget.widget_id - 1;
+        if self.edit_table_search(|search_state| {
+            search_state.search_toggle_regex();
+            is_searching_with_regex = Some(search_state.is_searching_with_regex);
+            true
+        }) {
+            search_widget_id = self.current_widget.widget_id;
+        } else if let Some(proc_widget_state) = self
             .proc_state
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
@@ -646,7 +850,7 @@ impl App {
             if let Some(flags) = &mut self.config.flags {
                 if let Some(map) = &mut flags.search_regex_enabled_widgets_map {
                     // Just update the map.
-                    let mapping = map.entry(self.current_widget.widget_id - 1).or_default();
+                    let mapping = map.entry(search_widget_id).or_default();
                     *mapping = is_searching_whole_word;
 
                     flags.search_regex_enabled_widgets =
@@ -654,7 +858,7 @@ impl App {
                 } else {
                     // Map doesn't exist yet... initialize ourselves.
                     let mut map = HashMap::default();
-                    map.insert(self.current_widget.widget_id - 1, is_searching_whole_word);
+                    map.insert(search_widget_id, is_searching_whole_word);
                     flags.search_regex_enabled_widgets =
                         Some(WidgetIdEnabled::create_from_hashmap(&map));
                     flags.search_regex_enabled_widgets_map = Some(map);
@@ -662,7 +866,7 @@ impl App {
             } else {
                 // Must initialize it ourselves...
                 let mut map = HashMap::default();
-                map.insert(self.current_widget.widget_id - 1, is_searching_whole_word);
+                map.insert(search_widget_id, is_searching_whole_word);
 
                 self.config.flags = Some(
                     ConfigFlags::builder()
@@ -758,113 +962,11 @@ impl App {
     }
 
     pub fn on_delete(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            let is_in_search_widget = self.is_in_search_widget();
-            if let Some(proc_widget_state) = self
-                .proc_state
-                .widget_states
-                .get_mut(&(self.current_widget.widget_id - 1))
-            {
-                if is_in_search_widget {
-                    if proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .is_enabled
-                        && proc_widget_state.get_search_cursor_position()
-                            < proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len()
-                    {
-                        let current_cursor = proc_widget_state.get_search_cursor_position();
-                        proc_widget_state
-                            .search_walk_forward(proc_widget_state.get_search_cursor_position());
-
-                        let _removed_chars: String = proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .current_search_query
-                            .drain(current_cursor..proc_widget_state.get_search_cursor_position())
-                            .collect();
-
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .grapheme_cursor = GraphemeCursor::new(
-                            current_cursor,
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            true,
-                        );
-
-                        proc_widget_state.update_query();
-                        self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-                    }
-                } else {
-                    self.start_killing_process()
-                }
-            }
-        }
+        self.edit_search(|search_state| search_state.delete_forward());
     }
 
     pub fn on_backspace(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            let is_in_search_widget = self.is_in_search_widget();
-            if let Some(proc_widget_state) = self
-                .proc_state
-                .widget_states
-                .get_mut(&(self.current_widget.widget_id - 1))
-            {
-                if is_in_search_widget
-                    && proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .is_enabled
-                    && proc_widget_state.get_search_cursor_position() > 0
-                {
-                    let current_cursor = proc_widget_state.get_search_cursor_position();
-                    proc_widget_state
-                        .search_walk_back(proc_widget_state.get_search_cursor_position());
-
-                    let removed_chars: String = proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .current_search_query
-                        .drain(proc_widget_state.get_search_cursor_position()..current_cursor)
-                        .collect();
-
-                    proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .grapheme_cursor = GraphemeCursor::new(
-                        proc_widget_state.get_search_cursor_position(),
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .current_search_query
-                            .len(),
-                        true,
-                    );
-
-                    proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .char_cursor_position -= UnicodeWidthStr::width(removed_chars.as_str());
-
-                    proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .cursor_direction = CursorDirection::Left;
-
-                    proc_widget_state.update_query();
-                    self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-                }
-            }
-        }
+        self.edit_search(|search_state| search_state.delete_backward());
     }
 
     pub fn get_process_filter(&self, widget_id: u64) -> &Option<query::Query> {
@@ -957,33 +1059,11 @@ impl App {
         if self.is_config_open {
         } else if !self.is_in_dialog() {
             match self.current_widget.widget_type {
-                BottomWidgetType::ProcSearch => {
-                    let is_in_search_widget = self.is_in_search_widget();
-                    if let Some(proc_widget_state) = self
-                        .proc_state
-                        .get_mut_widget_state(self.current_widget.widget_id - 1)
-                    {
-                        if is_in_search_widget {
-                            let prev_cursor = proc_widget_state.get_search_cursor_position();
-                            proc_widget_state
-                                .search_walk_back(proc_widget_state.get_search_cursor_position());
-                            if proc_widget_state.get_search_cursor_position() < prev_cursor {
-                                let str_slice = &proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .current_search_query
-                                    [proc_widget_state.get_search_cursor_position()..prev_cursor];
-                                proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .char_cursor_position -= UnicodeWidthStr::width(str_slice);
-                                proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .cursor_direction = CursorDirection::Left;
-                            }
-                        }
-                    }
+                BottomWidgetType::ProcSearch | BottomWidgetType::Temp | BottomWidgetType::Disk => {
+                    self.edit_search(|search_state| {
+                        search_state.move_cursor_left();
+                        false
+                    });
                 }
                 BottomWidgetType::Battery => {
                     if !self.canvas_data.battery_data.is_empty() {
@@ -1029,34 +1109,11 @@ impl App {
         if self.is_config_open {
         } else if !self.is_in_dialog() {
             match self.current_widget.widget_type {
-                BottomWidgetType::ProcSearch => {
-                    let is_in_search_widget = self.is_in_search_widget();
-                    if let Some(proc_widget_state) = self
-                        .proc_state
-                        .get_mut_widget_state(self.current_widget.widget_id - 1)
-                    {
-                        if is_in_search_widget {
-                            let prev_cursor = proc_widget_state.get_search_cursor_position();
-                            proc_widget_state.search_walk_forward(
-                                proc_widget_state.get_search_cursor_position(),
-                            );
-                            if proc_widget_state.get_search_cursor_position() > prev_cursor {
-                                let str_slice = &proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .current_search_query
-                                    [prev_cursor..proc_widget_state.get_search_cursor_position()];
-                                proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .char_cursor_position += UnicodeWidthStr::width(str_slice);
-                                proc_widget_state
-                                    .process_search_state
-                                    .search_state
-                                    .cursor_direction = CursorDirection::Right;
-                            }
-                        }
-                    }
+                BottomWidgetType::ProcSearch | BottomWidgetType::Temp | BottomWidgetType::Disk => {
+                    self.edit_search(|search_state| {
+                        search_state.move_cursor_right();
+                        false
+                    });
                 }
                 BottomWidgetType::Battery => {
                     if !self.canvas_data.battery_data.is_empty() {
@@ -1132,175 +1189,33 @@ impl App {
 
     pub fn skip_cursor_beginning(&mut self) {
         if !self.ignore_normal_keybinds() {
-            if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
-                if let Some(proc_widget_state) = self
-                    .proc_state
-                    .widget_states
-                    .get_mut(&(self.current_widget.widget_id - 1))
-                {
-                    if is_in_search_widget {
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .grapheme_cursor = GraphemeCursor::new(
-                            0,
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            true,
-                        );
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .char_cursor_position = 0;
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .cursor_direction = CursorDirection::Left;
-                    }
-                }
-            }
+            self.edit_search(|search_state| {
+                search_state.move_cursor_to_start();
+                false
+            });
         } else if self.is_config_open {
         }
     }
 
     pub fn skip_cursor_end(&mut self) {
         if !self.ignore_normal_keybinds() {
-            if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
-                if let Some(proc_widget_state) = self
-                    .proc_state
-                    .widget_states
-                    .get_mut(&(self.current_widget.widget_id - 1))
-                {
-                    if is_in_search_widget {
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .grapheme_cursor = GraphemeCursor::new(
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            true,
-                        );
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .char_cursor_position = UnicodeWidthStr::width(
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .as_str(),
-                        );
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .cursor_direction = CursorDirection::Right;
-                    }
-                }
-            }
+            self.edit_search(|search_state| {
+                search_state.move_cursor_to_end();
+                false
+            });
         } else if self.is_config_open {
         }
     }
 
     pub fn clear_search(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            if let Some(proc_widget_state) = self
-                .proc_state
-                .widget_states
-                .get_mut(&(self.current_widget.widget_id - 1))
-            {
-                proc_widget_state.clear_search();
-                self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-            }
-        }
+        self.edit_search(|search_state| {
+            search_state.reset();
+            true
+        });
     }
 
     pub fn clear_previous_word(&mut self) {
-        if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-            if let Some(proc_widget_state) = self
-                .proc_state
-                .widget_states
-                .get_mut(&(self.current_widget.widget_id - 1))
-            {
-                // Traverse backwards from the current cursor location until you hit non-whitespace characters,
-                // then continue to traverse (and delete) backwards until you hit a whitespace character.  Halt.
-
-                // So... first, let's get our current cursor position using graphemes...
-                let end_index = proc_widget_state.get_char_cursor_position();
-
-                // Then, let's crawl backwards until we hit our location, and store the "head"...
-                let query = proc_widget_state.get_current_search_query();
-                let mut start_index = 0;
-                let mut saw_non_whitespace = false;
-
-                for (itx, c) in query
-                    .chars()
-                    .rev()
-                    .enumerate()
-                    .skip(query.len() - end_index)
-                {
-                    if c.is_whitespace() {
-                        if saw_non_whitespace {
-                            start_index = query.len() - itx;
-                            break;
-                        }
-                    } else {
-                        saw_non_whitespace = true;
-                    }
-                }
-
-                let removed_chars: String = proc_widget_state
-                    .process_search_state
-                    .search_state
-                    .current_search_query
-                    .drain(start_index..end_index)
-                    .collect();
-
-                proc_widget_state
-                    .process_search_state
-                    .search_state
-                    .grapheme_cursor = GraphemeCursor::new(
-                    start_index,
-                    proc_widget_state
-                        .process_search_state
-                        .search_state
-                        .current_search_query
-                        .len(),
-                    true,
-                );
-
-                proc_widget_state
-                    .process_search_state
-                    .search_state
-                    .char_cursor_position -= UnicodeWidthStr::width(removed_chars.as_str());
-
-                proc_widget_state
-                    .process_search_state
-                    .search_state
-                    .cursor_direction = CursorDirection::Left;
-
-                proc_widget_state.update_query();
-                self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-
-                // Now, convert this range into a String-friendly range and remove it all at once!
-
-                // Now make sure to also update our current cursor positions...
-
-                self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-            }
-        }
+        self.edit_search(|search_state| search_state.delete_previous_word());
     }
 
     pub fn start_killing_process(&mut self) {
@@ -1361,61 +1276,12 @@ impl App {
             }
             self.last_key_press = current_key_press_inst;
 
-            if let BottomWidgetType::ProcSearch = self.current_widget.widget_type {
-                let is_in_search_widget = self.is_in_search_widget();
-                if let Some(proc_widget_state) = self
-                    .proc_state
-                    .widget_states
-                    .get_mut(&(self.current_widget.widget_id - 1))
-                {
-                    if is_in_search_widget
-                        && proc_widget_state.is_search_enabled()
-                        && UnicodeWidthStr::width(
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .as_str(),
-                        ) <= MAX_SEARCH_LENGTH
-                    {
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .current_search_query
-                            .insert(proc_widget_state.get_search_cursor_position(), caught_char);
-
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .grapheme_cursor = GraphemeCursor::new(
-                            proc_widget_state.get_search_cursor_position(),
-                            proc_widget_state
-                                .process_search_state
-                                .search_state
-                                .current_search_query
-                                .len(),
-                            true,
-                        );
-                        proc_widget_state
-                            .search_walk_forward(proc_widget_state.get_search_cursor_position());
-
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .char_cursor_position +=
-                            UnicodeWidthChar::width(caught_char).unwrap_or(0);
-
-                        proc_widget_state.update_query();
-                        self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
-                        proc_widget_state
-                            .process_search_state
-                            .search_state
-                            .cursor_direction = CursorDirection::Right;
-
-                        return;
-                    }
-                }
+            if self.edit_search(|search_state| {
+                search_state.insert_char(caught_char, MAX_SEARCH_LENGTH)
+            }) {
+                return;
             }
+
             self.handle_char(caught_char);
         } else if self.help_dialog_state.is_showing_help {
             match caught_char {
@@ -1535,7 +1401,11 @@ impl App {
                 }
             }
             'm' => {
-                if let BottomWidgetType::Proc = self.current_widget.widget_type {
+                if let BottomWidgetType::Cpu | BottomWidgetType::CpuLegend =
+                    self.current_widget.widget_type
+                {
+                    self.cycle_cpu_graph_mode();
+                } else if let BottomWidgetType::Proc = self.current_widget.widget_type {
                     if let Some(proc_widget_state) = self
                         .proc_state
                         .get_mut_widget_state(self.current_widget.widget_id)
@@ -1618,34 +1488,568 @@ impl App {
                     }
                 }
             }
-            '?' => {
-                self.help_dialog_state.is_showing_help = true;
-                self.is_force_redraw = true;
+            '?' => {
+                self.help_dialog_state.is_showing_help = true;
+                self.is_force_redraw = true;
+            }
+            'H' | 'A' => self.move_widget_selection(&WidgetDirection::Left),
+            'L' | 'D' => self.move_widget_selection(&WidgetDirection::Right),
+            'K' | 'W' => self.move_widget_selection(&WidgetDirection::Up),
+            'J' | 'S' => self.move_widget_selection(&WidgetDirection::Down),
+            't' => self.toggle_tree_mode(),
+            '+' => self.on_plus(),
+            '-' => self.on_minus(),
+            '=' => self.reset_zoom(),
+            'e' => self.toggle_expand_widget(),
+            'E' => self.toggle_layout_edit(),
+            's' => self.toggle_sort(),
+            'I' => self.invert_sort(),
+            'l' => self.toggle_legend(),
+            '[' => self.previous_layout_tab(),
+            ']' => self.next_layout_tab(),
+            '%' => self.toggle_percentages(),
+            ' ' => self.on_space(),
+            _ => {}
+        }
+
+        if let Some(second_char) = self.second_char {
+            if self.awaiting_second_char && caught_char != second_char {
+                self.awaiting_second_char = false;
+            }
+        }
+    }
+
+    pub fn on_space(&mut self) {}
+
+    /// Switches to another tab, showing its first layout until the painter picks whichever fits.
+    fn select_layout_tab(&mut self, tab_index: usize) {
+        if tab_index == self.layout_tab_state.current_tab
+            || tab_index >= self.layout_tab_state.tab_names.len()
+        {
+            return;
+        }
+
+        let first_layout = self.layout_tab_state.get_tab_layouts(tab_index).next();
+        if let Some(layout_index) = first_layout {
+            self.layout_tab_state.current_tab = tab_index;
+            self.select_layout(layout_index);
+        }
+    }
+
+    /// Switches to another layout, selecting whichever widget was last selected in it.
+    pub fn select_layout(&mut self, layout_index: usize) {
+        let layout_tab_state = &mut self.layout_tab_state;
+        if layout_index == layout_tab_state.current_layout
+            || layout_index >= layout_tab_state.layout_tabs.len()
+        {
+            return;
+        }
+
+        layout_tab_state.layout_widget_ids[layout_tab_state.current_layout] =
+            self.current_widget.widget_id;
+        layout_tab_state.current_layout = layout_index;
+
+        if let Some(new_widget) = self
+            .widget_map
+            .get(&layout_tab_state.layout_widget_ids[layout_index])
+        {
+            self.current_widget = new_widget.clone();
+        }
+
+        // The newly shown widgets might not have been brought up to date while hidden.
+        self.proc_state.force_update_all = true;
+        self.is_expanded = false;
+        self.is_force_redraw = true;
+    }
+
+    pub fn previous_layout_tab(&mut self) {
+        let num_tabs = self.layout_tab_state.tab_names.len();
+        if num_tabs > 1 {
+            self.select_layout_tab((self.layout_tab_state.current_tab + num_tabs - 1) % num_tabs);
+        }
+    }
+
+    pub fn next_layout_tab(&mut self) {
+        let num_tabs = self.layout_tab_state.tab_names.len();
+        if num_tabs > 1 {
+            self.select_layout_tab((self.layout_tab_state.current_tab + 1) % num_tabs);
+        }
+    }
+
+    pub fn is_editing_layout(&self) -> bool {
+        self.layout_edit_state.is_editing
+    }
+
+    pub fn toggle_layout_edit(&mut self) {
+        if self.app_config_fields.use_basic_mode {
+            return;
+        }
+
+        let layout_edit_state = &mut self.layout_edit_state;
+        layout_edit_state.is_editing = !layout_edit_state.is_editing;
+        layout_edit_state.drag = None;
+        layout_edit_state.message = None;
+
+        self.is_expanded = false;
+        self.is_force_redraw = true;
+    }
+
+    /// Applies an edit to the rows of the current layout, and then marks the widgets as needing to
+    /// be rebuilt.  The edit is given the position of every widget in the layout and which one is
+    /// selected.  It returns where each widget ended up, with `None` for widgets that were
+    /// replaced, or `None` overall if nothing changed.
+    fn edit_layout(
+        &mut self,
+        edit: impl FnOnce(&mut Vec<Row>, &[WidgetPosition], usize) -> Option<Vec<Option<usize>>>,
+    ) {
+        let current_layout = self.layout_tab_state.current_layout;
+        let current_widget_id = self.current_widget.widget_id;
+        let mut layout_rows = self
+            .config
+            .get_layout_rows_mut(self.used_widgets.use_battery);
+
+        // IDs carry on from one layout to the next, so every layout's positions are needed.
+        let get_all_positions = |layout_rows: &[&mut Vec<Row>]| {
+            let mut iter_id = 0;
+            layout_rows
+                .iter()
+                .map(|rows| get_widget_positions(rows, &mut iter_id))
+                .collect::<Vec<_>>()
+        };
+
+        let old_positions = get_all_positions(&layout_rows);
+        let current_index = match old_positions.get(current_layout).and_then(|positions| {
+            positions
+                .iter()
+                .position(|position| position.widget_ids.contains(&current_widget_id))
+        }) {
+            Some(current_index) => current_index,
+            None => return,
+        };
+
+        let new_indices = match edit(
+            layout_rows[current_layout],
+            &old_positions[current_layout],
+            current_index,
+        ) {
+            Some(new_indices) => new_indices,
+            None => return,
+        };
+        let new_positions = get_all_positions(&layout_rows);
+
+        let mut widget_id_map = HashMap::new();
+        for (layout, (old_layout_positions, new_layout_positions)) in
+            old_positions.iter().zip(&new_positions).enumerate()
+        {
+            for (old_index, old_position) in old_layout_positions.iter().enumerate() {
+                let new_index = if layout == current_layout {
+                    new_indices[old_index]
+                } else {
+                    Some(old_index)
+                };
+
+                if let Some(new_position) =
+                    new_index.and_then(|index| new_layout_positions.get(index))
+                {
+                    widget_id_map.extend(
+                        old_position
+                            .widget_ids
+                            .clone()
+                            .zip(new_position.widget_ids.clone()),
+                    );
+                }
+            }
+        }
+
+        // Follow the selected widget to wherever it went, or select whatever replaced it.
+        let widget_to_select = widget_id_map.get(&current_widget_id).copied().unwrap_or(
+            new_positions[current_layout][current_index]
+                .widget_ids
+                .start,
+        );
+
+        let layout_edit_state = &mut self.layout_edit_state;
+        layout_edit_state.widget_id_map = widget_id_map;
+        layout_edit_state.widget_to_select = Some(widget_to_select);
+        layout_edit_state.needs_rebuild = true;
+        layout_edit_state.message = None;
+    }
+
+    /// Grows the selected widget by moving its right or bottom edge in the given direction.
+    pub fn resize_layout_widget(&mut self, direction: &WidgetDirection) {
+        let (is_vertical, change) = match direction {
+            WidgetDirection::Left => (false, -1),
+            WidgetDirection::Right => (false, 1),
+            WidgetDirection::Up => (true, -1),
+            WidgetDirection::Down => (true, 1),
+        };
+
+        self.edit_layout(|rows, positions, index| {
+            let (mut sizes, own_index) = get_sizes_mut(rows, &positions[index], is_vertical)?;
+            let num_sizes = sizes.len();
+            let (ratio, size) = &mut sizes[own_index];
+
+            // Widgets with a set size have that changed instead of their ratio.
+            let is_changed = if let Some(percentage) = &mut size.percentage {
+                let old_percentage = *percentage;
+                *percentage = (i64::from(*percentage) + change).clamp(1, 100) as u16;
+                *percentage != old_percentage
+            } else if let Some(length) = size
+                .length
+                .as_mut()
+                .or(size.min_length.as_mut())
+                .or(size.max_length.as_mut())
+            {
+                let old_length = *length;
+                *length = max(1, i64::from(*length) + change) as u16;
+                *length != old_length
+            } else {
+                let old_ratio = ratio.unwrap_or(1);
+                let new_ratio = max(1, old_ratio as i64 + change) as u32;
+                **ratio = Some(new_ratio);
+                num_sizes > 1 && new_ratio != old_ratio
+            };
+
+            if is_changed {
+                Some((0..positions.len()).map(Some).collect())
+            } else {
+                None
             }
-            'H' | 'A' => self.move_widget_selection(&WidgetDirection::Left),
-            'L' | 'D' => self.move_widget_selection(&WidgetDirection::Right),
-            'K' | 'W' => self.move_widget_selection(&WidgetDirection::Up),
-            'J' | 'S' => self.move_widget_selection(&WidgetDirection::Down),
-            't' => self.toggle_tree_mode(),
-            '+' => self.on_plus(),
-            '-' => self.on_minus(),
-            '=' => self.reset_zoom(),
-            'e' => self.toggle_expand_widget(),
-            's' => self.toggle_sort(),
-            'I' => self.invert_sort(),
-            '%' => self.toggle_percentages(),
-            ' ' => self.on_space(),
-            _ => {}
+        });
+    }
+
+    /// Swaps the selected widget with its neighbour in the given direction.  Each widget takes on
+    /// the other's size.
+    pub fn swap_layout_widget(&mut self, direction: &WidgetDirection) {
+        // Some widgets have parts next to each other, like the process widget's sort list, so
+        // collect everything in that direction to skip over them.
+        let mut neighbour_ids = Vec::new();
+        let mut widget = &self.current_widget;
+        while let Some(neighbour) = match direction {
+            WidgetDirection::Left => widget.left_neighbour,
+            WidgetDirection::Right => widget.right_neighbour,
+            WidgetDirection::Up => widget.up_neighbour,
+            WidgetDirection::Down => widget.down_neighbour,
         }
+        .and_then(|neighbour_id| self.widget_map.get(&neighbour_id))
+        {
+            if neighbour_ids.contains(&neighbour.widget_id) {
+                break;
+            }
+            neighbour_ids.push(neighbour.widget_id);
+            widget = neighbour;
+        }
+
+        self.edit_layout(|rows, positions, index| {
+            let neighbour_index = neighbour_ids.iter().find_map(|neighbour_id| {
+                positions
+                    .iter()
+                    .position(|position| position.widget_ids.contains(neighbour_id))
+                    .filter(|neighbour_index| *neighbour_index != index)
+            })?;
+
+            let mut widget = get_widget_mut(rows, &positions[index])?.clone();
+            let neighbour = get_widget_mut(rows, &positions[neighbour_index])?;
+            std::mem::swap(&mut widget.ratio, &mut neighbour.ratio);
+            std::mem::swap(&mut widget, neighbour);
+            *get_widget_mut(rows, &positions[index])? = widget;
+
+            let mut new_indices: Vec<Option<usize>> = (0..positions.len()).map(Some).collect();
+            new_indices.swap(index, neighbour_index);
+            Some(new_indices)
+        });
+    }
+
+    /// Replaces the selected widget with the next or previous type of widget.
+    pub fn cycle_layout_widget_type(&mut self, is_forward: bool) {
+        self.edit_layout(|rows, positions, index| {
+            let widget = get_widget_mut(rows, &positions[index])?;
+            let widget_types: Vec<&str> = constants::LAYOUT_EDIT_WIDGET_TYPES
+                .iter()
+                .copied()
+                .filter(|widget_type| widget_type.parse::<BottomWidgetType>().is_ok())
+                .collect();
+            let current_type = widget.widget_type.parse::<BottomWidgetType>().ok();
+            let type_index = widget_types
+                .iter()
+                .position(|widget_type| {
+                    widget_type.parse::<BottomWidgetType>().ok() == current_type
+                })
+                .unwrap_or(0);
+
+            let new_type_index = if is_forward {
+                (type_index + 1) % widget_types.len()
+            } else {
+                (type_index + widget_types.len() - 1) % widget_types.len()
+            };
+            *widget = widget.replace_type(widget_types[new_type_index]);
 
-        if let Some(second_char) = self.second_char {
-            if self.awaiting_second_char && caught_char != second_char {
-                self.awaiting_second_char = false;
+            let mut new_indices: Vec<Option<usize>> = (0..positions.len()).map(Some).collect();
+            new_indices[index] = None;
+            Some(new_indices)
+        });
+    }
+
+    /// Starts dragging a border if the mouse is on the right or bottom border of a widget, or
+    /// otherwise just selects what was clicked.
+    pub fn start_layout_drag(&mut self, x: u16, y: u16) {
+        let dragged_widget = self.widget_map.values().find_map(|widget| {
+            if let (Some((tlc_x, tlc_y)), Some((brc_x, brc_y))) =
+                (widget.top_left_corner, widget.bottom_right_corner)
+            {
+                let is_on_right = x + 1 == brc_x && y >= tlc_y && y < brc_y;
+                let is_on_bottom = y + 1 == brc_y && x >= tlc_x && x < brc_x;
+                if is_on_right || is_on_bottom {
+                    return Some((widget, !is_on_right));
+                }
+            }
+            None
+        });
+
+        match dragged_widget {
+            Some((widget, is_vertical)) => {
+                self.current_widget = widget.clone();
+                self.layout_edit_state.drag = Some(LayoutDrag { is_vertical });
+            }
+            None => self.on_left_mouse_up(x, y),
+        }
+    }
+
+    pub fn stop_layout_drag(&mut self) {
+        self.layout_edit_state.drag = None;
+    }
+
+    /// Resizes the widget being dragged so that its border is under the mouse.
+    pub fn on_layout_drag(&mut self, x: u16, y: u16) {
+        let is_vertical = match &self.layout_edit_state.drag {
+            Some(drag) => drag.is_vertical,
+            None => return,
+        };
+        let widget_bounds: HashMap<u64, ((u16, u16), (u16, u16))> = self
+            .widget_map
+            .iter()
+            .filter_map(|(widget_id, widget)| {
+                Some((
+                    *widget_id,
+                    (widget.top_left_corner?, widget.bottom_right_corner?),
+                ))
+            })
+            .collect();
+
+        self.edit_layout(|rows, positions, index| {
+            // Find the space taken up by the widgets sharing the ratio being changed.
+            let position = &positions[index];
+            let is_same_child =
+                |other: &WidgetPosition| other.row == position.row && other.child == position.child;
+            let is_sharing_col = positions
+                .iter()
+                .filter(|other| is_same_child(other))
+                .count()
+                > 1;
+            let ((tlc_x, tlc_y), (brc_x, brc_y)) = positions
+                .iter()
+                .filter(|other| {
+                    if !is_vertical {
+                        is_same_child(other)
+                    } else if is_sharing_col {
+                        is_same_child(other) && other.col_child == position.col_child
+                    } else {
+                        other.row == position.row
+                    }
+                })
+                .flat_map(|other| other.widget_ids.clone())
+                .filter_map(|widget_id| widget_bounds.get(&widget_id))
+                .fold(None, |bounds, &((tlc_x, tlc_y), (brc_x, brc_y))| {
+                    Some(match bounds {
+                        Some(((min_x, min_y), (max_x, max_y))) => (
+                            (min(min_x, tlc_x), min(min_y, tlc_y)),
+                            (max(max_x, brc_x), max(max_y, brc_y)),
+                        ),
+                        None => ((tlc_x, tlc_y), (brc_x, brc_y)),
+                    })
+                })?;
+            let (start, end, pointer) = if is_vertical {
+                (tlc_y, brc_y, y)
+            } else {
+                (tlc_x, brc_x, x)
+            };
+
+            let (mut sizes, own_index) = get_sizes_mut(rows, position, is_vertical)?;
+            if end <= start {
+                return None;
+            }
+
+            // A fixed length just follows the mouse, while other set sizes are left alone.
+            let own_size = &mut sizes[own_index].1;
+            if let Some(length) = &mut own_size.length {
+                let new_length = max(1, (pointer + 1).saturating_sub(start));
+                if new_length == *length {
+                    return None;
+                }
+                *length = new_length;
+                return Some((0..positions.len()).map(Some).collect());
+            } else if own_size.percentage.is_some()
+                || own_size.min_length.is_some()
+                || own_size.max_length.is_some()
+            {
+                return None;
+            }
+
+            // Fixed sizes don't share the space left over, so only the ratios count.
+            let own_index = sizes[..own_index]
+                .iter()
+                .filter(|(_ratio, size)| !size.is_fixed())
+                .count();
+            let mut ratios: Vec<&mut Option<u32>> = sizes
+                .into_iter()
+                .filter(|(_ratio, size)| !size.is_fixed())
+                .map(|(ratio, _size)| ratio)
+                .collect();
+            let ratio = ratios[own_index].unwrap_or(1);
+            let mut other_ratio =
+                ratios.iter().map(|ratio| ratio.unwrap_or(1)).sum::<u32>() - ratio;
+            if other_ratio == 0 {
+                return None;
+            }
+
+            // Work out the space the parent has from how much this widget takes up, and then the
+            // ratio that gives the size the mouse asks for.
+            let size = f64::from(end - start);
+            let parent_size = size * f64::from(ratio + other_ratio) / f64::from(ratio);
+            let new_size = (f64::from(pointer.saturating_sub(start)) + 1.0)
+                .min(parent_size - 1.0)
+                .max(1.0);
+
+            // Small ratios can only make big jumps in size, so scale them all up first.
+            let scale = if other_ratio < constants::MIN_LAYOUT_DRAG_RATIO {
+                constants::MIN_LAYOUT_DRAG_RATIO / other_ratio + 1
+            } else {
+                1
+            };
+            other_ratio *= scale;
+
+            let new_ratio = max(
+                1,
+                (new_size * f64::from(other_ratio) / (parent_size - new_size)).round() as u32,
+            );
+            if scale == 1 && new_ratio == ratio {
+                return None;
+            }
+            for ratio in &mut ratios {
+                **ratio = Some(ratio.unwrap_or(1) * scale);
+            }
+            *ratios[own_index] = Some(new_ratio);
+
+            Some((0..positions.len()).map(Some).collect())
+        });
+    }
+
+    /// Writes the config, including the edited layout, back to the config file.
+    pub fn save_layout(&mut self) {
+        let result = match &self.config_path {
+            // Only the layout is replaced, so that the rest of the file keeps its comments and
+            // formatting.
+            Some(config_path) => self
+                .config
+                .get_layout_as_string()
+                .and_then(|layout_text| {
+                    let config_text = match fs::read_to_string(config_path) {
+                        Ok(config_text) => config_text,
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+                        Err(err) => return Err(err.into()),
+                    };
+                    Ok(fs::write(
+                        config_path,
+                        replace_layout_in_config_text(&config_text, &layout_text),
+                    )?)
+                })
+                .map(|_| format!("Saved the layout to {}", config_path.display())),
+            None => Err(anyhow::anyhow!("there is no config file to save to")),
+        };
+
+        self.layout_edit_state.message = Some(match result {
+            Ok(message) => message,
+            Err(err) => format!("Failed to save the layout: {}", err),
+        });
+        self.is_force_redraw = true;
+    }
+
+    fn toggle_legend(&mut self) {
+        let graph_widget_id = match self.current_widget.widget_type {
+            BottomWidgetType::TempGraph => self.current_widget.widget_id,
+            BottomWidgetType::TempGraphLegend => {
+                // Move back to the graph, as the legend is about to disappear.
+                if let Some(graph_widget) =
+                    self.widget_map.get(&(self.current_widget.widget_id - 1))
+                {
+                    self.current_widget = graph_widget.clone();
+                }
+                self.current_widget.widget_id
             }
+            _ => return,
+        };
+
+        if let Some(temp_graph_widget_state) =
+            self.temp_graph_state.get_mut_widget_state(graph_widget_id)
+        {
+            temp_graph_widget_state.show_legend = !temp_graph_widget_state.show_legend;
+            self.is_force_redraw = true;
         }
     }
 
-    pub fn on_space(&mut self) {}
+    /// Updates which CPU details are collected to match what the CPU widgets currently show.
+    pub fn update_used_cpu_details(&mut self) {
+        self.used_widgets.use_cpu_frequency =
+            self.cpu_state.is_using_graph_mode(CpuGraphMode::Frequency)
+                || self
+                    .cpu_state
+                    .is_using_legend_column(CpuLegendColumn::Frequency)
+                || self
+                    .cpu_state
+                    .is_using_legend_column(CpuLegendColumn::MinFrequency)
+                || self
+                    .cpu_state
+                    .is_using_legend_column(CpuLegendColumn::MaxFrequency);
+        self.used_widgets.use_cpu_temp = self
+            .cpu_state
+            .is_using_legend_column(CpuLegendColumn::Temperature);
+        self.used_widgets.use_cpu_topology = self.used_widgets.use_cpu
+            && matches!(
+                self.app_config_fields.cpu_grouping,
+                CpuGrouping::Socket | CpuGrouping::NumaNode
+            );
+    }
+
+    /// Sets which histories are kept based on the columns the widgets show.
+    pub fn update_used_histories(&mut self) {
+        self.used_widgets.use_disk_history = self.disk_state.is_using_column(DiskColumn::FillRate)
+            || self.disk_state.is_using_column(DiskColumn::EtaFull)
+            || self.disk_state.is_using_column(DiskColumn::History);
+        self.used_widgets.use_proc_history = self
+            .proc_state
+            .is_using_column(&processes::ProcessSorting::CpuHistory)
+            || self
+                .proc_state
+                .is_using_column(&processes::ProcessSorting::MemHistory)
+            || self
+                .proc_state
+                .is_using_column(&processes::ProcessSorting::MemGrowth);
+    }
+
+    fn cycle_cpu_graph_mode(&mut self) {
+        let graph_widget_id = match self.current_widget.widget_type {
+            BottomWidgetType::Cpu => self.current_widget.widget_id,
+            BottomWidgetType::CpuLegend => self.current_widget.widget_id - 1,
+            _ => return,
+        };
+
+        if let Some(cpu_widget_state) = self.cpu_state.get_mut_widget_state(graph_widget_id) {
+            cpu_widget_state.graph_mode = cpu_widget_state.graph_mode.next();
+            self.cpu_state.force_update = Some(graph_widget_id);
+            self.is_force_redraw = true;
+            self.update_used_cpu_details();
+        }
+    }
 
     pub fn open_config_screen(&mut self) {
         self.is_config_open = true;
@@ -1729,6 +2133,8 @@ impl App {
 
             match self.current_widget.widget_type {
                 BottomWidgetType::ProcSearch => {}
+                // Compact widgets have nothing more to show when expanded.
+                _ if self.current_widget.widget_type.is_widget_basic() => {}
                 _ => {
                     self.is_expanded = true;
                     self.is_force_redraw = true;
@@ -1763,6 +2169,7 @@ impl App {
                 WidgetDirection::Up => self.current_widget.up_neighbour,
                 WidgetDirection::Down => self.current_widget.down_neighbour,
             }) {
+                let previous_widget = self.current_widget.clone();
                 if let Some(new_widget) = self.widget_map.get(new_widget_id) {
                     match &new_widget.widget_type {
                         BottomWidgetType::Temp
@@ -1862,13 +2269,15 @@ impl App {
                                         WidgetDirection::Down => new_widget.down_neighbour,
                                     };
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::TempGraphLegend => {
+                                            if let Some(is_legend_hidden) = self
+                                                .is_graph_legend_hidden(
+                                                    &new_widget.widget_type,
+                                                    new_widget_id - *offset,
+                                                )
                                             {
-                                                if cpu_widget_state.is_legend_hidden {
+                                                if is_legend_hidden {
                                                     if let Some(next_neighbour_id) =
                                                         option_next_neighbour_id
                                                     {
@@ -1943,13 +2352,15 @@ impl App {
                                 } else {
                                     // Reflect
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::TempGraphLegend => {
+                                            if let Some(is_legend_hidden) = self
+                                                .is_graph_legend_hidden(
+                                                    &new_widget.widget_type,
+                                                    new_widget_id - *offset,
+                                                )
                                             {
-                                                if cpu_widget_state.is_legend_hidden {
+                                                if is_legend_hidden {
                                                     if let Some(parent_cpu_widget) = self
                                                         .widget_map
                                                         .get(&(new_widget_id - *offset))
@@ -2021,13 +2432,12 @@ impl App {
                     if let Some((parent_direction, offset)) = &self.current_widget.parent_reflector
                     {
                         match &self.current_widget.widget_type {
-                            BottomWidgetType::CpuLegend => {
-                                if let Some(cpu_widget_state) = self
-                                    .cpu_state
-                                    .widget_states
-                                    .get(&(self.current_widget.widget_id - *offset))
-                                {
-                                    if cpu_widget_state.is_legend_hidden {
+                            BottomWidgetType::CpuLegend | BottomWidgetType::TempGraphLegend => {
+                                if let Some(is_legend_hidden) = self.is_graph_legend_hidden(
+                                    &self.current_widget.widget_type,
+                                    self.current_widget.widget_id - *offset,
+                                ) {
+                                    if is_legend_hidden {
                                         reflection_dir = Some(parent_direction.clone());
                                     }
                                 }
@@ -2060,6 +2470,21 @@ impl App {
                     if let Some(ref_dir) = &reflection_dir {
                         self.move_widget_selection_logic(ref_dir);
                     }
+
+                    // Keep going past widgets hidden to make room for others, unless there's no
+                    // shown widget further on.
+                    if self
+                        .hidden_widget_ids
+                        .contains(&self.current_widget.widget_id)
+                    {
+                        self.move_widget_selection_logic(direction);
+                        if self
+                            .hidden_widget_ids
+                            .contains(&self.current_widget.widget_id)
+                        {
+                            self.current_widget = previous_widget;
+                        }
+                    }
                 }
             }
         } else {
@@ -2106,6 +2531,69 @@ impl App {
         }
     }
 
+    /// Moves the selection off the current widget if it was hidden to make room for others,
+    /// preferring one of its neighbours, then the first shown widget in `layout`.
+    pub fn move_selection_off_hidden_widget(&mut self, layout: &BottomLayout) {
+        if self.ignore_normal_keybinds()
+            || !self
+                .hidden_widget_ids
+                .contains(&self.current_widget.widget_id)
+        {
+            return;
+        }
+
+        for direction in &[
+            WidgetDirection::Left,
+            WidgetDirection::Right,
+            WidgetDirection::Up,
+            WidgetDirection::Down,
+        ] {
+            let previous_widget = self.current_widget.clone();
+            self.move_widget_selection_logic(direction);
+            if !self
+                .hidden_widget_ids
+                .contains(&self.current_widget.widget_id)
+            {
+                return;
+            }
+            self.current_widget = previous_widget;
+        }
+
+        if let Some(widget) = layout
+            .rows
+            .iter()
+            .flat_map(|row| &row.children)
+            .flat_map(|col| &col.children)
+            .flat_map(|col_row| &col_row.children)
+            .find(|widget| {
+                widget.widget_type.is_widget_selectable()
+                    && widget.parent_reflector.is_none()
+                    && !self.hidden_widget_ids.contains(&widget.widget_id)
+            })
+            .and_then(|widget| self.widget_map.get(&widget.widget_id))
+        {
+            self.current_widget = widget.clone();
+        }
+    }
+
+    /// Returns whether the legend of the graph widget with ID `graph_widget_id` is currently
+    /// hidden, or `None` if `legend_widget_type` is not a legend.
+    fn is_graph_legend_hidden(
+        &self, legend_widget_type: &BottomWidgetType, graph_widget_id: u64,
+    ) -> Option<bool> {
+        match legend_widget_type {
+            BottomWidgetType::CpuLegend => self
+                .cpu_state
+                .get_widget_state(graph_widget_id)
+                .map(|cpu_widget_state| cpu_widget_state.is_legend_hidden),
+            BottomWidgetType::TempGraphLegend => self
+                .temp_graph_state
+                .get_widget_state(graph_widget_id)
+                .map(|temp_graph_widget_state| temp_graph_widget_state.is_legend_hidden),
+            _ => None,
+        }
+    }
+
     fn handle_left_expanded_movement(&mut self) {
         if let BottomWidgetType::Proc = self.current_widget.widget_type {
             if let Some(new_widget_id) = self.current_widget.left_neighbour {
@@ -2121,15 +2609,13 @@ impl App {
                     }
                 }
             }
-        } else if self.app_config_fields.left_legend {
-            if let BottomWidgetType::Cpu = self.current_widget.widget_type {
+        } else if self.current_widget.left_legend {
+            if let Some(legend_widget_type) = self.current_widget.widget_type.get_legend_type() {
                 if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
-                    if let Some(cpu_widget_state) = self
-                        .cpu_state
-                        .widget_states
-                        .get(&self.current_widget.widget_id)
+                    if let Some(is_legend_hidden) = self
+                        .is_graph_legend_hidden(&legend_widget_type, self.current_widget.widget_id)
                     {
-                        if !cpu_widget_state.is_legend_hidden {
+                        if !is_legend_hidden {
                             if let Some(new_widget_id) = current_widget.left_neighbour {
                                 if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
                                     self.current_widget = new_widget.clone();
@@ -2139,7 +2625,9 @@ impl App {
                     }
                 }
             }
-        } else if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+        } else if let BottomWidgetType::CpuLegend | BottomWidgetType::TempGraphLegend =
+            self.current_widget.widget_type
+        {
             if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
                 if let Some(new_widget_id) = current_widget.left_neighbour {
                     if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
@@ -2157,8 +2645,10 @@ impl App {
                     self.current_widget = proc_sort_widget.clone();
                 }
             }
-        } else if self.app_config_fields.left_legend {
-            if let BottomWidgetType::CpuLegend = self.current_widget.widget_type {
+        } else if self.current_widget.left_legend {
+            if let BottomWidgetType::CpuLegend | BottomWidgetType::TempGraphLegend =
+                self.current_widget.widget_type
+            {
                 if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
                     if let Some(new_widget_id) = current_widget.right_neighbour {
                         if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
@@ -2167,14 +2657,12 @@ impl App {
                     }
                 }
             }
-        } else if let BottomWidgetType::Cpu = self.current_widget.widget_type {
+        } else if let Some(legend_widget_type) = self.current_widget.widget_type.get_legend_type() {
             if let Some(current_widget) = self.widget_map.get(&self.current_widget.widget_id) {
-                if let Some(cpu_widget_state) = self
-                    .cpu_state
-                    .widget_states
-                    .get(&self.current_widget.widget_id)
+                if let Some(is_legend_hidden) =
+                    self.is_graph_legend_hidden(&legend_widget_type, self.current_widget.widget_id)
                 {
-                    if !cpu_widget_state.is_legend_hidden {
+                    if !is_legend_hidden {
                         if let Some(new_widget_id) = current_widget.right_neighbour {
                             if let Some(new_widget) = self.widget_map.get(&new_widget_id) {
                                 self.current_widget = new_widget.clone();
@@ -2216,6 +2704,15 @@ impl App {
                         temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
+                BottomWidgetType::Sensors => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        sensor_widget_state.scroll_state.current_scroll_position = 0;
+                        sensor_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
@@ -2234,6 +2731,15 @@ impl App {
                         cpu_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
                     }
                 }
+                BottomWidgetType::TempGraphLegend => {
+                    if let Some(temp_graph_widget_state) = self
+                        .temp_graph_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        temp_graph_widget_state.scroll_state.current_scroll_position = 0;
+                        temp_graph_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+                    }
+                }
 
                 _ => {}
             }
@@ -2283,21 +2789,45 @@ impl App {
                         .temp_state
                         .get_mut_widget_state(self.current_widget.widget_id)
                     {
-                        if !self.canvas_data.temp_sensor_data.is_empty() {
+                        let temp_data_len = self
+                            .canvas_data
+                            .temp_sensor_data
+                            .get(&self.current_widget.widget_id)
+                            .map_or(0, Vec::len);
+                        if temp_data_len > 0 {
                             temp_widget_state.scroll_state.current_scroll_position =
-                                self.canvas_data.temp_sensor_data.len() - 1;
+                                temp_data_len - 1;
                             temp_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
                         }
                     }
                 }
+                BottomWidgetType::Sensors => {
+                    if let Some(sensor_widget_state) = self
+                        .sensor_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        let sensor_data_len = self.canvas_data.sensor_data.len();
+                        if sensor_data_len > 0 {
+                            sensor_widget_state.scroll_state.current_scroll_position =
+                                sensor_data_len - 1;
+                            sensor_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
                 BottomWidgetType::Disk => {
                     if let Some(disk_widget_state) = self
                         .disk_state
                         .get_mut_widget_state(self.current_widget.widget_id)
                     {
-                        if !self.canvas_data.disk_data.is_empty() {
+                        let disk_data_len = self
+                            .canvas_data
+                            .disk_data
+                            .get(&self.current_widget.widget_id)
+                            .map_or(0, Vec::len);
+                        if disk_data_len > 0 {
                             disk_widget_state.scroll_state.current_scroll_position =
-                                self.canvas_data.disk_data.len() - 1;
+                                disk_data_len - 1;
                             disk_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
                         }
                     }
@@ -2314,6 +2844,19 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::TempGraphLegend => {
+                    if let Some(temp_graph_widget_state) = self
+                        .temp_graph_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        let cap = self.canvas_data.temp_graph_data.len();
+                        if cap > 0 {
+                            temp_graph_widget_state.scroll_state.current_scroll_position = cap - 1;
+                            temp_graph_widget_state.scroll_state.scroll_direction =
+                                ScrollDirection::Down;
+                        }
+                    }
+                }
                 _ => {}
             }
             self.reset_multi_tap_keys();
@@ -2335,10 +2878,13 @@ impl App {
                 BottomWidgetType::Proc => {
                     self.increment_process_position(-1);
                 }
+                BottomWidgetType::ProcSearch => self.increment_saved_filter(-1),
                 BottomWidgetType::ProcSort => self.increment_process_sort_position(-1),
                 BottomWidgetType::Temp => self.increment_temp_position(-1),
+                BottomWidgetType::Sensors => self.increment_sensor_position(-1),
                 BottomWidgetType::Disk => self.increment_disk_position(-1),
                 BottomWidgetType::CpuLegend => self.increment_cpu_legend_position(-1),
+                BottomWidgetType::TempGraphLegend => self.increment_temp_graph_legend_position(-1),
                 _ => {}
             }
         }
@@ -2350,10 +2896,13 @@ impl App {
                 BottomWidgetType::Proc => {
                     self.increment_process_position(1);
                 }
+                BottomWidgetType::ProcSearch => self.increment_saved_filter(1),
                 BottomWidgetType::ProcSort => self.increment_process_sort_position(1),
                 BottomWidgetType::Temp => self.increment_temp_position(1),
+                BottomWidgetType::Sensors => self.increment_sensor_position(1),
                 BottomWidgetType::Disk => self.increment_disk_position(1),
                 BottomWidgetType::CpuLegend => self.increment_cpu_legend_position(1),
+                BottomWidgetType::TempGraphLegend => self.increment_temp_graph_legend_position(1),
                 _ => {}
             }
         }
@@ -2382,6 +2931,30 @@ impl App {
         }
     }
 
+    /// Cycles through the saved filters, wrapping around at either end.
+    fn increment_saved_filter(&mut self, num_to_change_by: i64) {
+        if self.saved_filters.is_empty() {
+            return;
+        }
+
+        if let Some(proc_widget_state) = self
+            .proc_state
+            .get_mut_widget_state(self.current_widget.widget_id - 1)
+        {
+            if proc_widget_state.is_search_enabled() {
+                let num_filters = self.saved_filters.len() as i64;
+                let new_index = match proc_widget_state.process_search_state.saved_filter_index {
+                    Some(index) => (index as i64 + num_to_change_by).rem_euclid(num_filters),
+                    None if num_to_change_by < 0 => num_filters - 1,
+                    None => 0,
+                } as usize;
+
+                proc_widget_state.apply_saved_filter(new_index, &self.saved_filters[new_index]);
+                self.proc_state.force_update = Some(self.current_widget.widget_id - 1);
+            }
+        }
+    }
+
     fn increment_cpu_legend_position(&mut self, num_to_change_by: i64) {
         if let Some(cpu_widget_state) = self
             .cpu_state
@@ -2406,6 +2979,30 @@ impl App {
         }
     }
 
+    fn increment_temp_graph_legend_position(&mut self, num_to_change_by: i64) {
+        if let Some(temp_graph_widget_state) = self
+            .temp_graph_state
+            .widget_states
+            .get_mut(&(self.current_widget.widget_id - 1))
+        {
+            let current_posn = temp_graph_widget_state.scroll_state.current_scroll_position;
+
+            let cap = self.canvas_data.temp_graph_data.len();
+            if current_posn as i64 + num_to_change_by >= 0
+                && current_posn as i64 + num_to_change_by < cap as i64
+            {
+                temp_graph_widget_state.scroll_state.current_scroll_position =
+                    (current_posn as i64 + num_to_change_by) as usize;
+            }
+
+            if num_to_change_by < 0 {
+                temp_graph_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+            } else {
+                temp_graph_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
+            }
+        }
+    }
+
     /// Returns the new position.
     fn increment_process_position(&mut self, num_to_change_by: i64) -> Option<usize> {
         if let Some(proc_widget_state) = self
@@ -2447,10 +3044,14 @@ impl App {
             .get_mut(&self.current_widget.widget_id)
         {
             let current_posn = temp_widget_state.scroll_state.current_scroll_position;
+            let temp_data_len = self
+                .canvas_data
+                .temp_sensor_data
+                .get(&self.current_widget.widget_id)
+                .map_or(0, Vec::len);
 
             if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by
-                    < self.canvas_data.temp_sensor_data.len() as i64
+                && current_posn as i64 + num_to_change_by < temp_data_len as i64
             {
                 temp_widget_state.scroll_state.current_scroll_position =
                     (current_posn as i64 + num_to_change_by) as usize;
@@ -2464,6 +3065,30 @@ impl App {
         }
     }
 
+    fn increment_sensor_position(&mut self, num_to_change_by: i64) {
+        if let Some(sensor_widget_state) = self
+            .sensor_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            let current_posn = sensor_widget_state.scroll_state.current_scroll_position;
+            let sensor_data_len = self.canvas_data.sensor_data.len();
+
+            if current_posn as i64 + num_to_change_by >= 0
+                && current_posn as i64 + num_to_change_by < sensor_data_len as i64
+            {
+                sensor_widget_state.scroll_state.current_scroll_position =
+                    (current_posn as i64 + num_to_change_by) as usize;
+            }
+
+            if num_to_change_by < 0 {
+                sensor_widget_state.scroll_state.scroll_direction = ScrollDirection::Up;
+            } else {
+                sensor_widget_state.scroll_state.scroll_direction = ScrollDirection::Down;
+            }
+        }
+    }
+
     fn increment_disk_position(&mut self, num_to_change_by: i64) {
         if let Some(disk_widget_state) = self
             .disk_state
@@ -2471,9 +3096,14 @@ impl App {
             .get_mut(&self.current_widget.widget_id)
         {
             let current_posn = disk_widget_state.scroll_state.current_scroll_position;
+            let disk_data_len = self
+                .canvas_data
+                .disk_data
+                .get(&self.current_widget.widget_id)
+                .map_or(0, Vec::len);
 
             if current_posn as i64 + num_to_change_by >= 0
-                && current_posn as i64 + num_to_change_by < self.canvas_data.disk_data.len() as i64
+                && current_posn as i64 + num_to_change_by < disk_data_len as i64
             {
                 disk_widget_state.scroll_state.current_scroll_position =
                     (current_posn as i64 + num_to_change_by) as usize;
@@ -2668,6 +3298,32 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state.current_display_time
+                        + self.app_config_fields.time_interval;
+                    if new_time <= constants::STALE_MAX_MILLISECONDS {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time
+                        != constants::STALE_MAX_MILLISECONDS
+                    {
+                        temp_graph_widget_state.current_display_time =
+                            constants::STALE_MAX_MILLISECONDS;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -2749,17 +3405,52 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state.current_display_time
+                        - self.app_config_fields.time_interval;
+                    if new_time >= constants::STALE_MIN_MILLISECONDS {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time
+                        != constants::STALE_MIN_MILLISECONDS
+                    {
+                        temp_graph_widget_state.current_display_time =
+                            constants::STALE_MIN_MILLISECONDS;
+                        self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
 
+    /// Returns the time range the current widget starts with, which it may set in the layout.
+    fn get_current_default_time_value(&self) -> u64 {
+        self.current_widget
+            .options
+            .default_time_value
+            .unwrap_or(self.app_config_fields.default_time_value)
+    }
+
     fn reset_cpu_zoom(&mut self) {
+        let default_time_value = self.get_current_default_time_value();
         if let Some(cpu_widget_state) = self
             .cpu_state
             .widget_states
             .get_mut(&self.current_widget.widget_id)
         {
-            cpu_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            cpu_widget_state.current_display_time = default_time_value;
             self.cpu_state.force_update = Some(self.current_widget.widget_id);
             if self.app_config_fields.autohide_time {
                 cpu_widget_state.autohide_timer = Some(Instant::now());
@@ -2768,12 +3459,13 @@ impl App {
     }
 
     fn reset_mem_zoom(&mut self) {
+        let default_time_value = self.get_current_default_time_value();
         if let Some(mem_widget_state) = self
             .mem_state
             .widget_states
             .get_mut(&self.current_widget.widget_id)
         {
-            mem_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            mem_widget_state.current_display_time = default_time_value;
             self.mem_state.force_update = Some(self.current_widget.widget_id);
             if self.app_config_fields.autohide_time {
                 mem_widget_state.autohide_timer = Some(Instant::now());
@@ -2782,12 +3474,13 @@ impl App {
     }
 
     fn reset_net_zoom(&mut self) {
+        let default_time_value = self.get_current_default_time_value();
         if let Some(net_widget_state) = self
             .net_state
             .widget_states
             .get_mut(&self.current_widget.widget_id)
         {
-            net_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            net_widget_state.current_display_time = default_time_value;
             self.net_state.force_update = Some(self.current_widget.widget_id);
             if self.app_config_fields.autohide_time {
                 net_widget_state.autohide_timer = Some(Instant::now());
@@ -2795,11 +3488,27 @@ impl App {
         }
     }
 
+    fn reset_temp_graph_zoom(&mut self) {
+        let default_time_value = self.get_current_default_time_value();
+        if let Some(temp_graph_widget_state) = self
+            .temp_graph_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            temp_graph_widget_state.current_display_time = default_time_value;
+            self.temp_graph_state.force_update = Some(self.current_widget.widget_id);
+            if self.app_config_fields.autohide_time {
+                temp_graph_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_zoom(&mut self) {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
+            BottomWidgetType::TempGraph => self.reset_temp_graph_zoom(),
             _ => {}
         }
     }
@@ -2900,6 +3609,20 @@ impl App {
             return;
         }
 
+        // Third short circuit --- did we click on a layout tab?
+        if let Some(tab_click_locs) = &self.layout_tab_state.tab_click_locs {
+            if let Some(tab_index) =
+                tab_click_locs
+                    .iter()
+                    .position(|((tlc_x, tlc_y), (brc_x, brc_y))| {
+                        (x >= *tlc_x && y >= *tlc_y) && (x <= *brc_x && y <= *brc_y)
+                    })
+            {
+                self.select_layout_tab(tab_index);
+                return;
+            }
+        }
+
         let mut failed_to_get = true;
         // TODO: [MOUSE] We could use a better data structure for this?  Currently it's a blind
         // traversal through a hashmap, using a 2d binary tree of sorts would be better.
@@ -2954,7 +3677,9 @@ impl App {
                     BottomWidgetType::Proc
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
+                    | BottomWidgetType::TempGraphLegend
                     | BottomWidgetType::Temp
+                    | BottomWidgetType::Sensors
                     | BottomWidgetType::Disk => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
@@ -3028,6 +3753,22 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::TempGraphLegend => {
+                                    if let Some(temp_graph_widget_state) = self
+                                        .temp_graph_state
+                                        .get_widget_state(self.current_widget.widget_id - 1)
+                                    {
+                                        if let Some(visual_index) = temp_graph_widget_state
+                                            .scroll_state
+                                            .table_state
+                                            .selected()
+                                        {
+                                            self.increment_temp_graph_legend_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 BottomWidgetType::Temp => {
                                     if let Some(temp_widget_state) = self
                                         .temp_state
@@ -3042,6 +3783,20 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::Sensors => {
+                                    if let Some(sensor_widget_state) = self
+                                        .sensor_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            sensor_widget_state.scroll_state.table_state.selected()
+                                        {
+                                            self.increment_sensor_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 BottomWidgetType::Disk => {
                                     if let Some(disk_widget_state) = self
                                         .disk_state
@@ -3062,7 +3817,6 @@ impl App {
                             // We might have clicked on a header!  Check if we only exceeded the table + border offset, and
                             // it's implied we exceeded the gap offset.
                             if clicked_entry == border_offset {
-                                #[allow(clippy::single_match)]
                                 match &self.current_widget.widget_type {
                                     BottomWidgetType::Proc => {
                                         if let Some(proc_widget_state) = self
@@ -3100,6 +3854,36 @@ impl App {
                                             }
                                         }
                                     }
+                                    BottomWidgetType::Temp => {
+                                        if let Some(temp_widget_state) = self
+                                            .temp_state
+                                            .get_mut_widget_state(self.current_widget.widget_id)
+                                        {
+                                            if let Some(itx) = temp_widget_state
+                                                .sort_state
+                                                .get_clicked_column(x, y)
+                                            {
+                                                temp_widget_state.sort_state.set_sort_index(itx);
+                                                self.temp_state.force_update =
+                                                    Some(self.current_widget.widget_id);
+                                            }
+                                        }
+                                    }
+                                    BottomWidgetType::Disk => {
+                                        if let Some(disk_widget_state) = self
+                                            .disk_state
+                                            .get_mut_widget_state(self.current_widget.widget_id)
+                                        {
+                                            if let Some(itx) = disk_widget_state
+                                                .sort_state
+                                                .get_clicked_column(x, y)
+                                            {
+                                                disk_widget_state.sort_state.set_sort_index(itx);
+                                                self.disk_state.force_update =
+                                                    Some(self.current_widget.widget_id);
+                                            }
+                                        }
+                                    }
                                     _ => {}
                                 }
                             }
@@ -14,14 +14,21 @@
 /// more points as this is used!
 use once_cell::sync::Lazy;
 
-use std::{time::Instant, vec::Vec};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Instant,
+    vec::Vec,
+};
 
 #[cfg(feature = "battery")]
 use crate::data_harvester::batteries;
 
 use crate::{
-    data_harvester::{cpu, disks, memory, network, processes, temperature, Data},
+    app::layout_manager::UsedWidgets,
+    constants::PROCESS_HISTORY_SPARKLINE_WIDTH,
+    data_harvester::{cpu, disks, host, memory, network, processes, sensors, temperature, Data},
     utils::gen_util::{get_decimal_bytes, GIGA_LIMIT},
+    Pid,
 };
 use regex::Regex;
 
@@ -33,9 +40,110 @@ pub struct TimedData {
     pub rx_data: Value,
     pub tx_data: Value,
     pub cpu_data: Vec<Value>,
+    pub cpu_breakdown_data: Vec<cpu::CpuBreakdown>,
+    pub cpu_frequency_data: Vec<Option<Value>>,
     pub load_avg_data: [f32; 3],
     pub mem_data: Option<Value>,
     pub swap_data: Option<Value>,
+    /// The memory usage percentage of each NUMA node, in the same order as `numa_stats_and_prev`.
+    pub numa_mem_data: Vec<Value>,
+}
+
+/// I/O statistics of a disk over the last harvest, similar to what `iostat -x` shows.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DiskIoStats {
+    pub read_iops: Option<f64>,
+    pub write_iops: Option<f64>,
+    /// The average time taken to serve a read or write, in milliseconds.
+    pub await_ms: Option<f64>,
+    /// The percentage of time that the device had I/O in flight.
+    pub util_percent: Option<f64>,
+    /// The average number of requests in flight.
+    pub queue_size: Option<f64>,
+}
+
+impl DiskIoStats {
+    fn from_io_data(
+        curr: &disks::IoData, prev: &disks::IoData, time_since_last_harvest: f64,
+    ) -> Self {
+        if time_since_last_harvest <= 0.0 {
+            return DiskIoStats::default();
+        }
+
+        let reads = curr.read_count.saturating_sub(prev.read_count) as f64;
+        let writes = curr.write_count.saturating_sub(prev.write_count) as f64;
+        let elapsed_millis = time_since_last_harvest * 1000.0;
+
+        let (await_ms, util_percent, queue_size) = match (curr.io_times, prev.io_times) {
+            (Some(curr_times), Some(prev_times)) => {
+                let request_time = curr_times.read_time.saturating_sub(prev_times.read_time)
+                    + curr_times.write_time.saturating_sub(prev_times.write_time);
+                let io_time = curr_times.io_time.saturating_sub(prev_times.io_time);
+                let weighted_io_time = curr_times
+                    .weighted_io_time
+                    .saturating_sub(prev_times.weighted_io_time);
+
+                (
+                    Some(if reads + writes > 0.0 {
+                        request_time as f64 / (reads + writes)
+                    } else {
+                        0.0
+                    }),
+                    Some((io_time as f64 / elapsed_millis * 100.0).min(100.0)),
+                    Some(weighted_io_time as f64 / elapsed_millis),
+                )
+            }
+            _ => (None, None, None),
+        };
+
+        DiskIoStats {
+            read_iops: Some(reads / time_since_last_harvest),
+            write_iops: Some(writes / time_since_last_harvest),
+            await_ms,
+            util_percent,
+            queue_size,
+        }
+    }
+}
+
+/// Task counts and the rate of context switches and interrupts over the last harvest.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SchedulerStats {
+    pub running_tasks: u64,
+    pub blocked_tasks: u64,
+    pub context_switches_per_sec: Option<f64>,
+    pub interrupts_per_sec: Option<f64>,
+}
+
+/// Memory usage of a NUMA node, and the rate of NUMA allocation misses over the last harvest.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NumaNodeStats {
+    pub node: u32,
+    pub mem_total_in_kib: u64,
+    pub mem_used_in_kib: u64,
+    pub mem_free_in_kib: u64,
+    pub use_percent: Option<f64>,
+    pub numa_miss_per_sec: Option<f64>,
+    pub numa_foreign_per_sec: Option<f64>,
+}
+
+/// Recent CPU and memory usage samples of a single process, along with totals since it was first
+/// seen.
+#[derive(Debug, Default)]
+pub struct ProcessHistory {
+    /// Used to detect a PID being reused by a different process.
+    pub name: String,
+    /// Memory usage when the process was first seen, in bytes.
+    pub initial_mem_bytes: u64,
+    /// The sum of every CPU usage percentage seen, used for the average.
+    pub cpu_usage_sum: f64,
+    /// How many times the process has been seen.
+    pub sample_count: u64,
+    /// The highest memory usage seen, in bytes.
+    pub peak_mem_bytes: u64,
+    /// Only the last [`PROCESS_HISTORY_SPARKLINE_WIDTH`] samples are kept.  Tuple is time, CPU
+    /// usage percentage, and memory usage in bytes.
+    pub samples: VecDeque<(Instant, f64, u64)>,
 }
 
 /// AppCollection represents the pooled data stored within the main app
@@ -55,14 +163,26 @@ pub struct DataCollection {
     pub network_harvest: network::NetworkHarvest,
     pub memory_harvest: memory::MemHarvest,
     pub swap_harvest: memory::MemHarvest,
+    pub numa_stats_and_prev: Vec<(NumaNodeStats, memory::NumaNodeHarvest)>,
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
+    pub scheduler_stats_and_prev: Option<(SchedulerStats, cpu::SchedulerHarvest)>,
     pub process_harvest: Vec<processes::ProcessHarvest>,
+    pub process_history: HashMap<Pid, ProcessHistory>,
     pub disk_harvest: Vec<disks::DiskHarvest>,
     pub io_harvest: disks::IoHarvest,
     pub io_labels_and_prev: Vec<((u64, u64), (u64, u64))>,
     pub io_labels: Vec<(String, String)>,
+    pub io_stats_and_prev: Vec<(DiskIoStats, Option<disks::IoData>)>,
+    /// Used space samples per mount point, kept for longer than the other data so that the
+    /// rate at which a disk is filling up can be estimated.
+    pub disk_usage_history: HashMap<String, VecDeque<(Instant, u64)>>,
     pub temp_harvest: Vec<temperature::TempHarvest>,
+    /// Temperature samples per sensor, keyed as in [`get_temp_history_keys`], as sensors may
+    /// come and go between harvests.
+    pub temp_history: HashMap<(String, usize), VecDeque<(Instant, Value)>>,
+    pub sensor_harvest: Vec<sensors::SensorHarvest>,
+    pub host_harvest: host::HostHarvest,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryHarvest>,
 }
@@ -76,14 +196,22 @@ impl Default for DataCollection {
             network_harvest: network::NetworkHarvest::default(),
             memory_harvest: memory::MemHarvest::default(),
             swap_harvest: memory::MemHarvest::default(),
+            numa_stats_and_prev: Vec::default(),
             cpu_harvest: cpu::CpuHarvest::default(),
             load_avg_harvest: cpu::LoadAvgHarvest::default(),
+            scheduler_stats_and_prev: None,
             process_harvest: Vec::default(),
+            process_history: HashMap::default(),
             disk_harvest: Vec::default(),
             io_harvest: disks::IoHarvest::default(),
             io_labels_and_prev: Vec::default(),
             io_labels: Vec::default(),
+            io_stats_and_prev: Vec::default(),
+            disk_usage_history: HashMap::default(),
             temp_harvest: Vec::default(),
+            temp_history: HashMap::default(),
+            sensor_harvest: Vec::default(),
+            host_harvest: host::HostHarvest::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
         }
@@ -96,12 +224,20 @@ impl DataCollection {
         self.network_harvest = network::NetworkHarvest::default();
         self.memory_harvest = memory::MemHarvest::default();
         self.swap_harvest = memory::MemHarvest::default();
+        self.numa_stats_and_prev = Vec::default();
         self.cpu_harvest = cpu::CpuHarvest::default();
+        self.scheduler_stats_and_prev = None;
         self.process_harvest = Vec::default();
+        self.process_history = HashMap::default();
         self.disk_harvest = Vec::default();
         self.io_harvest = disks::IoHarvest::default();
         self.io_labels_and_prev = Vec::default();
+        self.io_stats_and_prev = Vec::default();
+        self.disk_usage_history = HashMap::default();
         self.temp_harvest = Vec::default();
+        self.temp_history = HashMap::default();
+        self.sensor_harvest = Vec::default();
+        self.host_harvest = host::HostHarvest::default();
         #[cfg(feature = "battery")]
         {
             self.battery_harvest = Vec::default();
@@ -129,9 +265,55 @@ impl DataCollection {
         };
 
         self.timed_data_vec.drain(0..remove_index);
+
+        for history in self.process_history.values_mut() {
+            while let Some((instant, _cpu, _mem)) = history.samples.front() {
+                if current_time.duration_since(*instant).as_millis() > max_time_millis as u128 {
+                    history.samples.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        // Drop processes that haven't been seen for the whole window.
+        self.process_history
+            .retain(|_pid, history| !history.samples.is_empty());
+
+        for samples in self.temp_history.values_mut() {
+            while let Some((instant, _temp)) = samples.front() {
+                if current_time.duration_since(*instant).as_millis() > max_time_millis as u128 {
+                    samples.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        // Drop sensors that haven't been seen for the whole window.
+        self.temp_history
+            .retain(|_key, samples| !samples.is_empty());
+    }
+
+    pub fn clean_disk_history(&mut self, max_time_millis: u64) {
+        let current_time = Instant::now();
+
+        for samples in self.disk_usage_history.values_mut() {
+            while let Some((instant, _used)) = samples.front() {
+                if current_time.duration_since(*instant).as_millis() > max_time_millis as u128 {
+                    samples.pop_front();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        // Drop mounts that haven't been seen for the whole window.
+        self.disk_usage_history
+            .retain(|_mount, samples| !samples.is_empty());
     }
 
-    pub fn eat_data(&mut self, harvested_data: Box<Data>) {
+    pub fn eat_data(&mut self, harvested_data: Box<Data>, used_widgets: &UsedWidgets) {
         let harvested_time = harvested_data.last_collection_time;
         // trace!("Harvested time: {:?}", harvested_time);
         // trace!("New current instant: {:?}", self.current_instant);
@@ -147,6 +329,11 @@ impl DataCollection {
             self.eat_memory_and_swap(memory, swap, &mut new_entry);
         }
 
+        // NUMA nodes
+        if let Some(numa) = harvested_data.numa {
+            self.eat_numa(numa, harvested_time, &mut new_entry);
+        }
+
         // CPU
         if let Some(cpu) = harvested_data.cpu {
             self.eat_cpu(cpu, &mut new_entry);
@@ -157,21 +344,44 @@ impl DataCollection {
             self.eat_load_avg(load_avg, &mut new_entry);
         }
 
+        // Scheduler
+        if let Some(scheduler) = harvested_data.scheduler {
+            self.eat_scheduler(scheduler, harvested_time);
+        }
+
         // Temp
         if let Some(temperature_sensors) = harvested_data.temperature_sensors {
-            self.eat_temp(temperature_sensors);
+            self.eat_temp(
+                temperature_sensors,
+                harvested_time,
+                used_widgets.use_temp_graph,
+            );
+        }
+
+        // Other sensors
+        if let Some(sensors) = harvested_data.sensors {
+            self.eat_sensors(sensors);
+        }
+
+        // Host
+        if let Some(host) = harvested_data.host {
+            self.eat_host(host);
         }
 
         // Disks
         if let Some(disks) = harvested_data.disks {
             if let Some(io) = harvested_data.io {
-                self.eat_disks(disks, io, harvested_time);
+                self.eat_disks(disks, io, harvested_time, used_widgets.use_disk_history);
             }
         }
 
         // Processes
         if let Some(list_of_processes) = harvested_data.list_of_processes {
-            self.eat_proc(list_of_processes);
+            self.eat_proc(
+                list_of_processes,
+                harvested_time,
+                used_widgets.use_proc_history,
+            );
         }
 
         #[cfg(feature = "battery")]
@@ -201,6 +411,60 @@ impl DataCollection {
         self.swap_harvest = swap;
     }
 
+    fn eat_numa(
+        &mut self, numa: Vec<memory::NumaNodeHarvest>, harvested_time: Instant,
+        new_entry: &mut TimedData,
+    ) {
+        let elapsed_secs = harvested_time
+            .duration_since(self.current_instant)
+            .as_secs_f64();
+
+        let numa_stats_and_prev = numa
+            .into_iter()
+            .map(|harvest| {
+                let prev = self
+                    .numa_stats_and_prev
+                    .iter()
+                    .find(|(stats, _prev)| stats.node == harvest.node)
+                    .map(|(_stats, prev)| prev);
+                let get_rate = |current: u64, prev: u64| {
+                    if elapsed_secs > 0.0 {
+                        Some(current.saturating_sub(prev) as f64 / elapsed_secs)
+                    } else {
+                        None
+                    }
+                };
+
+                let stats = NumaNodeStats {
+                    node: harvest.node,
+                    mem_total_in_kib: harvest.mem_total_in_kib,
+                    mem_used_in_kib: harvest.mem_used_in_kib,
+                    mem_free_in_kib: harvest.mem_free_in_kib,
+                    use_percent: if harvest.mem_total_in_kib == 0 {
+                        None
+                    } else {
+                        Some(
+                            harvest.mem_used_in_kib as f64 / harvest.mem_total_in_kib as f64
+                                * 100.0,
+                        )
+                    },
+                    numa_miss_per_sec: prev
+                        .and_then(|prev| get_rate(harvest.numa_miss, prev.numa_miss)),
+                    numa_foreign_per_sec: prev
+                        .and_then(|prev| get_rate(harvest.numa_foreign, prev.numa_foreign)),
+                };
+
+                (stats, harvest)
+            })
+            .collect::<Vec<_>>();
+
+        new_entry.numa_mem_data = numa_stats_and_prev
+            .iter()
+            .map(|(stats, _harvest)| stats.use_percent.unwrap_or(0.0))
+            .collect();
+        self.numa_stats_and_prev = numa_stats_and_prev;
+    }
+
     fn eat_network(&mut self, network: network::NetworkHarvest, new_entry: &mut TimedData) {
         // RX
         if network.rx > 0 {
@@ -220,8 +484,13 @@ impl DataCollection {
         // Note this only pre-calculates the data points - the names will be
         // within the local copy of cpu_harvest.  Since it's all sequential
         // it probably doesn't matter anyways.
-        cpu.iter()
-            .for_each(|cpu| new_entry.cpu_data.push(cpu.cpu_usage));
+        cpu.iter().for_each(|cpu| {
+            new_entry.cpu_data.push(cpu.cpu_usage);
+            new_entry.cpu_breakdown_data.push(cpu.breakdown);
+            new_entry
+                .cpu_frequency_data
+                .push(cpu.frequency.map(|frequency| frequency.current));
+        });
 
         self.cpu_harvest = cpu.to_vec();
     }
@@ -232,13 +501,65 @@ impl DataCollection {
         self.load_avg_harvest = load_avg;
     }
 
-    fn eat_temp(&mut self, temperature_sensors: Vec<temperature::TempHarvest>) {
-        // TODO: [PO] To implement
-        self.temp_harvest = temperature_sensors.to_vec();
+    fn eat_scheduler(&mut self, scheduler: cpu::SchedulerHarvest, harvested_time: Instant) {
+        let elapsed_secs = harvested_time
+            .duration_since(self.current_instant)
+            .as_secs_f64();
+
+        let (context_switches_per_sec, interrupts_per_sec) = match &self.scheduler_stats_and_prev {
+            Some((_, prev)) if elapsed_secs > 0.0 => (
+                Some(
+                    scheduler
+                        .context_switches
+                        .saturating_sub(prev.context_switches) as f64
+                        / elapsed_secs,
+                ),
+                Some(scheduler.interrupts.saturating_sub(prev.interrupts) as f64 / elapsed_secs),
+            ),
+            _ => (None, None),
+        };
+
+        self.scheduler_stats_and_prev = Some((
+            SchedulerStats {
+                running_tasks: scheduler.running_tasks,
+                blocked_tasks: scheduler.blocked_tasks,
+                context_switches_per_sec,
+                interrupts_per_sec,
+            },
+            scheduler,
+        ));
+    }
+
+    fn eat_temp(
+        &mut self, temperature_sensors: Vec<temperature::TempHarvest>, harvested_time: Instant,
+        keep_history: bool,
+    ) {
+        if keep_history {
+            for (key, sensor) in get_temp_history_keys(&temperature_sensors)
+                .into_iter()
+                .zip(&temperature_sensors)
+            {
+                self.temp_history
+                    .entry(key)
+                    .or_default()
+                    .push_back((harvested_time, sensor.temperature as Value));
+            }
+        }
+
+        self.temp_harvest = temperature_sensors;
+    }
+
+    fn eat_sensors(&mut self, sensors: Vec<sensors::SensorHarvest>) {
+        self.sensor_harvest = sensors;
+    }
+
+    fn eat_host(&mut self, host: host::HostHarvest) {
+        self.host_harvest = host;
     }
 
     fn eat_disks(
         &mut self, disks: Vec<disks::DiskHarvest>, io: disks::IoHarvest, harvested_time: Instant,
+        keep_history: bool,
     ) {
         // TODO: [PO] To implement
 
@@ -275,6 +596,21 @@ impl DataCollection {
                         self.io_labels_and_prev.push(((0, 0), (io_r_pt, io_w_pt)));
                     }
 
+                    if self.io_stats_and_prev.len() <= itx {
+                        self.io_stats_and_prev
+                            .push((DiskIoStats::default(), io_device.clone()));
+                    }
+
+                    if let Some((io_stats, io_prev)) = self.io_stats_and_prev.get_mut(itx) {
+                        *io_stats = match (io_device, &io_prev) {
+                            (Some(io_curr), Some(io_prev)) => {
+                                DiskIoStats::from_io_data(io_curr, io_prev, time_since_last_harvest)
+                            }
+                            _ => DiskIoStats::default(),
+                        };
+                        *io_prev = io_device.clone();
+                    }
+
                     if let Some((io_curr, io_prev)) = self.io_labels_and_prev.get_mut(itx) {
                         let r_rate = ((io_r_pt.saturating_sub(io_prev.0)) as f64
                             / time_since_last_harvest)
@@ -311,6 +647,25 @@ impl DataCollection {
                     if let Some(io_labels) = self.io_labels.get_mut(itx) {
                         *io_labels = ("N/A".to_string(), "N/A".to_string());
                     }
+
+                    if self.io_stats_and_prev.len() <= itx {
+                        self.io_stats_and_prev.push((DiskIoStats::default(), None));
+                    }
+
+                    if let Some(io_stats_and_prev) = self.io_stats_and_prev.get_mut(itx) {
+                        *io_stats_and_prev = (DiskIoStats::default(), None);
+                    }
+                }
+            }
+        }
+
+        if keep_history {
+            for disk in &disks {
+                if let Some(used_space) = disk.used_space {
+                    self.disk_usage_history
+                        .entry(disk.mount_point.clone())
+                        .or_default()
+                        .push_back((harvested_time, used_space));
                 }
             }
         }
@@ -319,7 +674,36 @@ impl DataCollection {
         self.io_harvest = io;
     }
 
-    fn eat_proc(&mut self, list_of_processes: Vec<processes::ProcessHarvest>) {
+    fn eat_proc(
+        &mut self, list_of_processes: Vec<processes::ProcessHarvest>, harvested_time: Instant,
+        keep_history: bool,
+    ) {
+        if keep_history {
+            for process in &list_of_processes {
+                let history = self.process_history.entry(process.pid).or_default();
+                if history.samples.is_empty() || history.name != process.name {
+                    *history = ProcessHistory {
+                        name: process.name.clone(),
+                        initial_mem_bytes: process.mem_usage_bytes,
+                        ..ProcessHistory::default()
+                    };
+                }
+
+                history.cpu_usage_sum += process.cpu_usage_percent;
+                history.sample_count += 1;
+                history.peak_mem_bytes = history.peak_mem_bytes.max(process.mem_usage_bytes);
+
+                if history.samples.len() >= PROCESS_HISTORY_SPARKLINE_WIDTH {
+                    history.samples.pop_front();
+                }
+                history.samples.push_back((
+                    harvested_time,
+                    process.cpu_usage_percent,
+                    process.mem_usage_bytes,
+                ));
+            }
+        }
+
         self.process_harvest = list_of_processes;
     }
 
@@ -328,3 +712,18 @@ impl DataCollection {
         self.battery_harvest = list_of_batteries;
     }
 }
+
+/// Returns the key each sensor's history is kept under: its name, and how many sensors of the
+/// same name came before it in the harvest.  Names such as "nvme: Composite" aren't unique.
+pub fn get_temp_history_keys(temp_harvest: &[temperature::TempHarvest]) -> Vec<(String, usize)> {
+    let mut occurrences: HashMap<&str, usize> = HashMap::new();
+    temp_harvest
+        .iter()
+        .map(|sensor| {
+            let occurrence = occurrences.entry(sensor.name.as_str()).or_insert(0);
+            let key = (sensor.name.clone(), *occurrence);
+            *occurrence += 1;
+            key
+        })
+        .collect()
+}
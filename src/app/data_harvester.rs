@@ -21,9 +21,11 @@ use super::DataFilters;
 pub mod batteries;
 pub mod cpu;
 pub mod disks;
+pub mod host;
 pub mod memory;
 pub mod network;
 pub mod processes;
+pub mod sensors;
 pub mod temperature;
 
 #[derive(Clone, Debug)]
@@ -31,13 +33,17 @@ pub struct Data {
     pub last_collection_time: Instant,
     pub cpu: Option<cpu::CpuHarvest>,
     pub load_avg: Option<cpu::LoadAvgHarvest>,
+    pub scheduler: Option<cpu::SchedulerHarvest>,
     pub memory: Option<memory::MemHarvest>,
     pub swap: Option<memory::MemHarvest>,
+    pub numa: Option<Vec<memory::NumaNodeHarvest>>,
     pub temperature_sensors: Option<Vec<temperature::TempHarvest>>,
+    pub sensors: Option<Vec<sensors::SensorHarvest>>,
     pub network: Option<network::NetworkHarvest>,
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
     pub io: Option<disks::IoHarvest>,
+    pub host: Option<host::HostHarvest>,
     #[cfg(feature = "battery")]
     pub list_of_batteries: Option<Vec<batteries::BatteryHarvest>>,
 }
@@ -48,12 +54,16 @@ impl Default for Data {
             last_collection_time: Instant::now(),
             cpu: None,
             load_avg: None,
+            scheduler: None,
             memory: None,
             swap: None,
+            numa: None,
             temperature_sensors: None,
+            sensors: None,
             list_of_processes: None,
             disks: None,
             io: None,
+            host: None,
             network: None,
             #[cfg(feature = "battery")]
             list_of_batteries: None,
@@ -65,12 +75,15 @@ impl Data {
     pub fn cleanup(&mut self) {
         self.io = None;
         self.temperature_sensors = None;
+        self.sensors = None;
         self.list_of_processes = None;
         self.disks = None;
         self.memory = None;
         self.swap = None;
+        self.numa = None;
         self.cpu = None;
         self.load_avg = None;
+        self.scheduler = None;
 
         if let Some(network) = &mut self.network {
             network.first_run_cleanup();
@@ -83,8 +96,8 @@ pub struct DataCollector {
     pub data: Data,
     #[cfg(not(target_os = "linux"))]
     sys: System,
-    previous_cpu_times: Vec<(cpu::PastCpuWork, cpu::PastCpuTotal)>,
-    previous_average_cpu_time: Option<(cpu::PastCpuWork, cpu::PastCpuTotal)>,
+    previous_cpu_times: Vec<(cpu::PastCpuWork, cpu::PastCpuTotal, cpu::PastCpuBreakdown)>,
+    previous_average_cpu_time: Option<(cpu::PastCpuWork, cpu::PastCpuTotal, cpu::PastCpuBreakdown)>,
     #[cfg(target_os = "linux")]
     pid_mapping: FxHashMap<crate::Pid, processes::PrevProcDetails>,
     #[cfg(target_os = "linux")]
@@ -104,6 +117,8 @@ pub struct DataCollector {
     #[cfg(feature = "battery")]
     battery_list: Option<Vec<Battery>>,
     filters: DataFilters,
+    extra_filesystems: disks::ExtraFilesystems,
+    unresponsive_mounts: std::collections::HashMap<String, disks::UnresponsiveMount>,
 }
 
 impl DataCollector {
@@ -133,6 +148,8 @@ impl DataCollector {
             #[cfg(feature = "battery")]
             battery_list: None,
             filters,
+            extra_filesystems: disks::ExtraFilesystems::None,
+            unresponsive_mounts: std::collections::HashMap::new(),
         }
     }
 
@@ -199,6 +216,10 @@ impl DataCollector {
         self.temperature_type = temperature_type;
     }
 
+    pub fn set_extra_filesystems(&mut self, extra_filesystems: disks::ExtraFilesystems) {
+        self.extra_filesystems = extra_filesystems;
+    }
+
     pub fn set_use_current_cpu_total(&mut self, use_current_cpu_total: bool) {
         self.use_current_cpu_total = use_current_cpu_total;
     }
@@ -210,7 +231,7 @@ impl DataCollector {
     pub async fn update_data(&mut self) {
         #[cfg(not(target_os = "linux"))]
         {
-            if self.widgets_to_harvest.use_proc {
+            if self.widgets_to_harvest.use_proc || self.widgets_to_harvest.use_host {
                 self.sys.refresh_processes();
             }
             if self.widgets_to_harvest.use_temp {
@@ -228,6 +249,8 @@ impl DataCollector {
         if self.widgets_to_harvest.use_cpu {
             if let Ok(cpu_data) = cpu::get_cpu_data_list(
                 self.show_average_cpu,
+                &self.temperature_type,
+                &self.widgets_to_harvest,
                 &mut self.previous_cpu_times,
                 &mut self.previous_average_cpu_time,
             )
@@ -243,6 +266,8 @@ impl DataCollector {
                     self.data.load_avg = Some(load_avg_data);
                 }
             }
+
+            self.data.scheduler = cpu::get_scheduler_stats();
         }
 
         // Batteries
@@ -284,6 +309,18 @@ impl DataCollector {
             }
         }
 
+        // Host
+        if self.widgets_to_harvest.use_host {
+            #[cfg(target_os = "linux")]
+            {
+                self.data.host = Some(host::get_host_data());
+            }
+            #[cfg(not(target_os = "linux"))]
+            {
+                self.data.host = Some(host::get_host_data(&self.sys));
+            }
+        }
+
         let network_data_fut = {
             #[cfg(target_os = "windows")]
             {
@@ -314,6 +351,8 @@ impl DataCollector {
             self.widgets_to_harvest.use_disk,
             &self.filters.disk_filter,
             &self.filters.mount_filter,
+            &self.extra_filesystems,
+            &mut self.unresponsive_mounts,
         );
         let disk_io_usage_fut = disks::get_io_usage(self.widgets_to_harvest.use_disk);
         let temp_data_fut = {
@@ -337,12 +376,18 @@ impl DataCollector {
             }
         };
 
-        let (net_data, mem_res, disk_res, io_res, temp_res) = join!(
+        let sensor_data_fut = sensors::get_sensor_data(
+            self.widgets_to_harvest.use_sensors,
+            &self.filters.sensor_filter,
+        );
+
+        let (net_data, mem_res, disk_res, io_res, temp_res, sensor_res) = join!(
             network_data_fut,
             mem_data_fut,
             disk_data_fut,
             disk_io_usage_fut,
-            temp_data_fut
+            temp_data_fut,
+            sensor_data_fut
         );
 
         if let Ok(net_data) = net_data {
@@ -361,6 +406,15 @@ impl DataCollector {
             self.data.swap = swap;
         }
 
+        #[cfg(target_os = "linux")]
+        {
+            if self.widgets_to_harvest.use_mem {
+                if let Ok(numa) = memory::get_numa_data() {
+                    self.data.numa = Some(numa);
+                }
+            }
+        }
+
         if let Ok(disks) = disk_res {
             self.data.disks = disks;
         }
@@ -373,6 +427,10 @@ impl DataCollector {
             self.data.temperature_sensors = temp;
         }
 
+        if let Ok(sensors) = sensor_res {
+            self.data.sensors = sensors;
+        }
+
         // Update time
         self.data.last_collection_time = current_instant;
         self.last_collection_time = current_instant;
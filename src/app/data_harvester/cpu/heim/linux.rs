@@ -1,6 +1,14 @@
 //! Linux-specific functions regarding CPU usage.
 
 use heim::cpu::os::linux::CpuTimeExt;
+
+use super::{CpuBreakdown, CpuDetails, CpuFrequency};
+use crate::app::{
+    data_harvester::{cpu::SchedulerHarvest, temperature::TemperatureType},
+    layout_manager::UsedWidgets,
+};
+use std::{collections::HashMap, fs, path::Path};
+
 pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> (f64, f64) {
     let working_time: f64 = (cpu_time.user()
         + cpu_time.nice()
@@ -14,3 +22,225 @@ pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> (f64, f64) {
         working_time + (cpu_time.idle() + cpu_time.io_wait()).get::<heim::units::time::second>(),
     )
 }
+
+pub fn convert_cpu_breakdown(cpu_time: &heim::cpu::CpuTime) -> CpuBreakdown {
+    CpuBreakdown {
+        user: (cpu_time.user() + cpu_time.nice()).get::<heim::units::time::second>(),
+        system: cpu_time.system().get::<heim::units::time::second>(),
+        iowait: cpu_time.io_wait().get::<heim::units::time::second>(),
+        irq: (cpu_time.irq() + cpu_time.soft_irq()).get::<heim::units::time::second>(),
+        steal: cpu_time.steal().get::<heim::units::time::second>(),
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|contents| contents.trim().to_string())
+}
+
+/// Reads the frequency of the given CPU from cpufreq, converting from kHz to MHz.
+fn get_cpu_frequency(cpu_dir: &Path) -> Option<CpuFrequency> {
+    let read_mhz = |file_name: &str| {
+        read_trimmed(&cpu_dir.join("cpufreq").join(file_name))?
+            .parse::<f64>()
+            .ok()
+            .map(|khz| khz / 1000.0)
+    };
+
+    Some(CpuFrequency {
+        current: read_mhz("scaling_cur_freq")?,
+        min: read_mhz("cpuinfo_min_freq"),
+        max: read_mhz("cpuinfo_max_freq"),
+    })
+}
+
+/// Reads the per-core temperatures from the coretemp driver, keyed by package and core ID, in
+/// Celsius.
+fn get_coretemp_temperatures() -> HashMap<(u32, u32), f32> {
+    let mut temperatures = HashMap::new();
+
+    let hwmon_dirs = match fs::read_dir("/sys/class/hwmon") {
+        Ok(entries) => entries.filter_map(Result::ok).map(|entry| entry.path()),
+        Err(_) => return temperatures,
+    };
+
+    for hwmon_dir in hwmon_dirs {
+        if read_trimmed(&hwmon_dir.join("name")).as_deref() != Some("coretemp") {
+            continue;
+        }
+        let entries = match fs::read_dir(&hwmon_dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        // Each coretemp instance covers one package, labelled like "Package id 0", with the
+        // cores labelled like "Core 3".  Every label has a matching input, like "temp2_label" and
+        // "temp2_input".
+        let mut package_id = None;
+        let mut core_temperatures = Vec::new();
+
+        for entry in entries.filter_map(Result::ok) {
+            let file_name = entry.file_name();
+            let sensor = match file_name
+                .to_str()
+                .and_then(|file_name| file_name.strip_suffix("_label"))
+                .filter(|sensor| sensor.starts_with("temp"))
+            {
+                Some(sensor) => sensor,
+                None => continue,
+            };
+            let label = match read_trimmed(&entry.path()) {
+                Some(label) => label,
+                None => continue,
+            };
+            let temperature = match read_trimmed(&hwmon_dir.join(format!("{}_input", sensor)))
+                .and_then(|input| input.parse::<f32>().ok())
+            {
+                Some(millidegrees) => millidegrees / 1000.0,
+                None => continue,
+            };
+
+            if let Some(id) = label.strip_prefix("Package id ") {
+                package_id = id.parse::<u32>().ok();
+            } else if let Some(core_id) = label
+                .strip_prefix("Core ")
+                .and_then(|id| id.parse::<u32>().ok())
+            {
+                core_temperatures.push((core_id, temperature));
+            }
+        }
+
+        if let Some(package_id) = package_id {
+            for (core_id, temperature) in core_temperatures {
+                temperatures.insert((package_id, core_id), temperature);
+            }
+        }
+    }
+
+    temperatures
+}
+
+/// Returns the NUMA node of a CPU, which is listed as a `nodeN` entry in its sysfs directory.
+fn get_numa_node(cpu_dir: &Path) -> Option<u32> {
+    fs::read_dir(cpu_dir).ok()?.find_map(|entry| {
+        entry
+            .ok()?
+            .file_name()
+            .to_str()?
+            .strip_prefix("node")?
+            .parse::<u32>()
+            .ok()
+    })
+}
+
+/// Returns the ID of each CPU listed in `/proc/stat`, which is the order heim reports them in.
+/// Offline CPUs aren't listed, so a CPU's position isn't always its ID.
+fn get_cpu_ids() -> Option<Vec<usize>> {
+    let stat = fs::read_to_string("/proc/stat").ok()?;
+
+    Some(
+        stat.lines()
+            .filter_map(|line| {
+                line.split_whitespace()
+                    .next()?
+                    .strip_prefix("cpu")?
+                    .parse::<usize>()
+                    .ok()
+            })
+            .collect(),
+    )
+}
+
+/// Returns the frequency, temperature, and topology of each of the `num_cpus` CPUs that heim
+/// reports, in the same order.  Only the details that a widget shows are read.
+pub fn get_cpu_details(
+    num_cpus: usize, temp_type: &TemperatureType, used_widgets: &UsedWidgets,
+) -> Vec<CpuDetails> {
+    if !(used_widgets.use_cpu_frequency
+        || used_widgets.use_cpu_temp
+        || used_widgets.use_cpu_topology)
+    {
+        return vec![CpuDetails::default(); num_cpus];
+    }
+
+    // If the CPUs changed since heim read them, the IDs can't be matched up, so skip this update.
+    let cpu_ids = match get_cpu_ids().filter(|cpu_ids| cpu_ids.len() == num_cpus) {
+        Some(cpu_ids) => cpu_ids,
+        None => return vec![CpuDetails::default(); num_cpus],
+    };
+
+    let coretemp_temperatures = if used_widgets.use_cpu_temp {
+        get_coretemp_temperatures()
+    } else {
+        HashMap::new()
+    };
+
+    cpu_ids
+        .into_iter()
+        .map(|cpu| {
+            let cpu_dir = Path::new("/sys/devices/system/cpu").join(format!("cpu{}", cpu));
+            let read_id = |file_name: &str| {
+                read_trimmed(&cpu_dir.join("topology").join(file_name))?
+                    .parse::<u32>()
+                    .ok()
+            };
+
+            // The package is also needed to match the core to its temperature.
+            let package_id = if used_widgets.use_cpu_topology || used_widgets.use_cpu_temp {
+                read_id("physical_package_id")
+            } else {
+                None
+            };
+            let temperature = package_id
+                .filter(|_| used_widgets.use_cpu_temp)
+                .zip(read_id("core_id"))
+                .and_then(|ids| coretemp_temperatures.get(&ids))
+                .map(|celsius| match temp_type {
+                    TemperatureType::Celsius => *celsius,
+                    TemperatureType::Kelvin => celsius + 273.15,
+                    TemperatureType::Fahrenheit => (celsius * (9.0 / 5.0)) + 32.0,
+                });
+
+            CpuDetails {
+                frequency: if used_widgets.use_cpu_frequency {
+                    get_cpu_frequency(&cpu_dir)
+                } else {
+                    None
+                },
+                temperature,
+                package_id,
+                numa_node: if used_widgets.use_cpu_topology {
+                    get_numa_node(&cpu_dir)
+                } else {
+                    None
+                },
+            }
+        })
+        .collect()
+}
+
+/// Returns the task and interrupt counters from `/proc/stat`.
+pub fn get_scheduler_stats() -> Option<SchedulerHarvest> {
+    let stat = fs::read_to_string("/proc/stat").ok()?;
+    let mut harvest = SchedulerHarvest::default();
+
+    for line in stat.lines() {
+        let mut parts = line.split_whitespace();
+        let (key, value) = match (parts.next(), parts.next().and_then(|v| v.parse().ok())) {
+            (Some(key), Some(value)) => (key, value),
+            _ => continue,
+        };
+
+        // For "intr", the first value is the total; the rest are per interrupt.
+        match key {
+            "ctxt" => harvest.context_switches = value,
+            "intr" => harvest.interrupts = value,
+            "procs_running" => harvest.running_tasks = value,
+            "procs_blocked" => harvest.blocked_tasks = value,
+            _ => {}
+        }
+    }
+
+    Some(harvest)
+}
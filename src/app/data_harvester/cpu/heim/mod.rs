@@ -23,19 +23,81 @@ pub struct CpuData {
     pub cpu_prefix: String,
     pub cpu_count: Option<usize>,
     pub cpu_usage: f64,
+    pub breakdown: CpuBreakdown,
+    pub frequency: Option<CpuFrequency>,
+    /// The temperature of the core, if it could be matched to a sensor.
+    pub temperature: Option<f32>,
+    /// The physical package (socket) that the core is on.
+    pub package_id: Option<u32>,
+    /// The NUMA node that the core belongs to.
+    pub numa_node: Option<u32>,
+}
+
+/// Details about a core that aren't derived from its CPU times.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CpuDetails {
+    pub frequency: Option<CpuFrequency>,
+    pub temperature: Option<f32>,
+    pub package_id: Option<u32>,
+    pub numa_node: Option<u32>,
+}
+
+/// The frequency of a CPU in MHz.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CpuFrequency {
+    pub current: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// How CPU time is split between categories.  When part of [`CpuData`], these are percentages of
+/// the total time; otherwise they're the cumulative times in seconds.
+///
+/// Nice time is counted as user time, and soft IRQ time is counted as IRQ time.  Only user and
+/// system time are supported outside of Linux.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CpuBreakdown {
+    pub user: f64,
+    pub system: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub steal: f64,
+}
+
+impl CpuBreakdown {
+    fn percentages_since(&self, previous: &CpuBreakdown, total_time: f64) -> CpuBreakdown {
+        let percentage = |current: f64, previous: f64| {
+            if current > previous {
+                (current - previous) * 100.0 / total_time
+            } else {
+                0.0
+            }
+        };
+
+        CpuBreakdown {
+            user: percentage(self.user, previous.user),
+            system: percentage(self.system, previous.system),
+            iowait: percentage(self.iowait, previous.iowait),
+            irq: percentage(self.irq, previous.irq),
+            steal: percentage(self.steal, previous.steal),
+        }
+    }
 }
 
 pub type CpuHarvest = Vec<CpuData>;
 
 pub type PastCpuWork = f64;
 pub type PastCpuTotal = f64;
+pub type PastCpuBreakdown = CpuBreakdown;
 
+use crate::app::{data_harvester::temperature::TemperatureType, layout_manager::UsedWidgets};
 use futures::StreamExt;
 use std::collections::VecDeque;
 
 pub async fn get_cpu_data_list(
-    show_average_cpu: bool, previous_cpu_times: &mut Vec<(PastCpuWork, PastCpuTotal)>,
-    previous_average_cpu_time: &mut Option<(PastCpuWork, PastCpuTotal)>,
+    show_average_cpu: bool, temp_type: &TemperatureType, used_widgets: &UsedWidgets,
+    previous_cpu_times: &mut Vec<(PastCpuWork, PastCpuTotal, PastCpuBreakdown)>,
+    previous_average_cpu_time: &mut Option<(PastCpuWork, PastCpuTotal, PastCpuBreakdown)>,
 ) -> crate::error::Result<CpuHarvest> {
     fn calculate_cpu_usage_percentage(
         (previous_working_time, previous_total_time): (f64, f64),
@@ -53,6 +115,20 @@ pub async fn get_cpu_data_list(
             })
     }
 
+    fn calculate_cpu_breakdown(
+        (previous_total_time, previous_breakdown): (f64, &CpuBreakdown),
+        (current_total_time, current_breakdown): (f64, &CpuBreakdown),
+    ) -> CpuBreakdown {
+        current_breakdown.percentages_since(
+            previous_breakdown,
+            if current_total_time > previous_total_time {
+                current_total_time - previous_total_time
+            } else {
+                1.0
+            },
+        )
+    }
+
     // Get all CPU times...
     let cpu_times = heim::cpu::times().await?;
     futures::pin_mut!(cpu_times);
@@ -64,28 +140,40 @@ pub async fn get_cpu_data_list(
         let second_cpu_times = heim::cpu::times().await?;
         futures::pin_mut!(second_cpu_times);
 
-        let mut new_cpu_times: Vec<(PastCpuWork, PastCpuTotal)> = Vec::new();
+        let mut new_cpu_times: Vec<(PastCpuWork, PastCpuTotal, PastCpuBreakdown)> = Vec::new();
         let mut cpu_deque: VecDeque<CpuData> = VecDeque::new();
         let mut collected_zip = cpu_times.zip(second_cpu_times).enumerate(); // Gotta move it here, can't on while line.
 
         while let Some((itx, (past, present))) = collected_zip.next().await {
             if let (Ok(past), Ok(present)) = (past, present) {
+                let past_times = convert_cpu_times(&past);
                 let present_times = convert_cpu_times(&present);
-                new_cpu_times.push(present_times);
+                let present_breakdown = convert_cpu_breakdown(&present);
+                new_cpu_times.push((present_times.0, present_times.1, present_breakdown));
                 cpu_deque.push_back(CpuData {
                     cpu_prefix: "CPU".to_string(),
                     cpu_count: Some(itx),
-                    cpu_usage: calculate_cpu_usage_percentage(
-                        convert_cpu_times(&past),
-                        present_times,
+                    cpu_usage: calculate_cpu_usage_percentage(past_times, present_times),
+                    breakdown: calculate_cpu_breakdown(
+                        (past_times.1, &convert_cpu_breakdown(&past)),
+                        (present_times.1, &present_breakdown),
                     ),
+                    frequency: None,
+                    temperature: None,
+                    package_id: None,
+                    numa_node: None,
                 });
             } else {
-                new_cpu_times.push((0.0, 0.0));
+                new_cpu_times.push((0.0, 0.0, CpuBreakdown::default()));
                 cpu_deque.push_back(CpuData {
                     cpu_prefix: "CPU".to_string(),
                     cpu_count: Some(itx),
                     cpu_usage: 0.0,
+                    breakdown: CpuBreakdown::default(),
+                    frequency: None,
+                    temperature: None,
+                    package_id: None,
+                    numa_node: None,
                 });
             }
         }
@@ -93,19 +181,23 @@ pub async fn get_cpu_data_list(
         *previous_cpu_times = new_cpu_times;
         cpu_deque
     } else {
-        let (new_cpu_times, cpu_deque): (Vec<(PastCpuWork, PastCpuTotal)>, VecDeque<CpuData>) =
-            cpu_times
-                .collect::<Vec<_>>()
-                .await
-                .iter()
-                .zip(&*previous_cpu_times)
-                .enumerate()
-                .map(|(itx, (current_cpu, (past_cpu_work, past_cpu_total)))| {
+        let (new_cpu_times, cpu_deque): (
+            Vec<(PastCpuWork, PastCpuTotal, PastCpuBreakdown)>,
+            VecDeque<CpuData>,
+        ) = cpu_times
+            .collect::<Vec<_>>()
+            .await
+            .iter()
+            .zip(&*previous_cpu_times)
+            .enumerate()
+            .map(
+                |(itx, (current_cpu, (past_cpu_work, past_cpu_total, past_cpu_breakdown)))| {
                     if let Ok(cpu_time) = current_cpu {
                         let present_times = convert_cpu_times(cpu_time);
+                        let present_breakdown = convert_cpu_breakdown(cpu_time);
 
                         (
-                            present_times,
+                            (present_times.0, present_times.1, present_breakdown),
                             CpuData {
                                 cpu_prefix: "CPU".to_string(),
                                 cpu_count: Some(itx),
@@ -113,54 +205,96 @@ pub async fn get_cpu_data_list(
                                     (*past_cpu_work, *past_cpu_total),
                                     present_times,
                                 ),
+                                breakdown: calculate_cpu_breakdown(
+                                    (*past_cpu_total, past_cpu_breakdown),
+                                    (present_times.1, &present_breakdown),
+                                ),
+                                frequency: None,
+                                temperature: None,
+                                package_id: None,
+                                numa_node: None,
                             },
                         )
                     } else {
                         (
-                            (*past_cpu_work, *past_cpu_total),
+                            (*past_cpu_work, *past_cpu_total, *past_cpu_breakdown),
                             CpuData {
                                 cpu_prefix: "CPU".to_string(),
                                 cpu_count: Some(itx),
                                 cpu_usage: 0.0,
+                                breakdown: CpuBreakdown::default(),
+                                frequency: None,
+                                temperature: None,
+                                package_id: None,
+                                numa_node: None,
                             },
                         )
                     }
-                })
-                .unzip();
+                },
+            )
+            .unzip();
 
         *previous_cpu_times = new_cpu_times;
         cpu_deque
     };
 
+    let cpu_details = get_cpu_details(cpu_deque.len(), temp_type, used_widgets);
+    for (cpu, details) in cpu_deque.iter_mut().zip(cpu_details) {
+        cpu.frequency = details.frequency;
+        cpu.temperature = details.temperature;
+        cpu.package_id = details.package_id;
+        cpu.numa_node = details.numa_node;
+    }
+
     // Get average CPU if needed... and slap it at the top
     if show_average_cpu {
         let cpu_time = heim::cpu::time().await?;
 
-        let (cpu_usage, new_average_cpu_time) = if let Some((past_cpu_work, past_cpu_total)) =
-            previous_average_cpu_time
-        {
-            let present_times = convert_cpu_times(&cpu_time);
-            (
-                calculate_cpu_usage_percentage((*past_cpu_work, *past_cpu_total), present_times),
-                present_times,
-            )
-        } else {
-            // Again, we need to do a quick timeout...
-            futures_timer::Delay::new(std::time::Duration::from_millis(100)).await;
-            let second_cpu_time = heim::cpu::time().await?;
-
-            let present_times = convert_cpu_times(&second_cpu_time);
-            (
-                calculate_cpu_usage_percentage(convert_cpu_times(&cpu_time), present_times),
-                present_times,
-            )
-        };
+        let (cpu_usage, breakdown, new_average_cpu_time) =
+            if let Some((past_cpu_work, past_cpu_total, past_cpu_breakdown)) =
+                previous_average_cpu_time
+            {
+                let present_times = convert_cpu_times(&cpu_time);
+                let present_breakdown = convert_cpu_breakdown(&cpu_time);
+                (
+                    calculate_cpu_usage_percentage(
+                        (*past_cpu_work, *past_cpu_total),
+                        present_times,
+                    ),
+                    calculate_cpu_breakdown(
+                        (*past_cpu_total, past_cpu_breakdown),
+                        (present_times.1, &present_breakdown),
+                    ),
+                    (present_times.0, present_times.1, present_breakdown),
+                )
+            } else {
+                // Again, we need to do a quick timeout...
+                futures_timer::Delay::new(std::time::Duration::from_millis(100)).await;
+                let second_cpu_time = heim::cpu::time().await?;
+
+                let past_times = convert_cpu_times(&cpu_time);
+                let present_times = convert_cpu_times(&second_cpu_time);
+                let present_breakdown = convert_cpu_breakdown(&second_cpu_time);
+                (
+                    calculate_cpu_usage_percentage(past_times, present_times),
+                    calculate_cpu_breakdown(
+                        (past_times.1, &convert_cpu_breakdown(&cpu_time)),
+                        (present_times.1, &present_breakdown),
+                    ),
+                    (present_times.0, present_times.1, present_breakdown),
+                )
+            };
 
         *previous_average_cpu_time = Some(new_average_cpu_time);
         cpu_deque.push_front(CpuData {
             cpu_prefix: "AVG".to_string(),
             cpu_count: None,
             cpu_usage,
+            breakdown,
+            frequency: get_average_frequency(&cpu_deque),
+            temperature: get_average_temperature(&cpu_deque),
+            package_id: None,
+            numa_node: None,
         })
     }
 
@@ -168,3 +302,48 @@ pub async fn get_cpu_data_list(
 
     Ok(Vec::from(cpu_deque))
 }
+
+/// Averages the current frequencies of the cores, and takes the lowest minimum and highest maximum.
+fn get_average_frequency(cpu_deque: &VecDeque<CpuData>) -> Option<CpuFrequency> {
+    let frequencies = cpu_deque
+        .iter()
+        .filter_map(|cpu| cpu.frequency)
+        .collect::<Vec<_>>();
+
+    if frequencies.is_empty() {
+        return None;
+    }
+
+    Some(CpuFrequency {
+        current: frequencies
+            .iter()
+            .map(|frequency| frequency.current)
+            .sum::<f64>()
+            / frequencies.len() as f64,
+        min: frequencies
+            .iter()
+            .filter_map(|frequency| frequency.min)
+            .fold(None, |lowest, min| {
+                Some(lowest.map_or(min, |lowest: f64| lowest.min(min)))
+            }),
+        max: frequencies
+            .iter()
+            .filter_map(|frequency| frequency.max)
+            .fold(None, |highest, max| {
+                Some(highest.map_or(max, |highest: f64| highest.max(max)))
+            }),
+    })
+}
+
+fn get_average_temperature(cpu_deque: &VecDeque<CpuData>) -> Option<f32> {
+    let temperatures = cpu_deque
+        .iter()
+        .filter_map(|cpu| cpu.temperature)
+        .collect::<Vec<_>>();
+
+    if temperatures.is_empty() {
+        None
+    } else {
+        Some(temperatures.iter().sum::<f32>() / temperatures.len() as f32)
+    }
+}
@@ -1,5 +1,11 @@
 //! Windows and macOS-specific functions regarding CPU usage.
 
+use super::{CpuBreakdown, CpuDetails};
+use crate::app::{
+    data_harvester::{cpu::SchedulerHarvest, temperature::TemperatureType},
+    layout_manager::UsedWidgets,
+};
+
 pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> (f64, f64) {
     let working_time: f64 =
         (cpu_time.user() + cpu_time.system()).get::<heim::units::time::second>();
@@ -8,3 +14,24 @@ pub fn convert_cpu_times(cpu_time: &heim::cpu::CpuTime) -> (f64, f64) {
         working_time + cpu_time.idle().get::<heim::units::time::second>(),
     )
 }
+
+pub fn convert_cpu_breakdown(cpu_time: &heim::cpu::CpuTime) -> CpuBreakdown {
+    CpuBreakdown {
+        user: cpu_time.user().get::<heim::units::time::second>(),
+        system: cpu_time.system().get::<heim::units::time::second>(),
+        ..CpuBreakdown::default()
+    }
+}
+
+/// Returns the frequency, temperature, and topology of each CPU.  This is currently unsupported
+/// outside of Linux, so no CPU will have any of these.
+pub fn get_cpu_details(
+    num_cpus: usize, _temp_type: &TemperatureType, _used_widgets: &UsedWidgets,
+) -> Vec<CpuDetails> {
+    vec![CpuDetails::default(); num_cpus]
+}
+
+/// Returns the task and interrupt counters.  This is currently unsupported outside of Linux.
+pub fn get_scheduler_stats() -> Option<SchedulerHarvest> {
+    None
+}
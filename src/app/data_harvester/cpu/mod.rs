@@ -3,6 +3,8 @@
 //! For CPU usage, Linux, macOS, and Windows are handled by Heim.
 //!
 //! For load average, macOS and Linux are supported through Heim.
+//!
+//! For task and interrupt counts, only Linux is supported, through `/proc/stat`.
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))] {
@@ -12,3 +14,16 @@ cfg_if::cfg_if! {
 }
 
 pub type LoadAvgHarvest = [f32; 3];
+
+/// Task and interrupt counters, as reported by `/proc/stat` on Linux.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SchedulerHarvest {
+    /// The number of tasks currently running or waiting to run.
+    pub running_tasks: u64,
+    /// The number of tasks currently blocked on I/O.
+    pub blocked_tasks: u64,
+    /// The total number of context switches since boot.
+    pub context_switches: u64,
+    /// The total number of interrupts serviced since boot.
+    pub interrupts: u64,
+}
@@ -32,3 +32,49 @@ pub fn get_device_name(partition: &Partition) -> String {
         "Name Unavailable".to_string()
     }
 }
+
+/// Returns the mount options for each mount point, as listed in `/proc/self/mounts`.
+pub fn get_mount_options() -> std::collections::HashMap<String, String> {
+    std::fs::read_to_string("/proc/self/mounts")
+        .map(|mounts| {
+            mounts
+                .lines()
+                .filter_map(|line| {
+                    // Example: `/dev/sda3 /home ext4 rw,relatime,data=ordered 0 0`
+                    let mut parts = line.split_whitespace().skip(1);
+                    let mount_point = parts.next()?;
+                    let options = parts.nth(1)?;
+
+                    Some((mount_point.to_string(), options.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the I/O times of each device, as listed in `/proc/diskstats`.
+pub fn get_io_times() -> std::collections::HashMap<String, super::IoTimes> {
+    std::fs::read_to_string("/proc/diskstats")
+        .map(|diskstats| {
+            diskstats
+                .lines()
+                .filter_map(|line| {
+                    // Example: `8 0 sda 5112 1398 365142 2493 4830 7432 217786 12047 0 11880 14541 ...`
+                    // See https://www.kernel.org/doc/Documentation/iostats.txt for the fields.
+                    let parts = line.split_whitespace().collect::<Vec<_>>();
+                    let field = |index: usize| parts.get(index)?.parse::<u64>().ok();
+
+                    Some((
+                        parts.get(2)?.to_string(),
+                        super::IoTimes {
+                            read_time: field(6)?,
+                            write_time: field(10)?,
+                            io_time: field(12)?,
+                            weighted_io_time: field(13)?,
+                        },
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
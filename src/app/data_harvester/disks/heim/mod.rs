@@ -1,4 +1,12 @@
-use crate::app::Filter;
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use futures::channel::oneshot;
+
+use crate::app::{Filter, FilterField};
 
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
@@ -10,19 +18,179 @@ cfg_if::cfg_if! {
     }
 }
 
+/// How long to wait on a non-physical filesystem, such as a network mount, before giving up on it.
+const NON_PHYSICAL_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// How long to skip a filesystem for after it has failed to respond in time.
+const UNRESPONSIVE_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Which non-physical filesystems (i.e. network and virtual ones) are listed alongside physical ones.
+#[derive(Debug, Clone)]
+pub enum ExtraFilesystems {
+    None,
+    All,
+    /// Only filesystems of these types, such as "nfs4" or "tmpfs".
+    Types(Vec<String>),
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DiskHarvest {
     pub name: String,
     pub mount_point: String,
+    /// The filesystem type, i.e. "ext4".
+    pub fs_type: String,
+    /// The comma-separated mount options.  This is empty if they are unknown.
+    pub mount_options: String,
+    pub is_read_only: Option<bool>,
     pub free_space: Option<u64>,
     pub used_space: Option<u64>,
     pub total_space: Option<u64>,
+    pub free_inodes: Option<u64>,
+    pub used_inodes: Option<u64>,
+    pub total_inodes: Option<u64>,
+}
+
+/// Inode counts and flags of a mounted filesystem that heim doesn't provide.
+#[derive(Debug, Default)]
+struct FsStats {
+    free_inodes: Option<u64>,
+    total_inodes: Option<u64>,
+    is_read_only: Option<bool>,
+}
+
+/// The space and inode usage of a partition.
+#[derive(Debug, Default)]
+struct PartitionUsage {
+    free_space: Option<u64>,
+    used_space: Option<u64>,
+    total_space: Option<u64>,
+    fs_stats: FsStats,
+}
+
+async fn get_partition_usage(partition: &heim::disk::Partition) -> PartitionUsage {
+    let mut partition_usage = PartitionUsage {
+        fs_stats: get_fs_stats(partition),
+        ..PartitionUsage::default()
+    };
+
+    // The usage line can fail in some cases (for example, if you use Void Linux + LUKS,
+    // see https://github.com/ClementTsang/bottom/issues/419 for details).  As such, check
+    // it like this instead.
+    if let Ok(usage) = heim::disk::usage(partition.mount_point().to_path_buf()).await {
+        partition_usage.free_space = Some(usage.free().get::<heim::units::information::byte>());
+        partition_usage.used_space = Some(usage.used().get::<heim::units::information::byte>());
+        partition_usage.total_space = Some(usage.total().get::<heim::units::information::byte>());
+    }
+
+    partition_usage
+}
+
+/// A mount point that failed to respond in time.
+#[derive(Debug)]
+pub struct UnresponsiveMount {
+    last_attempt: Instant,
+    /// The result of the attempt that timed out, which arrives if its thread ever finishes.  No
+    /// new attempt is made while this is pending, so a hung mount only ever ties up one thread.
+    pending_usage: oneshot::Receiver<PartitionUsage>,
+}
+
+impl UnresponsiveMount {
+    /// Whether the mount should be skipped, which it is until the last attempt has finished and
+    /// the retry delay has passed.
+    fn is_skipped(&mut self) -> bool {
+        let is_pending = matches!(self.pending_usage.try_recv(), Ok(None));
+        is_pending || self.last_attempt.elapsed() < UNRESPONSIVE_RETRY_DELAY
+    }
+}
+
+/// Gets the usage of a partition on a separate thread, giving up if it takes too long.  This stops
+/// a hung network mount from stalling the entire collection thread.  If it times out, the
+/// still-running attempt is returned as an error.
+async fn get_partition_usage_with_timeout(
+    partition: heim::disk::Partition,
+) -> Result<PartitionUsage, UnresponsiveMount> {
+    use futures::future::{select, Either};
+
+    let (sender, receiver) = oneshot::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(futures::executor::block_on(get_partition_usage(&partition)));
+    });
+
+    match select(receiver, futures_timer::Delay::new(NON_PHYSICAL_TIMEOUT)).await {
+        Either::Left((Ok(partition_usage), _)) => Ok(partition_usage),
+        Either::Left((Err(_), _)) => {
+            // The thread gave up without a result, so there's nothing left to wait on.
+            let (_, pending_usage) = oneshot::channel();
+            Err(UnresponsiveMount {
+                last_attempt: Instant::now(),
+                pending_usage,
+            })
+        }
+        Either::Right((_, pending_usage)) => Err(UnresponsiveMount {
+            last_attempt: Instant::now(),
+            pending_usage,
+        }),
+    }
+}
+
+#[cfg(unix)]
+#[allow(clippy::useless_conversion)] // The inode counts are only u32s on some platforms, like macOS.
+fn get_fs_stats(partition: &heim::disk::Partition) -> FsStats {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let path = match CString::new(partition.mount_point().as_os_str().as_bytes()) {
+        Ok(path) => path,
+        Err(_) => return FsStats::default(),
+    };
+
+    let mut stats = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    let result = unsafe { libc::statvfs(path.as_ptr(), stats.as_mut_ptr()) };
+    if result == 0 {
+        let stats = unsafe { stats.assume_init() };
+
+        FsStats {
+            free_inodes: Some(u64::from(stats.f_ffree)),
+            total_inodes: Some(u64::from(stats.f_files)),
+            is_read_only: Some(stats.f_flag & libc::ST_RDONLY != 0),
+        }
+    } else {
+        FsStats::default()
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn get_fs_stats(partition: &heim::disk::Partition) -> FsStats {
+    use heim::disk::os::windows::{Flags, PartitionExt};
+
+    // Windows has no concept of inodes.
+    FsStats {
+        free_inodes: None,
+        total_inodes: None,
+        is_read_only: Some(partition.flags().contains(Flags::FILE_READ_ONLY_VOLUME)),
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct IoData {
     pub read_bytes: u64,
     pub write_bytes: u64,
+    /// The number of reads completed.
+    pub read_count: u64,
+    /// The number of writes completed.
+    pub write_count: u64,
+    /// How long was spent on I/O.  This is only available on Linux.
+    pub io_times: Option<IoTimes>,
+}
+
+/// Cumulative I/O times of a device in milliseconds, as reported by `/proc/diskstats`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IoTimes {
+    pub read_time: u64,
+    pub write_time: u64,
+    /// How long the device had I/O in flight.
+    pub io_time: u64,
+    /// The I/O time weighted by the number of requests in flight.
+    pub weighted_io_time: u64,
 }
 
 pub type IoHarvest = std::collections::HashMap<String, Option<IoData>>;
@@ -37,6 +205,8 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
     let mut io_hash: std::collections::HashMap<String, Option<IoData>> =
         std::collections::HashMap::new();
 
+    let io_times = get_io_times();
+
     let counter_stream = heim::disk::io_counters().await?;
     futures::pin_mut!(counter_stream);
 
@@ -49,6 +219,9 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
                 Some(IoData {
                     read_bytes: io.read_bytes().get::<heim::units::information::byte>(),
                     write_bytes: io.write_bytes().get::<heim::units::information::byte>(),
+                    read_count: io.read_count(),
+                    write_count: io.write_count(),
+                    io_times: io_times.get(mount_point).copied(),
                 }),
             );
         }
@@ -57,8 +230,71 @@ pub async fn get_io_usage(actually_get: bool) -> crate::utils::error::Result<Opt
     Ok(Some(io_hash))
 }
 
+/// Returns whether a disk passes the filters.  The disk filter matches against `name` or
+/// `file_system`, and the mount filter against `mount_point` or `options`, depending on their
+/// fields.
+pub fn keep_disk_entry(
+    disk_filter: &Option<Filter>, mount_filter: &Option<Filter>, name: &str, file_system: &str,
+    mount_point: &str, options: &str,
+) -> bool {
+    // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
+    //
+    // For implementation, we do this as follows:
+    // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
+    // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
+    // 3. If there is any filter where `is_list_ignored` is `false`, then the entry wasn't allowed through it, so deny it.
+    // 4. Anything else is allowed.
+
+    let filter_check_map = [
+        (
+            disk_filter,
+            match disk_filter.as_ref().map(|filter| filter.field) {
+                Some(FilterField::FileSystem) => file_system,
+                _ => name,
+            },
+        ),
+        (
+            mount_filter,
+            match mount_filter.as_ref().map(|filter| filter.field) {
+                Some(FilterField::MountOptions) => options,
+                _ => mount_point,
+            },
+        ),
+    ];
+
+    let matches_filter = |filter: &Filter, text: &str| filter.list.iter().any(|r| r.is_match(text));
+
+    // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
+    // immediately allow it!
+    let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
+        if let Some(filter) = filter {
+            !filter.is_list_ignored && matches_filter(filter, text)
+        } else {
+            false
+        }
+    });
+
+    if matches_allow_list {
+        true
+    } else {
+        // If it doesn't match an allow list, then check if it is denied.
+        // That is, if it matches in a reject filter or if there is an allow list, then reject.  Otherwise, we always keep it.
+        !filter_check_map.iter().any(|(filter, text)| {
+            if let Some(filter) = filter {
+                !filter.is_list_ignored || matches_filter(filter, text)
+            } else {
+                false
+            }
+        })
+    }
+}
+
+/// Gets the usage of every disk that passes the filters.  Mount points that fail to respond in time
+/// are tracked in `unresponsive_mounts` so they can be skipped for a while.
 pub async fn get_disk_usage(
     actually_get: bool, disk_filter: &Option<Filter>, mount_filter: &Option<Filter>,
+    extra_filesystems: &ExtraFilesystems,
+    unresponsive_mounts: &mut HashMap<String, UnresponsiveMount>,
 ) -> crate::utils::error::Result<Option<Vec<DiskHarvest>>> {
     if !actually_get {
         return Ok(None);
@@ -67,84 +303,120 @@ pub async fn get_disk_usage(
     use futures::StreamExt;
 
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
-    let partitions_stream = heim::disk::partitions_physical().await?;
-    futures::pin_mut!(partitions_stream);
-
-    while let Some(part) = partitions_stream.next().await {
-        if let Ok(partition) = part {
-            let name = get_device_name(&partition);
-
-            let mount_point = (partition
-                .mount_point()
-                .to_str()
-                .unwrap_or("Name Unavailable"))
-            .to_string();
-
-            // Precedence ordering in the case where name and mount filters disagree, "allow" takes precedence over "deny".
-            //
-            // For implementation, we do this as follows:
-            // 1. Is the entry allowed through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `false`? If so, we always keep this entry.
-            // 2. Is the entry denied through any filter? That is, does it match an entry in a filter where `is_list_ignored` is `true`? If so, we always deny this entry.
-            // 3. Anything else is allowed.
-
-            let filter_check_map = [(disk_filter, &name), (mount_filter, &mount_point)];
-
-            // This represents case 1.  That is, if there is a match in an allowing list - if there is, then
-            // immediately allow it!
-            let matches_allow_list = filter_check_map.iter().any(|(filter, text)| {
-                if let Some(filter) = filter {
-                    if !filter.is_list_ignored {
-                        for r in &filter.list {
-                            if r.is_match(text) {
-                                return true;
-                            }
-                        }
+
+    let mount_options = get_mount_options();
+
+    let physical_partitions = heim::disk::partitions_physical()
+        .await?
+        .filter_map(|part| async move { part.ok() })
+        .collect::<Vec<_>>()
+        .await;
+
+    // Pair each partition with whether it's physical.
+    let partitions: Vec<(heim::disk::Partition, bool)> =
+        if let ExtraFilesystems::None = extra_filesystems {
+            physical_partitions
+                .into_iter()
+                .map(|partition| (partition, true))
+                .collect()
+        } else {
+            let physical_mount_points = physical_partitions
+                .iter()
+                .map(|partition| partition.mount_point().to_path_buf())
+                .collect::<HashSet<PathBuf>>();
+
+            heim::disk::partitions()
+                .await?
+                .filter_map(|part| async move { part.ok() })
+                .collect::<Vec<_>>()
+                .await
+                .into_iter()
+                .filter_map(|partition| {
+                    let is_physical = physical_mount_points.contains(partition.mount_point());
+                    let is_included = match extra_filesystems {
+                        ExtraFilesystems::None => false,
+                        ExtraFilesystems::All => true,
+                        ExtraFilesystems::Types(types) => types
+                            .iter()
+                            .any(|fs_type| fs_type == partition.file_system().as_str()),
+                    };
+
+                    if is_physical || is_included {
+                        Some((partition, is_physical))
+                    } else {
+                        None
                     }
-                }
-                false
-            });
+                })
+                .collect()
+        };
+
+    for (partition, is_physical) in partitions {
+        let name = get_device_name(&partition);
 
-            let to_keep = if matches_allow_list {
-                true
+        let mount_point = (partition
+            .mount_point()
+            .to_str()
+            .unwrap_or("Name Unavailable"))
+        .to_string();
+
+        let file_system = partition.file_system().as_str().to_string();
+        let options = mount_options
+            .get(&mount_point)
+            .map(String::as_str)
+            .unwrap_or("");
+
+        let to_keep = keep_disk_entry(
+            disk_filter,
+            mount_filter,
+            &name,
+            &file_system,
+            &mount_point,
+            options,
+        );
+
+        if to_keep {
+            let partition_usage = if is_physical {
+                get_partition_usage(&partition).await
             } else {
-                // If it doesn't match an allow list, then check if it is denied.
-                // That is, if it matches in a reject filter, then reject.  Otherwise, we always keep it.
-                !filter_check_map.iter().any(|(filter, text)| {
-                    if let Some(filter) = filter {
-                        if filter.is_list_ignored {
-                            for r in &filter.list {
-                                if r.is_match(text) {
-                                    return true;
-                                }
-                            }
+                let is_skipped = unresponsive_mounts
+                    .get_mut(&mount_point)
+                    .map(|unresponsive_mount| unresponsive_mount.is_skipped())
+                    .unwrap_or(false);
+
+                if is_skipped {
+                    PartitionUsage::default()
+                } else {
+                    match get_partition_usage_with_timeout(partition).await {
+                        Ok(partition_usage) => {
+                            unresponsive_mounts.remove(&mount_point);
+                            partition_usage
+                        }
+                        Err(unresponsive_mount) => {
+                            unresponsive_mounts.insert(mount_point.clone(), unresponsive_mount);
+                            PartitionUsage::default()
                         }
                     }
-                    false
-                })
+                }
             };
 
-            if to_keep {
-                // The usage line can fail in some cases (for example, if you use Void Linux + LUKS,
-                // see https://github.com/ClementTsang/bottom/issues/419 for details).  As such, check
-                // it like this instead.
-                if let Ok(usage) = heim::disk::usage(partition.mount_point().to_path_buf()).await {
-                    vec_disks.push(DiskHarvest {
-                        free_space: Some(usage.free().get::<heim::units::information::byte>()),
-                        used_space: Some(usage.used().get::<heim::units::information::byte>()),
-                        total_space: Some(usage.total().get::<heim::units::information::byte>()),
-                        mount_point,
-                        name,
-                    });
-                } else {
-                    vec_disks.push(DiskHarvest {
-                        free_space: None,
-                        used_space: None,
-                        total_space: None,
-                        mount_point,
-                        name,
-                    });
-                }
-            }
+            let fs_stats = partition_usage.fs_stats;
+            vec_disks.push(DiskHarvest {
+                fs_type: file_system,
+                mount_options: options.to_string(),
+                is_read_only: fs_stats.is_read_only,
+                free_space: partition_usage.free_space,
+                used_space: partition_usage.used_space,
+                total_space: partition_usage.total_space,
+                free_inodes: fs_stats.free_inodes,
+                used_inodes: fs_stats.total_inodes.and_then(|total_inodes| {
+                    fs_stats
+                        .free_inodes
+                        .map(|free_inodes| total_inodes.saturating_sub(free_inodes))
+                }),
+                total_inodes: fs_stats.total_inodes,
+                mount_point,
+                name,
+            });
         }
     }
 
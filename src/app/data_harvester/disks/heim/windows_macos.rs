@@ -12,3 +12,15 @@ pub fn get_device_name(partition: &Partition) -> String {
         "Name Unavailable".to_string()
     }
 }
+
+/// Returns the mount options for each mount point.  This is currently unsupported outside of
+/// Linux, so no mount will have any options.
+pub fn get_mount_options() -> std::collections::HashMap<String, String> {
+    std::collections::HashMap::new()
+}
+
+/// Returns the I/O times of each device.  This is currently unsupported outside of Linux, so no
+/// device will have any.
+pub fn get_io_times() -> std::collections::HashMap<String, super::IoTimes> {
+    std::collections::HashMap::new()
+}
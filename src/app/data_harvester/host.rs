@@ -0,0 +1,150 @@
+//! Data collection for general information about the host, such as its name, OS, and uptime.
+//!
+//! For Linux, this is mostly read from procfs.  Other platforms use sysinfo, and don't report a
+//! thread count.
+
+#[cfg(not(target_os = "linux"))]
+use sysinfo::{System, SystemExt};
+
+#[derive(Clone, Debug, Default)]
+pub struct HostHarvest {
+    pub hostname: Option<String>,
+    /// The name and version of the OS, i.e. "Debian GNU/Linux 12 (bookworm)".
+    pub os_name: Option<String>,
+    pub kernel_version: Option<String>,
+    pub uptime_secs: Option<u64>,
+    /// The number of login sessions, counted the same way as `uptime` and `who`.
+    pub user_count: Option<usize>,
+    pub process_count: Option<usize>,
+    pub thread_count: Option<usize>,
+    /// The local time zone's offset from UTC, in seconds.
+    pub utc_offset_secs: Option<i32>,
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_host_data() -> HostHarvest {
+    use std::fs;
+
+    fn read_trimmed(path: &str) -> Option<String> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|contents| contents.trim().to_string())
+    }
+
+    let os_name = fs::read_to_string("/etc/os-release")
+        .ok()
+        .and_then(|os_release| {
+            os_release.lines().find_map(|line| {
+                line.strip_prefix("PRETTY_NAME=")
+                    .map(|name| name.trim_matches('"').to_string())
+            })
+        });
+
+    let uptime_secs = read_trimmed("/proc/uptime").and_then(|uptime| {
+        uptime
+            .split_whitespace()
+            .next()
+            .and_then(|secs| secs.parse::<f64>().ok())
+            .map(|secs| secs as u64)
+    });
+
+    // Every process has a directory named after its PID.
+    let process_count = fs::read_dir("/proc").ok().map(|entries| {
+        entries
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .map(|name| name.bytes().all(|byte| byte.is_ascii_digit()))
+                    .unwrap_or(false)
+            })
+            .count()
+    });
+
+    // The fourth field of /proc/loadavg is "<running>/<total>" scheduling entities, which are
+    // threads.
+    let thread_count = read_trimmed("/proc/loadavg").and_then(|loadavg| {
+        loadavg
+            .split_whitespace()
+            .nth(3)
+            .and_then(|tasks| tasks.split('/').nth(1))
+            .and_then(|total| total.parse().ok())
+    });
+
+    HostHarvest {
+        hostname: read_trimmed("/proc/sys/kernel/hostname"),
+        os_name,
+        kernel_version: read_trimmed("/proc/sys/kernel/osrelease"),
+        uptime_secs,
+        user_count: get_user_count(),
+        process_count,
+        thread_count,
+        utc_offset_secs: get_utc_offset(),
+    }
+}
+
+/// Expects the processes of `sys` to have been refreshed.
+#[cfg(not(target_os = "linux"))]
+pub fn get_host_data(sys: &System) -> HostHarvest {
+    HostHarvest {
+        hostname: sys.get_host_name(),
+        os_name: sys.get_long_os_version(),
+        kernel_version: sys.get_kernel_version(),
+        uptime_secs: Some(sys.get_uptime()),
+        user_count: get_user_count(),
+        process_count: Some(sys.get_processes().len()),
+        thread_count: None,
+        utc_offset_secs: get_utc_offset(),
+    }
+}
+
+/// Counts the user processes in the utmp database.
+#[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos"))]
+fn get_user_count() -> Option<usize> {
+    let mut user_count = 0;
+
+    // The utmp functions aren't thread-safe, but only the collection thread uses them.
+    unsafe {
+        libc::setutxent();
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+            if (*entry).ut_type == libc::USER_PROCESS {
+                user_count += 1;
+            }
+        }
+        libc::endutxent();
+    }
+
+    Some(user_count)
+}
+
+#[cfg(not(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos")))]
+fn get_user_count() -> Option<usize> {
+    None
+}
+
+#[cfg(target_family = "unix")]
+fn get_utc_offset() -> Option<i32> {
+    // The time crate refuses to look up the local offset on Unix, as it can't be sure that no
+    // other thread is changing the environment at the same time.  Nothing here does.
+    let now = unsafe { libc::time(std::ptr::null_mut()) };
+    let mut local_time = std::mem::MaybeUninit::<libc::tm>::uninit();
+    let result = unsafe { libc::localtime_r(&now, local_time.as_mut_ptr()) };
+    if result.is_null() {
+        None
+    } else {
+        let local_time = unsafe { local_time.assume_init() };
+        Some(local_time.tm_gmtoff as i32)
+    }
+}
+
+#[cfg(not(target_family = "unix"))]
+fn get_utc_offset() -> Option<i32> {
+    time::UtcOffset::current_local_offset()
+        .ok()
+        .map(|offset| offset.whole_seconds())
+}
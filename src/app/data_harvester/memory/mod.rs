@@ -1,6 +1,8 @@
 //! Data collection for memory.
 //!
 //! For Linux, macOS, and Windows, this is handled by Heim.
+//!
+//! For per-NUMA node memory, only Linux is supported, through sysfs.
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))] {
@@ -8,3 +10,23 @@ cfg_if::cfg_if! {
         pub use self::general::*;
     }
 }
+
+cfg_if::cfg_if! {
+    if #[cfg(target_os = "linux")] {
+        pub mod numa;
+        pub use self::numa::*;
+    }
+}
+
+/// Memory usage and allocation counters of a NUMA node.
+#[derive(Debug, Clone, Default)]
+pub struct NumaNodeHarvest {
+    pub node: u32,
+    pub mem_total_in_kib: u64,
+    pub mem_used_in_kib: u64,
+    pub mem_free_in_kib: u64,
+    /// The total number of pages allocated on this node that were meant for another node.
+    pub numa_miss: u64,
+    /// The total number of pages meant for this node that were allocated on another node.
+    pub numa_foreign: u64,
+}
@@ -0,0 +1,90 @@
+//! Per-NUMA node memory data collection for Linux, through sysfs.
+
+use super::NumaNodeHarvest;
+use std::{fs, path::Path};
+
+/// Returns the memory usage of every NUMA node, sorted by node number.
+pub fn get_numa_data() -> crate::utils::error::Result<Vec<NumaNodeHarvest>> {
+    let mut nodes = Vec::new();
+
+    for entry in fs::read_dir("/sys/devices/system/node")? {
+        let entry = entry?;
+        let node = match entry
+            .file_name()
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix("node"))
+            .and_then(|node| node.parse::<u32>().ok())
+        {
+            Some(node) => node,
+            None => continue,
+        };
+
+        if let Some(harvest) = get_node_data(node, &entry.path()) {
+            nodes.push(harvest);
+        }
+    }
+
+    nodes.sort_by_key(|harvest| harvest.node);
+    Ok(nodes)
+}
+
+fn get_node_data(node: u32, node_dir: &Path) -> Option<NumaNodeHarvest> {
+    let meminfo = fs::read_to_string(node_dir.join("meminfo")).ok()?;
+
+    // Lines look like "Node 0 MemTotal:        6158152 kB".  All values are in KiB.
+    let mut mem_total = 0;
+    let mut mem_free = 0;
+    let mut file_pages = 0;
+    let mut s_reclaimable = 0;
+    let mut shmem = 0;
+
+    for line in meminfo.lines() {
+        let mut parts = line.split_whitespace().skip(2);
+        if let (Some(label), Some(value)) = (parts.next(), parts.next()) {
+            let to_write = match label {
+                "MemTotal:" => &mut mem_total,
+                "MemFree:" => &mut mem_free,
+                "FilePages:" => &mut file_pages,
+                "SReclaimable:" => &mut s_reclaimable,
+                "Shmem:" => &mut shmem,
+                _ => continue,
+            };
+
+            if let Ok(value) = value.parse::<u64>() {
+                *to_write = value;
+            }
+        }
+    }
+
+    // Like the overall memory usage, cached memory is not counted as used.  File pages include
+    // buffers here.
+    let cached = (file_pages + s_reclaimable).saturating_sub(shmem);
+    let mem_used = mem_total.saturating_sub(mem_free + cached);
+
+    let mut numa_miss = 0;
+    let mut numa_foreign = 0;
+    if let Ok(numastat) = fs::read_to_string(node_dir.join("numastat")) {
+        for line in numastat.lines() {
+            let mut parts = line.split_whitespace();
+            let (label, value) = match (parts.next(), parts.next().and_then(|v| v.parse().ok())) {
+                (Some(label), Some(value)) => (label, value),
+                _ => continue,
+            };
+
+            match label {
+                "numa_miss" => numa_miss = value,
+                "numa_foreign" => numa_foreign = value,
+                _ => {}
+            }
+        }
+    }
+
+    Some(NumaNodeHarvest {
+        node,
+        mem_total_in_kib: mem_total,
+        mem_used_in_kib: mem_used,
+        mem_free_in_kib: mem_free,
+        numa_miss,
+        numa_foreign,
+    })
+}
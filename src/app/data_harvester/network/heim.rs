@@ -1,6 +1,6 @@
 //! Gets network data via heim.
 
-use super::NetworkHarvest;
+use super::{is_interface_kept, NetworkHarvest};
 use std::time::Instant;
 
 // FIXME: Eventually make it so that this thing also takes individual usage into account, so we can allow for showing per-interface!
@@ -21,24 +21,7 @@ pub async fn get_network_data(
 
     while let Some(io) = io_data.next().await {
         if let Ok(io) = io {
-            let to_keep = if let Some(filter) = filter {
-                if filter.is_list_ignored {
-                    let mut ret = true;
-                    for r in &filter.list {
-                        if r.is_match(io.interface()) {
-                            ret = false;
-                            break;
-                        }
-                    }
-                    ret
-                } else {
-                    true
-                }
-            } else {
-                true
-            };
-
-            if to_keep {
+            if is_interface_kept(filter, io.interface()) {
                 // TODO: Use bytes as the default instead, perhaps?
                 // Since you might have to do a double conversion (bytes -> bits -> bytes) in some cases;
                 // but if you stick to bytes, then in the bytes, case, you do no conversion, and in the bits case,
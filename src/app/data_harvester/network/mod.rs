@@ -13,6 +13,8 @@ cfg_if::cfg_if! {
     }
 }
 
+use crate::app::{Filter, FilterField};
+
 #[derive(Default, Clone, Debug)]
 /// All units in bits.
 pub struct NetworkHarvest {
@@ -28,3 +30,74 @@ impl NetworkHarvest {
         self.tx = 0;
     }
 }
+
+/// Returns whether an interface should be counted towards the network totals.
+pub fn is_interface_kept(filter: &Option<Filter>, interface: &str) -> bool {
+    if let Some(filter) = filter {
+        match filter.field {
+            FilterField::InterfaceType => filter.keep_entry(get_interface_type(interface)),
+            _ => filter.keep_entry(interface),
+        }
+    } else {
+        true
+    }
+}
+
+/// Classifies an interface as "loopback", "virtual", or "physical".
+#[cfg(target_os = "linux")]
+fn get_interface_type(interface: &str) -> &'static str {
+    const IFF_LOOPBACK: u32 = 0x8;
+
+    let interface_dir = std::path::Path::new("/sys/class/net").join(interface);
+    let flags = std::fs::read_to_string(interface_dir.join("flags"))
+        .ok()
+        .and_then(|flags| u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16).ok())
+        .unwrap_or(0);
+
+    if flags & IFF_LOOPBACK != 0 {
+        "loopback"
+    } else if interface_dir.join("device").exists() {
+        // Only interfaces backed by actual hardware have a device.
+        "physical"
+    } else {
+        "virtual"
+    }
+}
+
+/// Classifies an interface as "loopback", "virtual", or "physical".
+///
+/// There's no cheap way to ask the OS for this outside of Linux, so this goes by common
+/// interface names instead.
+#[cfg(not(target_os = "linux"))]
+fn get_interface_type(interface: &str) -> &'static str {
+    const VIRTUAL_PREFIXES: [&str; 9] = [
+        "utun",
+        "bridge",
+        "awdl",
+        "llw",
+        "gif",
+        "stf",
+        "vmnet",
+        "vEthernet",
+        "docker",
+    ];
+
+    let lowercase = interface.to_lowercase();
+    let is_loopback = lowercase.contains("loopback")
+        || lowercase
+            .strip_prefix("lo")
+            .map(|index| index.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or(false);
+
+    if is_loopback {
+        "loopback"
+    } else if VIRTUAL_PREFIXES
+        .iter()
+        .any(|prefix| lowercase.starts_with(&prefix.to_lowercase()))
+        || lowercase.contains("virtual")
+    {
+        "virtual"
+    } else {
+        "physical"
+    }
+}
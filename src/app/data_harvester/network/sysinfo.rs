@@ -1,6 +1,6 @@
 //! Gets network data via sysinfo.
 
-use super::NetworkHarvest;
+use super::{is_interface_kept, NetworkHarvest};
 use std::time::Instant;
 
 pub async fn get_network_data(
@@ -19,20 +19,7 @@ pub async fn get_network_data(
 
     let networks = sys.get_networks();
     for (name, network) in networks {
-        let to_keep = if let Some(filter) = filter {
-            let mut ret = filter.is_list_ignored;
-            for r in &filter.list {
-                if r.is_match(name) {
-                    ret = !filter.is_list_ignored;
-                    break;
-                }
-            }
-            ret
-        } else {
-            true
-        };
-
-        if to_keep {
+        if is_interface_kept(filter, name) {
             total_rx += network.get_total_received() * 8;
             total_tx += network.get_total_transmitted() * 8;
         }
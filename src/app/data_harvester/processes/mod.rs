@@ -41,6 +41,9 @@ pub enum ProcessSorting {
     State,
     User,
     Count,
+    CpuHistory,
+    MemHistory,
+    MemGrowth,
 }
 
 impl std::fmt::Display for ProcessSorting {
@@ -62,6 +65,9 @@ impl std::fmt::Display for ProcessSorting {
                 ProcessSorting::Pid => "PID",
                 ProcessSorting::Count => "Count",
                 ProcessSorting::User => "User",
+                ProcessSorting::CpuHistory => "CPU Hist",
+                ProcessSorting::MemHistory => "Mem Hist",
+                ProcessSorting::MemGrowth => "Growth",
             }
         )
     }
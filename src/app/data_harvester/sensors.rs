@@ -0,0 +1,178 @@
+//! Data collection for non-temperature hardware sensors, such as fans, voltages,
+//! currents, and power.
+//!
+//! For Linux, this is read from hwmon in sysfs.  Other platforms are currently unsupported.
+
+use crate::app::Filter;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensorType {
+    Fan,
+    Voltage,
+    Current,
+    Power,
+}
+
+impl SensorType {
+    /// Returns the unit that values of this sensor type are stored in.
+    pub fn get_unit(&self) -> &str {
+        match self {
+            SensorType::Fan => "RPM",
+            SensorType::Voltage => "V",
+            SensorType::Current => "A",
+            SensorType::Power => "W",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SensorHarvest {
+    /// The name of the chip the sensor belongs to, i.e. "nct6775".
+    pub chip: String,
+    pub label: String,
+    pub sensor_type: SensorType,
+    /// The current reading, in the unit given by [`SensorType::get_unit`].
+    pub value: f64,
+}
+
+fn is_sensor_filtered(filter: &Option<Filter>, text: &str) -> bool {
+    if let Some(filter) = filter {
+        filter.keep_entry(text)
+    } else {
+        true
+    }
+}
+
+pub async fn get_sensor_data(
+    actually_get: bool, filter: &Option<Filter>,
+) -> crate::utils::error::Result<Option<Vec<SensorHarvest>>> {
+    if !actually_get {
+        return Ok(None);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Ok(Some(get_hwmon_sensors(filter)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = filter;
+        Ok(Some(Vec::new()))
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn get_hwmon_sensors(filter: &Option<Filter>) -> Vec<SensorHarvest> {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    fn read_trimmed(path: &Path) -> Option<String> {
+        fs::read_to_string(path)
+            .ok()
+            .map(|contents| contents.trim().to_string())
+    }
+
+    /// Splits an attribute file name like "fan2_input" into its sensor type, its base name
+    /// ("fan2"), the index, and the scale needed to convert the raw value.
+    fn parse_attribute(file_name: &str) -> Option<(SensorType, &str, u32, f64)> {
+        let base = file_name
+            .strip_suffix("_input")
+            .or_else(|| file_name.strip_suffix("_average"))?;
+        let index_start = base.find(|c: char| c.is_ascii_digit())?;
+        let index = base[index_start..].parse::<u32>().ok()?;
+
+        // Fans are in RPM, voltages in mV, currents in mA, and power in µW.
+        let (sensor_type, scale) = match &base[..index_start] {
+            "fan" => (SensorType::Fan, 1.0),
+            "in" => (SensorType::Voltage, 1_000.0),
+            "curr" => (SensorType::Current, 1_000.0),
+            "power" => (SensorType::Power, 1_000_000.0),
+            _ => return None,
+        };
+
+        // Only power exposes an average reading.
+        if file_name.ends_with("_average") && sensor_type != SensorType::Power {
+            return None;
+        }
+
+        Some((sensor_type, base, index, scale))
+    }
+
+    let mut sensors = Vec::new();
+
+    let mut hwmon_dirs = match fs::read_dir("/sys/class/hwmon") {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .collect::<Vec<PathBuf>>(),
+        Err(_) => return sensors,
+    };
+    hwmon_dirs.sort();
+
+    for hwmon_dir in hwmon_dirs {
+        // Older kernels put the attributes in the device directory instead.
+        let hwmon_dir = if hwmon_dir.join("name").exists() {
+            hwmon_dir
+        } else {
+            hwmon_dir.join("device")
+        };
+
+        let chip = match read_trimmed(&hwmon_dir.join("name")) {
+            Some(chip) => chip,
+            None => continue,
+        };
+
+        let file_names = match fs::read_dir(&hwmon_dir) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<_>>(),
+            Err(_) => continue,
+        };
+
+        let mut chip_sensors = file_names
+            .iter()
+            .filter_map(|file_name| {
+                let (sensor_type, base, index, scale) = parse_attribute(file_name)?;
+
+                // Prefer the instantaneous power reading if there is one.
+                if file_name.ends_with("_average")
+                    && file_names.contains(&format!("{}_input", base))
+                {
+                    return None;
+                }
+
+                let raw_value = read_trimmed(&hwmon_dir.join(file_name))?
+                    .parse::<f64>()
+                    .ok()?;
+                let label = read_trimmed(&hwmon_dir.join(format!("{}_label", base)))
+                    .unwrap_or_else(|| base.to_string());
+
+                if is_sensor_filtered(filter, &format!("{}: {}", chip, label)) {
+                    Some((
+                        index,
+                        SensorHarvest {
+                            chip: chip.clone(),
+                            label,
+                            sensor_type,
+                            value: raw_value / scale,
+                        },
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        chip_sensors.sort_by(|(a_index, a), (b_index, b)| {
+            a.sensor_type.cmp(&b.sensor_type).then(a_index.cmp(b_index))
+        });
+
+        sensors.extend(chip_sensors.into_iter().map(|(_index, sensor)| sensor));
+    }
+
+    sensors
+}
@@ -13,8 +13,6 @@ cfg_if::cfg_if! {
     }
 }
 
-use std::cmp::Ordering;
-
 use crate::app::Filter;
 
 #[derive(Default, Debug, Clone)]
@@ -38,36 +36,8 @@ impl Default for TemperatureType {
 
 fn is_temp_filtered(filter: &Option<Filter>, text: &str) -> bool {
     if let Some(filter) = filter {
-        if filter.is_list_ignored {
-            let mut ret = true;
-            for r in &filter.list {
-                if r.is_match(text) {
-                    ret = false;
-                    break;
-                }
-            }
-            ret
-        } else {
-            true
-        }
+        filter.keep_entry(text)
     } else {
         true
     }
 }
-
-fn temp_vec_sort(temperature_vec: &mut Vec<TempHarvest>) {
-    // By default, sort temperature, then by alphabetically!
-    // TODO: [TEMPS] Allow users to control this.
-
-    // Note we sort in reverse here; we want greater temps to be higher priority.
-    temperature_vec.sort_by(|a, b| match a.temperature.partial_cmp(&b.temperature) {
-        Some(x) => match x {
-            Ordering::Less => Ordering::Greater,
-            Ordering::Greater => Ordering::Less,
-            Ordering::Equal => Ordering::Equal,
-        },
-        None => Ordering::Equal,
-    });
-
-    temperature_vec.sort_by(|a, b| a.name.partial_cmp(&b.name).unwrap_or(Ordering::Equal));
-}
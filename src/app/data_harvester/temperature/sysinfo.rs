@@ -1,6 +1,6 @@
 //! Gets temperature data via sysinfo.
 
-use super::{is_temp_filtered, temp_vec_sort, TempHarvest, TemperatureType};
+use super::{is_temp_filtered, TempHarvest, TemperatureType};
 use crate::app::Filter;
 
 pub async fn get_temperature_data(
@@ -42,6 +42,5 @@ pub async fn get_temperature_data(
         }
     }
 
-    temp_vec_sort(&mut temperature_vec);
     Ok(Some(temperature_vec))
 }
@@ -1,4 +1,6 @@
+use crate::app::GraphStyle;
 use crate::error::{BottomError, Result};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use typed_builder::*;
 
@@ -10,6 +12,14 @@ use crate::constants::DEFAULT_WIDGET_ID;
 pub struct BottomLayout {
     pub rows: Vec<BottomRow>,
     pub total_row_height_ratio: u32,
+    /// The name shown in the tab bar if there are multiple layouts.
+    pub name: String,
+    /// The widget selected when this layout is first switched to.
+    pub default_widget_id: u64,
+    /// The smallest terminal width this layout is used for, if its tab has other layouts.
+    pub min_width: u16,
+    /// The smallest terminal height this layout is used for, if its tab has other layouts.
+    pub min_height: u16,
 }
 
 // Represents a start and end coordinate in some dimension.
@@ -20,6 +30,27 @@ type ColumnRowMappings = (u32, BTreeMap<LineSegment, WidgetMappings>);
 type ColumnMappings = (u32, BTreeMap<LineSegment, ColumnRowMappings>);
 
 impl BottomLayout {
+    /// Returns whether a widget is part of this layout.
+    pub fn has_widget(&self, widget_id: u64) -> bool {
+        self.rows
+            .iter()
+            .flat_map(|row| &row.children)
+            .flat_map(|col| &col.children)
+            .flat_map(|col_row| &col_row.children)
+            .any(|widget| widget.widget_id == widget_id)
+    }
+
+    /// Returns the first widget in this layout that can be selected, if there is one.
+    pub fn get_first_selectable_widget_id(&self) -> Option<u64> {
+        self.rows
+            .iter()
+            .flat_map(|row| &row.children)
+            .flat_map(|col| &col.children)
+            .flat_map(|col_row| &col_row.children)
+            .find(|widget| widget.widget_type.is_widget_selectable())
+            .map(|widget| widget.widget_id)
+    }
+
     pub fn get_movement_mappings(&mut self) {
         #[allow(clippy::suspicious_operation_groupings)] // Have to enable this, clippy really doesn't like me doing this with tuples...
         fn is_intersecting(a: LineSegment, b: LineSegment) -> bool {
@@ -58,19 +89,16 @@ impl BottomLayout {
                     let mut col_row_mapping: BTreeMap<LineSegment, u64> = BTreeMap::new();
                     let mut is_valid_col_row = false;
                     for widget in &col_row.children {
-                        match widget.widget_type {
-                            BottomWidgetType::Empty => {}
-                            _ => {
-                                is_valid_col_row = true;
-                                col_row_mapping.insert(
-                                    (
-                                        widget_width * 100 / col_row.total_widget_ratio,
-                                        (widget_width + widget.width_ratio) * 100
-                                            / col_row.total_widget_ratio,
-                                    ),
-                                    widget.widget_id,
-                                );
-                            }
+                        if widget.widget_type.is_widget_selectable() {
+                            is_valid_col_row = true;
+                            col_row_mapping.insert(
+                                (
+                                    widget_width * 100 / col_row.total_widget_ratio,
+                                    (widget_width + widget.width_ratio) * 100
+                                        / col_row.total_widget_ratio,
+                                ),
+                                widget.widget_id,
+                            );
                         }
                         widget_width += widget.width_ratio;
                     }
@@ -138,8 +166,8 @@ impl BottomLayout {
                     let col_row_children_len = col_row.children.len();
 
                     for widget in &mut col_row.children {
-                        // Bail if empty.
-                        if let BottomWidgetType::Empty = widget.widget_type {
+                        // Bail if empty, or if it can't be selected.
+                        if !widget.widget_type.is_widget_selectable() {
                             continue;
                         }
 
@@ -532,7 +560,9 @@ impl BottomLayout {
         }
     }
 
-    pub fn init_basic_default(use_battery: bool) -> Self {
+    /// Creates the layout used in basic mode, with the given compact widgets along the top in
+    /// order, and the tables below them.
+    pub fn init_basic(basic_widgets: &[BottomWidgetType], use_battery: bool) -> Self {
         let table_widgets = if use_battery {
             vec![
                 BottomCol::builder()
@@ -696,71 +726,109 @@ impl BottomLayout {
             ]
         };
 
-        BottomLayout {
-            total_row_height_ratio: 3,
-            rows: vec![
-                BottomRow::builder()
-                    .canvas_handle_height(true)
-                    .children(vec![BottomCol::builder()
-                        .canvas_handle_width(true)
-                        .children(vec![BottomColRow::builder()
-                            .canvas_handle_height(true)
-                            .children(vec![BottomWidget::builder()
-                                .canvas_handle_width(true)
-                                .widget_type(BottomWidgetType::BasicCpu)
-                                .widget_id(1)
-                                .down_neighbour(Some(2))
-                                .build()])
-                            .build()])
-                        .build()])
-                    .build(),
+        // The CPU widget gets a row to itself, while the others share rows in pairs.
+        let mut header_rows: Vec<Vec<BottomWidgetType>> = Vec::new();
+        for basic_widget in basic_widgets {
+            match header_rows.last_mut() {
+                Some(header_row)
+                    if header_row.len() == 1
+                        && header_row[0] != BottomWidgetType::BasicCpu
+                        && *basic_widget != BottomWidgetType::BasicCpu =>
+                {
+                    header_row.push(basic_widget.clone());
+                }
+                _ => header_rows.push(vec![basic_widget.clone()]),
+            }
+        }
+
+        // Header widgets are numbered after the tables' widget.  Moving up or down from a row goes
+        // to the first widget of the next one.
+        let mut row_first_ids = Vec::new();
+        let mut next_id = 101;
+        for header_row in &header_rows {
+            row_first_ids.push(next_id);
+            next_id += header_row.len() as u64;
+        }
+
+        let mut rows: Vec<BottomRow> = header_rows
+            .iter()
+            .enumerate()
+            .map(|(row_index, header_row)| {
+                let first_id = row_first_ids[row_index];
+                let up_neighbour = row_index
+                    .checked_sub(1)
+                    .map(|up_index| row_first_ids[up_index]);
+                let down_neighbour = Some(row_first_ids.get(row_index + 1).copied().unwrap_or(100));
+
                 BottomRow::builder()
                     .canvas_handle_height(true)
                     .children(vec![BottomCol::builder()
                         .canvas_handle_width(true)
                         .children(vec![BottomColRow::builder()
                             .canvas_handle_height(true)
-                            .children(vec![
-                                BottomWidget::builder()
-                                    .canvas_handle_width(true)
-                                    .widget_type(BottomWidgetType::BasicMem)
-                                    .widget_id(2)
-                                    .up_neighbour(Some(1))
-                                    .down_neighbour(Some(100))
-                                    .right_neighbour(Some(3))
-                                    .build(),
-                                BottomWidget::builder()
-                                    .canvas_handle_width(true)
-                                    .widget_type(BottomWidgetType::BasicNet)
-                                    .widget_id(3)
-                                    .up_neighbour(Some(1))
-                                    .down_neighbour(Some(100))
-                                    .left_neighbour(Some(2))
-                                    .build(),
-                            ])
+                            .children(
+                                header_row
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, widget_type)| {
+                                        let widget_id = first_id + index as u64;
+                                        BottomWidget::builder()
+                                            .canvas_handle_width(true)
+                                            .widget_type(widget_type.clone())
+                                            .widget_id(widget_id)
+                                            .up_neighbour(up_neighbour)
+                                            .down_neighbour(down_neighbour)
+                                            .left_neighbour(if index > 0 {
+                                                Some(widget_id - 1)
+                                            } else {
+                                                None
+                                            })
+                                            .right_neighbour(if index + 1 < header_row.len() {
+                                                Some(widget_id + 1)
+                                            } else {
+                                                None
+                                            })
+                                            .build()
+                                    })
+                                    .collect(),
+                            )
                             .build()])
                         .build()])
-                    .build(),
-                BottomRow::builder()
-                    .canvas_handle_height(true)
-                    .children(vec![BottomCol::builder()
-                        .canvas_handle_width(true)
-                        .children(vec![BottomColRow::builder()
-                            .canvas_handle_height(true)
-                            .children(vec![BottomWidget::builder()
-                                .canvas_handle_width(true)
-                                .widget_type(BottomWidgetType::BasicTables)
-                                .widget_id(100)
-                                .up_neighbour(Some(2))
-                                .build()])
+                    .build()
+            })
+            .collect();
+
+        rows.push(
+            BottomRow::builder()
+                .canvas_handle_height(true)
+                .children(vec![BottomCol::builder()
+                    .canvas_handle_width(true)
+                    .children(vec![BottomColRow::builder()
+                        .canvas_handle_height(true)
+                        .children(vec![BottomWidget::builder()
+                            .canvas_handle_width(true)
+                            .widget_type(BottomWidgetType::BasicTables)
+                            .widget_id(100)
+                            .up_neighbour(row_first_ids.last().copied())
                             .build()])
                         .build()])
-                    .build(),
-                BottomRow::builder()
-                    .canvas_handle_height(true)
-                    .children(table_widgets)
-                    .build(),
-            ],
+                    .build()])
+                .build(),
+        );
+        rows.push(
+            BottomRow::builder()
+                .canvas_handle_height(true)
+                .children(table_widgets)
+                .build(),
+        );
+
+        BottomLayout {
+            total_row_height_ratio: 3,
+            name: String::default(),
+            default_widget_id: DEFAULT_WIDGET_ID,
+            min_width: 0,
+            min_height: 0,
+            rows,
         }
     }
 }
@@ -776,6 +844,14 @@ pub struct BottomRow {
     #[builder(default = 1)]
     pub row_height_ratio: u32,
 
+    /// Used instead of the ratio if set.
+    #[builder(default)]
+    pub size: Option<LayoutSize>,
+
+    /// Lets this be hidden when there isn't enough room, with lower priorities hidden first.
+    #[builder(default)]
+    pub priority: Option<u32>,
+
     #[builder(default = false)]
     pub canvas_handle_height: bool,
 
@@ -796,6 +872,14 @@ pub struct BottomCol {
     #[builder(default = 1)]
     pub col_width_ratio: u32,
 
+    /// Used instead of the ratio if set.
+    #[builder(default)]
+    pub size: Option<LayoutSize>,
+
+    /// Lets this be hidden when there isn't enough room, with lower priorities hidden first.
+    #[builder(default)]
+    pub priority: Option<u32>,
+
     #[builder(default = false)]
     pub canvas_handle_width: bool,
 
@@ -813,6 +897,14 @@ pub struct BottomColRow {
     #[builder(default = 1)]
     pub col_row_height_ratio: u32,
 
+    /// Used instead of the ratio if set.
+    #[builder(default)]
+    pub size: Option<LayoutSize>,
+
+    /// Lets this be hidden when there isn't enough room, with lower priorities hidden first.
+    #[builder(default)]
+    pub priority: Option<u32>,
+
     #[builder(default = false)]
     pub canvas_handle_height: bool,
 
@@ -820,6 +912,19 @@ pub struct BottomColRow {
     pub flex_grow: bool,
 }
 
+/// A size for part of the layout, set in the config instead of a ratio.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LayoutSize {
+    /// A fixed number of lines or columns.
+    Length(u16),
+    /// A percentage of the parent.
+    Percentage(u16),
+    /// At least this many lines or columns, otherwise sized like a ratio of 1.
+    Min(u16),
+    /// At most this many lines or columns, otherwise sized like a ratio of 1.
+    Max(u16),
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum WidgetDirection {
     Left,
@@ -879,6 +984,45 @@ pub struct BottomWidget {
     /// Bottom right corner when drawn, for mouse click detection.  (x, y)
     #[builder(default = None)]
     pub bottom_right_corner: Option<(u16, u16)>,
+
+    /// The name of a saved filter to start with.  Only used by process widgets.
+    #[builder(default = None)]
+    pub default_filter: Option<String>,
+
+    /// How to draw the widget's graph, if not the default.  Only used by graph widgets.
+    #[builder(default = None)]
+    pub graph_style: Option<GraphStyle>,
+
+    /// Whether the legend is drawn to the left of its graph.  Only used by graph widgets with
+    /// legends, and their legends.
+    #[builder(default = false)]
+    pub left_legend: bool,
+
+    /// Settings from the layout that override the app-wide ones for just this widget.
+    #[builder(default)]
+    pub options: WidgetOptions,
+}
+
+/// Settings that can be given to a single widget in the layout, overriding the app-wide ones.
+/// Each is only used by the widgets it applies to.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WidgetOptions {
+    /// The title to show instead of the widget's name.
+    pub title: Option<String>,
+    /// The starting time window of graph widgets, in milliseconds.
+    pub default_time_value: Option<u64>,
+    /// The optional columns of CPU legends, disk widgets, and process widgets.
+    pub columns: Option<Vec<String>>,
+    /// The column process widgets are sorted by.
+    pub default_sort: Option<String>,
+    /// Whether process widgets start in tree mode.
+    pub tree: Option<bool>,
+    /// Whether process widgets start grouped.
+    pub group_processes: Option<bool>,
+    /// Whether network widgets show bytes instead of bits.
+    pub network_use_bytes: Option<bool>,
+    /// Whether network widgets use a log scale.
+    pub network_use_log: Option<bool>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -892,23 +1036,60 @@ pub enum BottomWidgetType {
     ProcSearch,
     ProcSort,
     Temp,
+    TempGraph,
+    TempGraphLegend,
+    Sensors,
     Disk,
     BasicCpu,
     BasicMem,
     BasicNet,
+    BasicTemp,
+    BasicDisk,
+    BasicLoadAvg,
+    BasicBattery,
     BasicTables,
     Battery,
+    StatusBar,
 }
 
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | Proc | ProcSort | Temp | CpuLegend | TempGraphLegend | Sensors
+        )
+    }
+
+    /// Whether this is one of the compact widgets used in basic mode.
+    pub fn is_widget_basic(&self) -> bool {
+        use BottomWidgetType::*;
+        matches!(
+            self,
+            BasicCpu | BasicMem | BasicNet | BasicTemp | BasicDisk | BasicLoadAvg | BasicBattery
+        )
+    }
+
+    /// Whether this widget can be moved to and selected, which empty space and the status bar
+    /// can't be.
+    pub fn is_widget_selectable(&self) -> bool {
+        use BottomWidgetType::*;
+        !matches!(self, Empty | StatusBar)
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(self, Cpu | Net | Mem | TempGraph)
+    }
+
+    /// Returns the type of the legend widget attached to this graph, if it has one.
+    pub fn get_legend_type(&self) -> Option<BottomWidgetType> {
+        use BottomWidgetType::*;
+        match self {
+            Cpu => Some(CpuLegend),
+            TempGraph => Some(TempGraphLegend),
+            _ => None,
+        }
     }
 
     pub fn get_pretty_name(&self) -> &str {
@@ -919,8 +1100,11 @@ impl BottomWidgetType {
             Net => "Network",
             Proc => "Processes",
             Temp => "Temperature",
+            TempGraph => "Temperature Graph",
+            Sensors => "Sensors",
             Disk => "Disks",
             Battery => "Battery",
+            StatusBar => "Status Bar",
             _ => "",
         }
     }
@@ -943,9 +1127,21 @@ impl std::str::FromStr for BottomWidgetType {
             "net" | "network" => Ok(BottomWidgetType::Net),
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
+            "temp_graph" | "temperature_graph" => Ok(BottomWidgetType::TempGraph),
+            "sensor" | "sensors" => Ok(BottomWidgetType::Sensors),
             "disk" => Ok(BottomWidgetType::Disk),
             "empty" => Ok(BottomWidgetType::Empty),
+            "status" | "status_bar" => Ok(BottomWidgetType::StatusBar),
             "battery" | "batt" if cfg!(feature = "battery") => Ok(BottomWidgetType::Battery),
+            "basic_cpu" => Ok(BottomWidgetType::BasicCpu),
+            "basic_mem" | "basic_memory" => Ok(BottomWidgetType::BasicMem),
+            "basic_net" | "basic_network" => Ok(BottomWidgetType::BasicNet),
+            "basic_temp" | "basic_temperature" => Ok(BottomWidgetType::BasicTemp),
+            "basic_disk" => Ok(BottomWidgetType::BasicDisk),
+            "basic_load_avg" => Ok(BottomWidgetType::BasicLoadAvg),
+            "basic_battery" | "basic_batt" if cfg!(feature = "battery") => {
+                Ok(BottomWidgetType::BasicBattery)
+            }
             _ => {
                 if cfg!(feature = "battery") {
                     Err(BottomError::ConfigError(format!(
@@ -963,9 +1159,24 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|       temp_graph,        |
+|    temperature_graph     |
++--------------------------+
+|     sensor, sensors      |
++--------------------------+
 |           disk           |
 +--------------------------+
+|    status, status_bar    |
++--------------------------+
+|   basic_cpu, basic_mem,  |
+|  basic_net, basic_temp,  |
+|       basic_disk,        |
+|      basic_load_avg      |
++--------------------------+
 |       batt, battery      |
++--------------------------+
+|        basic_batt,       |
+|      basic_battery       |
 +--------------------------+
                 ",
                         s
@@ -986,7 +1197,19 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|       temp_graph,        |
+|    temperature_graph     |
++--------------------------+
+|     sensor, sensors      |
++--------------------------+
 |           disk           |
++--------------------------+
+|    status, status_bar    |
++--------------------------+
+|   basic_cpu, basic_mem,  |
+|  basic_net, basic_temp,  |
+|       basic_disk,        |
+|      basic_load_avg      |
 +--------------------------+
                 ",
                         s
@@ -997,13 +1220,27 @@ Supported widget names:
     }
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct UsedWidgets {
     pub use_cpu: bool,
     pub use_mem: bool,
     pub use_net: bool,
     pub use_proc: bool,
+    /// Whether any process widget shows a column that needs the history of each process.
+    pub use_proc_history: bool,
     pub use_disk: bool,
+    /// Whether any disk widget shows a column that needs the usage history of each disk.
+    pub use_disk_history: bool,
     pub use_temp: bool,
+    /// Whether any temperature graph is shown, which needs the history of each sensor.
+    pub use_temp_graph: bool,
+    pub use_sensors: bool,
     pub use_battery: bool,
+    pub use_host: bool,
+    /// Whether any CPU widget shows the frequency of its cores, in its legend or graph.
+    pub use_cpu_frequency: bool,
+    /// Whether any CPU widget shows the temperature of its cores.
+    pub use_cpu_temp: bool,
+    /// Whether cores are grouped by socket or NUMA node.
+    pub use_cpu_topology: bool,
 }
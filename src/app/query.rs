@@ -14,6 +14,29 @@ const COMPARISON_LIST: [&str; 3] = [">", "=", "<"];
 const OR_LIST: [&str; 2] = ["or", "||"];
 const AND_LIST: [&str; 2] = ["and", "&&"];
 
+/// Builds the regex for a search term, which is escaped unless `is_searching_with_regex` is set.
+/// This is shared by the process search and the simpler table searches.
+pub fn new_search_regex(
+    term: &str, is_searching_whole_word: bool, is_ignoring_case: bool,
+    is_searching_with_regex: bool,
+) -> std::result::Result<regex::Regex, regex::Error> {
+    let escaped_regex: String;
+    let final_regex_string = format!(
+        "{}{}{}{}",
+        if is_searching_whole_word { "^" } else { "" },
+        if is_ignoring_case { "(?i)" } else { "" },
+        if !is_searching_with_regex {
+            escaped_regex = regex::escape(term);
+            &escaped_regex
+        } else {
+            term
+        },
+        if is_searching_whole_word { "$" } else { "" },
+    );
+
+    regex::Regex::new(&final_regex_string)
+}
+
 /// I only separated this as otherwise, the states.rs file gets huge... and this should
 /// belong in another file anyways, IMO.
 pub trait ProcessQuery {
@@ -633,26 +656,16 @@ impl Prefix {
         {
             match prefix_type {
                 PrefixType::Pid | PrefixType::Name | PrefixType::State | PrefixType::User => {
-                    let escaped_regex: String;
-                    let final_regex_string = &format!(
-                        "{}{}{}{}",
-                        if is_searching_whole_word { "^" } else { "" },
-                        if is_ignoring_case { "(?i)" } else { "" },
-                        if !is_searching_with_regex {
-                            escaped_regex = regex::escape(regex_string);
-                            &escaped_regex
-                        } else {
-                            regex_string
-                        },
-                        if is_searching_whole_word { "$" } else { "" },
-                    );
+                    let regex = new_search_regex(
+                        regex_string,
+                        is_searching_whole_word,
+                        is_ignoring_case,
+                        is_searching_with_regex,
+                    )?;
 
                     let taken_pwc = self.regex_prefix.take();
                     if let Some((taken_pt, _)) = taken_pwc {
-                        self.regex_prefix = Some((
-                            taken_pt,
-                            StringQuery::Regex(regex::Regex::new(final_regex_string)?),
-                        ));
+                        self.regex_prefix = Some((taken_pt, StringQuery::Regex(regex)));
                     }
                 }
                 _ => {}
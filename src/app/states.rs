@@ -1,13 +1,16 @@
 use std::{collections::HashMap, time::Instant};
 
 use unicode_segmentation::GraphemeCursor;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use regex::Regex;
 use tui::widgets::TableState;
 
 use crate::{
-    app::{layout_manager::BottomWidgetType, query::*},
+    app::{layout_manager::BottomWidgetType, query::*, AxisScaling},
     constants,
     data_harvester::processes::{self, ProcessSorting},
+    units::data_units::DataUnit,
 };
 use ProcessSorting::*;
 
@@ -129,6 +132,320 @@ impl AppSearchState {
     pub fn is_invalid_or_blank_search(&self) -> bool {
         self.is_blank_search || self.is_invalid_search
     }
+
+    pub fn get_cursor_position(&self) -> usize {
+        self.grapheme_cursor.cur_cursor()
+    }
+
+    pub fn walk_forward(&mut self, start_position: usize) {
+        self.grapheme_cursor
+            .next_boundary(&self.current_search_query[start_position..], start_position)
+            .unwrap();
+    }
+
+    pub fn walk_back(&mut self, start_position: usize) {
+        self.grapheme_cursor
+            .prev_boundary(&self.current_search_query[..start_position], 0)
+            .unwrap();
+    }
+
+    /// Inserts a character at the cursor.  Returns whether the query changed.
+    pub fn insert_char(&mut self, caught_char: char, max_length: usize) -> bool {
+        if UnicodeWidthStr::width(self.current_search_query.as_str()) > max_length {
+            return false;
+        }
+
+        self.current_search_query
+            .insert(self.get_cursor_position(), caught_char);
+        self.grapheme_cursor = GraphemeCursor::new(
+            self.get_cursor_position(),
+            self.current_search_query.len(),
+            true,
+        );
+        self.walk_forward(self.get_cursor_position());
+        self.char_cursor_position += UnicodeWidthChar::width(caught_char).unwrap_or(0);
+        self.cursor_direction = CursorDirection::Right;
+
+        true
+    }
+
+    /// Removes the grapheme after the cursor.  Returns whether the query changed.
+    pub fn delete_forward(&mut self) -> bool {
+        let current_cursor = self.get_cursor_position();
+        if current_cursor >= self.current_search_query.len() {
+            return false;
+        }
+
+        self.walk_forward(current_cursor);
+        let _removed_chars: String = self
+            .current_search_query
+            .drain(current_cursor..self.get_cursor_position())
+            .collect();
+        self.grapheme_cursor =
+            GraphemeCursor::new(current_cursor, self.current_search_query.len(), true);
+
+        true
+    }
+
+    /// Removes the grapheme before the cursor.  Returns whether the query changed.
+    pub fn delete_backward(&mut self) -> bool {
+        let current_cursor = self.get_cursor_position();
+        if current_cursor == 0 {
+            return false;
+        }
+
+        self.walk_back(current_cursor);
+        let removed_chars: String = self
+            .current_search_query
+            .drain(self.get_cursor_position()..current_cursor)
+            .collect();
+        self.grapheme_cursor = GraphemeCursor::new(
+            self.get_cursor_position(),
+            self.current_search_query.len(),
+            true,
+        );
+        self.char_cursor_position -= UnicodeWidthStr::width(removed_chars.as_str());
+        self.cursor_direction = CursorDirection::Left;
+
+        true
+    }
+
+    /// Removes the word before the cursor, along with any whitespace after it.  Returns whether
+    /// the query changed.
+    pub fn delete_previous_word(&mut self) -> bool {
+        let end_index = self.get_cursor_position();
+        let mut start_index = 0;
+        let mut saw_non_whitespace = false;
+
+        for (itx, c) in self.current_search_query[..end_index].char_indices().rev() {
+            if c.is_whitespace() {
+                if saw_non_whitespace {
+                    start_index = itx + c.len_utf8();
+                    break;
+                }
+            } else {
+                saw_non_whitespace = true;
+            }
+        }
+
+        if start_index == end_index {
+            return false;
+        }
+
+        let removed_chars: String = self
+            .current_search_query
+            .drain(start_index..end_index)
+            .collect();
+        self.grapheme_cursor =
+            GraphemeCursor::new(start_index, self.current_search_query.len(), true);
+        self.char_cursor_position -= UnicodeWidthStr::width(removed_chars.as_str());
+        self.cursor_direction = CursorDirection::Left;
+
+        true
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        let prev_cursor = self.get_cursor_position();
+        self.walk_back(prev_cursor);
+        if self.get_cursor_position() < prev_cursor {
+            let str_slice = &self.current_search_query[self.get_cursor_position()..prev_cursor];
+            self.char_cursor_position -= UnicodeWidthStr::width(str_slice);
+            self.cursor_direction = CursorDirection::Left;
+        }
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        let prev_cursor = self.get_cursor_position();
+        self.walk_forward(prev_cursor);
+        if self.get_cursor_position() > prev_cursor {
+            let str_slice = &self.current_search_query[prev_cursor..self.get_cursor_position()];
+            self.char_cursor_position += UnicodeWidthStr::width(str_slice);
+            self.cursor_direction = CursorDirection::Right;
+        }
+    }
+
+    pub fn move_cursor_to_start(&mut self) {
+        self.grapheme_cursor = GraphemeCursor::new(0, self.current_search_query.len(), true);
+        self.char_cursor_position = 0;
+        self.cursor_direction = CursorDirection::Left;
+    }
+
+    pub fn move_cursor_to_end(&mut self) {
+        self.grapheme_cursor = GraphemeCursor::new(
+            self.current_search_query.len(),
+            self.current_search_query.len(),
+            true,
+        );
+        self.char_cursor_position = UnicodeWidthStr::width(self.current_search_query.as_str());
+        self.cursor_direction = CursorDirection::Right;
+    }
+}
+
+/// TableSearchState deals with the search bar of the simpler tables (temperature and disk), which
+/// just match each row against one regex rather than a full process query.
+pub struct TableSearchState {
+    pub search_state: AppSearchState,
+    pub is_ignoring_case: bool,
+    pub is_searching_whole_word: bool,
+    pub is_searching_with_regex: bool,
+    pub regex: Option<Regex>,
+}
+
+impl Default for TableSearchState {
+    fn default() -> Self {
+        TableSearchState {
+            search_state: AppSearchState::default(),
+            is_ignoring_case: true,
+            is_searching_whole_word: false,
+            is_searching_with_regex: false,
+            regex: None,
+        }
+    }
+}
+
+impl TableSearchState {
+    pub fn init(is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool) -> Self {
+        TableSearchState {
+            is_ignoring_case: !is_case_sensitive,
+            is_searching_whole_word: is_match_whole_word,
+            is_searching_with_regex: is_use_regex,
+            ..TableSearchState::default()
+        }
+    }
+
+    pub fn is_search_enabled(&self) -> bool {
+        self.search_state.is_enabled
+    }
+
+    pub fn search_toggle_ignore_case(&mut self) {
+        self.is_ignoring_case = !self.is_ignoring_case;
+    }
+
+    pub fn search_toggle_whole_word(&mut self) {
+        self.is_searching_whole_word = !self.is_searching_whole_word;
+    }
+
+    pub fn search_toggle_regex(&mut self) {
+        self.is_searching_with_regex = !self.is_searching_with_regex;
+    }
+
+    /// Rebuilds the regex from the current query and search settings.
+    pub fn update_query(&mut self) {
+        let query = &self.search_state.current_search_query;
+        if query.is_empty() {
+            self.regex = None;
+            self.search_state.is_blank_search = true;
+            self.search_state.is_invalid_search = false;
+            self.search_state.error_message = None;
+        } else {
+            self.search_state.is_blank_search = false;
+            match new_search_regex(
+                query,
+                self.is_searching_whole_word,
+                self.is_ignoring_case,
+                self.is_searching_with_regex,
+            ) {
+                Ok(regex) => {
+                    self.regex = Some(regex);
+                    self.search_state.is_invalid_search = false;
+                    self.search_state.error_message = None;
+                }
+                Err(err) => {
+                    self.regex = None;
+                    self.search_state.is_invalid_search = true;
+                    self.search_state.error_message = Some(err.to_string());
+                }
+            }
+        }
+    }
+
+    /// Returns whether any of the given entries match the current search.  An invalid or blank
+    /// search matches everything.
+    pub fn is_match(&self, entries: &[&str]) -> bool {
+        if let Some(regex) = &self.regex {
+            entries.iter().any(|entry| regex.is_match(entry))
+        } else {
+            true
+        }
+    }
+}
+
+/// TableSortState keeps track of which column a simple table is sorted by, as well as where
+/// its column headers were drawn (for click-to-sort).
+pub struct TableSortState {
+    pub sort_index: usize,
+    pub is_sort_descending: bool,
+    pub column_header_y_loc: Option<u16>,
+    pub column_header_x_locs: Option<Vec<(u16, u16)>>,
+}
+
+impl TableSortState {
+    pub fn init(sort_index: usize, is_sort_descending: bool) -> Self {
+        TableSortState {
+            sort_index,
+            is_sort_descending,
+            column_header_y_loc: None,
+            column_header_x_locs: None,
+        }
+    }
+
+    /// Sorts by the given column, or inverts the sort if we're already sorting by it.
+    pub fn set_sort_index(&mut self, sort_index: usize) {
+        if self.sort_index == sort_index {
+            self.is_sort_descending = !self.is_sort_descending;
+        } else {
+            self.sort_index = sort_index;
+            self.is_sort_descending = false;
+        }
+    }
+
+    /// Returns the index of the column header at the given location, if any.
+    pub fn get_clicked_column(&self, x: u16, y: u16) -> Option<usize> {
+        if let (Some(y_loc), Some(x_locs)) = (self.column_header_y_loc, &self.column_header_x_locs)
+        {
+            if y == y_loc {
+                return x_locs
+                    .iter()
+                    .position(|(x_left, x_right)| x >= *x_left && x <= *x_right);
+            }
+        }
+
+        None
+    }
+
+    /// Moves the sort over to the next column, wrapping around.
+    pub fn cycle_sort_index(&mut self, num_columns: usize) {
+        if num_columns > 0 {
+            self.sort_index = (self.sort_index + 1) % num_columns;
+            self.is_sort_descending = false;
+        }
+    }
+
+    /// Returns the header names with an arrow on the currently sorted column.
+    pub fn get_column_headers(&self, headers: &[&str]) -> Vec<String> {
+        const DOWN_ARROW: char = '▼';
+        const UP_ARROW: char = '▲';
+
+        headers
+            .iter()
+            .enumerate()
+            .map(|(itx, header)| {
+                if itx == self.sort_index {
+                    format!(
+                        "{}{}",
+                        header,
+                        if self.is_sort_descending {
+                            DOWN_ARROW
+                        } else {
+                            UP_ARROW
+                        }
+                    )
+                } else {
+                    header.to_string()
+                }
+            })
+            .collect()
+    }
 }
 
 /// Meant for canvas operations involving table column widths.
@@ -138,12 +455,24 @@ pub struct CanvasTableWidthState {
     pub calculated_column_widths: Vec<u16>,
 }
 
+/// A named process search query, as defined in the `[filters]` section of the config file.
+#[derive(Clone, Debug)]
+pub struct SavedFilter {
+    pub name: String,
+    pub query: String,
+    pub is_ignoring_case: bool,
+    pub is_searching_whole_word: bool,
+    pub is_searching_with_regex: bool,
+}
+
 /// ProcessSearchState only deals with process' search's current settings and state.
 pub struct ProcessSearchState {
     pub search_state: AppSearchState,
     pub is_ignoring_case: bool,
     pub is_searching_whole_word: bool,
     pub is_searching_with_regex: bool,
+    /// The index of the last saved filter that was applied, if any.
+    pub saved_filter_index: Option<usize>,
 }
 
 impl Default for ProcessSearchState {
@@ -153,6 +482,7 @@ impl Default for ProcessSearchState {
             is_ignoring_case: true,
             is_searching_whole_word: false,
             is_searching_with_regex: false,
+            saved_filter_index: None,
         }
     }
 }
@@ -210,6 +540,9 @@ impl Default for ProcColumn {
             TotalWrite,
             User,
             State,
+            CpuHistory,
+            MemHistory,
+            MemGrowth,
         ];
 
         let mut column_mapping = HashMap::new();
@@ -303,6 +636,15 @@ impl Default for ProcColumn {
                         },
                     );
                 }
+                CpuHistory | MemHistory | MemGrowth => {
+                    column_mapping.insert(
+                        column,
+                        ColumnInfo {
+                            enabled: false,
+                            shortcut: None,
+                        },
+                    );
+                }
                 _ => {
                     column_mapping.insert(
                         column,
@@ -473,10 +815,24 @@ pub struct ProcWidgetState {
     pub requires_redraw: bool,
 }
 
+/// Whether a process column is sorted in descending order when first picked, which is all of them
+/// except for the ones that are usually sorted alphabetically.
+fn is_sorted_descending_by_default(process_sorting: &ProcessSorting) -> bool {
+    !matches!(
+        process_sorting,
+        ProcessSorting::State
+            | ProcessSorting::Pid
+            | ProcessSorting::ProcessName
+            | ProcessSorting::Command
+    )
+}
+
 impl ProcWidgetState {
+    #[allow(clippy::too_many_arguments)]
     pub fn init(
         is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool, is_grouped: bool,
         show_memory_as_values: bool, is_tree_mode: bool, is_using_command: bool,
+        history_columns: &[ProcessSorting], default_sort: Option<ProcessSorting>,
     ) -> Self {
         let mut process_search_state = ProcessSearchState::default();
 
@@ -491,25 +847,38 @@ impl ProcWidgetState {
             process_search_state.search_toggle_regex();
         }
 
-        let (process_sorting_type, is_process_sort_descending) = if is_tree_mode {
-            (processes::ProcessSorting::Pid, false)
-        } else {
-            (processes::ProcessSorting::CpuPercent, true)
-        };
+        let has_default_sort = default_sort.is_some();
+        let (process_sorting_type, is_process_sort_descending) =
+            if let Some(default_sort) = default_sort {
+                let is_descending = is_sorted_descending_by_default(&default_sort);
+                (default_sort, is_descending)
+            } else if is_tree_mode {
+                (processes::ProcessSorting::Pid, false)
+            } else {
+                (processes::ProcessSorting::CpuPercent, true)
+            };
 
-        // TODO: If we add customizable columns, this should pull from config
         let mut columns = ProcColumn::default();
-        columns.set_to_sorted_index_from_type(&process_sorting_type);
         if is_grouped {
             // Normally defaults to showing by PID, toggle count on instead.
             columns.toggle(&ProcessSorting::Count);
             columns.toggle(&ProcessSorting::Pid);
+            columns.try_disable(&ProcessSorting::State);
+            columns.try_disable(&ProcessSorting::User);
         }
         if show_memory_as_values {
             // Normally defaults to showing by percent, toggle value on instead.
             columns.toggle(&ProcessSorting::Mem);
             columns.toggle(&ProcessSorting::MemPercent);
         }
+        for column in history_columns {
+            columns.try_enable(column);
+        }
+        if has_default_sort {
+            // Make sure the column being sorted by is shown.
+            columns.try_enable(&process_sorting_type);
+        }
+        columns.set_to_sorted_index_from_type(&process_sorting_type);
 
         ProcWidgetState {
             process_search_state,
@@ -554,18 +923,8 @@ impl ProcWidgetState {
                 self.is_process_sort_descending = !(self.is_process_sort_descending);
             } else {
                 self.process_sorting_type = new_sort_type.clone();
-                match self.process_sorting_type {
-                    ProcessSorting::State
-                    | ProcessSorting::Pid
-                    | ProcessSorting::ProcessName
-                    | ProcessSorting::Command => {
-                        // Also invert anything that uses alphabetical sorting by default.
-                        self.is_process_sort_descending = false;
-                    }
-                    _ => {
-                        self.is_process_sort_descending = true;
-                    }
-                }
+                self.is_process_sort_descending =
+                    is_sorted_descending_by_default(&self.process_sorting_type);
             }
         }
     }
@@ -635,30 +994,38 @@ impl ProcWidgetState {
         self.scroll_state.current_scroll_position = 0;
     }
 
-    pub fn clear_search(&mut self) {
-        self.process_search_state.search_state.reset();
-    }
-
-    pub fn search_walk_forward(&mut self, start_position: usize) {
-        self.process_search_state
-            .search_state
-            .grapheme_cursor
-            .next_boundary(
-                &self.process_search_state.search_state.current_search_query[start_position..],
-                start_position,
-            )
-            .unwrap();
+    /// Replaces the current search query and its settings with the given saved filter.
+    pub fn apply_saved_filter(&mut self, index: usize, saved_filter: &SavedFilter) {
+        let search_state = &mut self.process_search_state.search_state;
+        search_state.current_search_query = saved_filter.query.clone();
+        search_state.grapheme_cursor = GraphemeCursor::new(
+            search_state.current_search_query.len(),
+            search_state.current_search_query.len(),
+            true,
+        );
+        search_state.char_cursor_position =
+            UnicodeWidthStr::width(search_state.current_search_query.as_str());
+        search_state.cursor_direction = CursorDirection::Right;
+
+        self.process_search_state.is_ignoring_case = saved_filter.is_ignoring_case;
+        self.process_search_state.is_searching_whole_word = saved_filter.is_searching_whole_word;
+        self.process_search_state.is_searching_with_regex = saved_filter.is_searching_with_regex;
+        self.process_search_state.saved_filter_index = Some(index);
+
+        self.update_query();
     }
 
-    pub fn search_walk_back(&mut self, start_position: usize) {
+    /// Returns the index of the applied saved filter, but only if the query hasn't been edited since.
+    pub fn get_current_saved_filter_index(&self, saved_filters: &[SavedFilter]) -> Option<usize> {
         self.process_search_state
-            .search_state
-            .grapheme_cursor
-            .prev_boundary(
-                &self.process_search_state.search_state.current_search_query[..start_position],
-                0,
-            )
-            .unwrap();
+            .saved_filter_index
+            .filter(|index| match saved_filters.get(*index) {
+                Some(saved_filter) => {
+                    saved_filter.query
+                        == self.process_search_state.search_state.current_search_query
+                }
+                None => false,
+            })
     }
 }
 
@@ -684,34 +1051,40 @@ impl ProcState {
     pub fn get_widget_state(&self, widget_id: u64) -> Option<&ProcWidgetState> {
         self.widget_states.get(&widget_id)
     }
+
+    /// Whether any process widget shows the given column.
+    pub fn is_using_column(&self, column: &ProcessSorting) -> bool {
+        self.widget_states
+            .values()
+            .any(|widget_state| widget_state.columns.is_enabled(column))
+    }
 }
 
 pub struct NetWidgetState {
     pub current_display_time: u64,
     pub autohide_timer: Option<Instant>,
+    pub graph_style: GraphStyle,
+    pub unit_type: DataUnit,
+    pub scale_type: AxisScaling,
     // pub draw_max_range_cache: f64,
     // pub draw_labels_cache: Vec<String>,
     // pub draw_time_start_cache: f64,
-    // TODO: Re-enable these when we move net details state-side!
-    // pub unit_type: DataUnitTypes,
-    // pub scale_type: AxisScaling,
 }
 
 impl NetWidgetState {
     pub fn init(
-        current_display_time: u64,
-        autohide_timer: Option<Instant>,
-        // unit_type: DataUnitTypes,
-        // scale_type: AxisScaling,
+        current_display_time: u64, autohide_timer: Option<Instant>, graph_style: GraphStyle,
+        unit_type: DataUnit, scale_type: AxisScaling,
     ) -> Self {
         NetWidgetState {
             current_display_time,
             autohide_timer,
+            graph_style,
+            unit_type,
+            scale_type,
             // draw_max_range_cache: 0.0,
             // draw_labels_cache: vec![],
             // draw_time_start_cache: 0.0,
-            // unit_type,
-            // scale_type,
         }
     }
 }
@@ -738,6 +1111,140 @@ impl NetState {
     }
 }
 
+/// How a graph widget draws its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphStyle {
+    /// Lines drawn with braille characters.
+    Braille,
+    /// Lines drawn with dots, for terminals that can't show braille characters.
+    Dot,
+    /// Bars drawn with block characters, one per column, for low-resolution terminals.
+    Block,
+    /// Lines drawn with braille characters, with the area under each line filled in.
+    Filled,
+    /// Like filled, but related lines, such as RAM and swap, are stacked on top of each other.
+    Stacked,
+}
+
+impl GraphStyle {
+    /// Whether the area under each line is filled in.
+    pub fn is_filled(&self) -> bool {
+        !matches!(self, GraphStyle::Braille | GraphStyle::Dot)
+    }
+}
+
+impl std::str::FromStr for GraphStyle {
+    type Err = crate::utils::error::BottomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "braille" => Ok(GraphStyle::Braille),
+            "dot" => Ok(GraphStyle::Dot),
+            "block" => Ok(GraphStyle::Block),
+            "filled" => Ok(GraphStyle::Filled),
+            "stacked" => Ok(GraphStyle::Stacked),
+            _ => Err(crate::utils::error::BottomError::ConfigError(format!(
+                "\"{}\" is an invalid graph style, use \"<braille|dot|block|filled|stacked>\".",
+                s
+            ))),
+        }
+    }
+}
+
+/// What the CPU graph plots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuGraphMode {
+    /// The total usage of each CPU.
+    Usage,
+    /// The usage of the selected CPU, split into user, system, iowait, IRQ, and steal time and
+    /// stacked on top of each other.
+    Breakdown,
+    /// The current frequency of each CPU.
+    Frequency,
+    /// The 1, 5, and 15 minute load averages.  Only available on Unix-like systems.
+    LoadAverage,
+    /// The current usage of each core as a grid of coloured cells.
+    Heatmap,
+}
+
+impl CpuGraphMode {
+    pub fn next(&self) -> Self {
+        match self {
+            CpuGraphMode::Usage => CpuGraphMode::Breakdown,
+            CpuGraphMode::Breakdown => CpuGraphMode::Frequency,
+            CpuGraphMode::Frequency => {
+                if cfg!(target_family = "unix") {
+                    CpuGraphMode::LoadAverage
+                } else {
+                    CpuGraphMode::Heatmap
+                }
+            }
+            CpuGraphMode::LoadAverage => CpuGraphMode::Heatmap,
+            CpuGraphMode::Heatmap => CpuGraphMode::Usage,
+        }
+    }
+}
+
+/// How cores are grouped together in the CPU widget, where each group shows the average of its
+/// cores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuGrouping {
+    /// Every core is shown separately.
+    None,
+    /// Cores are grouped by the physical package (socket) they're on.
+    Socket,
+    /// Cores are grouped by NUMA node.
+    NumaNode,
+    /// Cores are grouped into fixed-size buckets of consecutive cores.
+    Bucket(usize),
+}
+
+/// The optional columns of the CPU legend, shown after the CPU name and usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuLegendColumn {
+    Frequency,
+    MinFrequency,
+    MaxFrequency,
+    Temperature,
+}
+
+impl CpuLegendColumn {
+    pub fn get_header(&self) -> &'static str {
+        match self {
+            CpuLegendColumn::Frequency => "Freq",
+            CpuLegendColumn::MinFrequency => "Min",
+            CpuLegendColumn::MaxFrequency => "Max",
+            CpuLegendColumn::Temperature => "Temp",
+        }
+    }
+
+    pub fn get_width(&self) -> u16 {
+        match self {
+            CpuLegendColumn::Frequency
+            | CpuLegendColumn::MinFrequency
+            | CpuLegendColumn::MaxFrequency => 6,
+            CpuLegendColumn::Temperature => 5,
+        }
+    }
+}
+
+impl std::str::FromStr for CpuLegendColumn {
+    type Err = crate::utils::error::BottomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "freq" | "frequency" => Ok(CpuLegendColumn::Frequency),
+            "min_freq" => Ok(CpuLegendColumn::MinFrequency),
+            "max_freq" => Ok(CpuLegendColumn::MaxFrequency),
+            "temp" | "temperature" => Ok(CpuLegendColumn::Temperature),
+            _ => Err(crate::utils::error::BottomError::ConfigError(format!(
+                "\"{}\" is an invalid CPU legend column, use \"<freq|min_freq|max_freq|temp>\".",
+                s
+            ))),
+        }
+    }
+}
+
 pub struct CpuWidgetState {
     pub current_display_time: u64,
     pub is_legend_hidden: bool,
@@ -745,10 +1252,16 @@ pub struct CpuWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub is_multi_graph_mode: bool,
     pub table_width_state: CanvasTableWidthState,
+    pub graph_mode: CpuGraphMode,
+    pub graph_style: GraphStyle,
+    pub legend_columns: Vec<CpuLegendColumn>,
 }
 
 impl CpuWidgetState {
-    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+    pub fn init(
+        current_display_time: u64, autohide_timer: Option<Instant>, graph_style: GraphStyle,
+        legend_columns: Vec<CpuLegendColumn>,
+    ) -> Self {
         CpuWidgetState {
             current_display_time,
             is_legend_hidden: false,
@@ -756,6 +1269,9 @@ impl CpuWidgetState {
             scroll_state: AppScrollWidgetState::default(),
             is_multi_graph_mode: false,
             table_width_state: CanvasTableWidthState::default(),
+            graph_mode: CpuGraphMode::Usage,
+            graph_style,
+            legend_columns,
         }
     }
 }
@@ -773,6 +1289,20 @@ impl CpuState {
         }
     }
 
+    /// Whether any CPU widget shows the given legend column.
+    pub fn is_using_legend_column(&self, legend_column: CpuLegendColumn) -> bool {
+        self.widget_states
+            .values()
+            .any(|widget_state| widget_state.legend_columns.contains(&legend_column))
+    }
+
+    /// Whether any CPU widget is using the given graph mode, and so needs its data converted.
+    pub fn is_using_graph_mode(&self, graph_mode: CpuGraphMode) -> bool {
+        self.widget_states
+            .values()
+            .any(|widget_state| widget_state.graph_mode == graph_mode)
+    }
+
     pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut CpuWidgetState> {
         self.widget_states.get_mut(&widget_id)
     }
@@ -785,13 +1315,17 @@ impl CpuState {
 pub struct MemWidgetState {
     pub current_display_time: u64,
     pub autohide_timer: Option<Instant>,
+    pub graph_style: GraphStyle,
 }
 
 impl MemWidgetState {
-    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+    pub fn init(
+        current_display_time: u64, autohide_timer: Option<Instant>, graph_style: GraphStyle,
+    ) -> Self {
         MemWidgetState {
             current_display_time,
             autohide_timer,
+            graph_style,
         }
     }
 }
@@ -820,24 +1354,43 @@ impl MemState {
 pub struct TempWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub table_width_state: CanvasTableWidthState,
+    pub search_state: TableSearchState,
+    pub sort_state: TableSortState,
 }
 
 impl TempWidgetState {
-    pub fn init() -> Self {
+    pub fn init(is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool) -> Self {
         TempWidgetState {
             scroll_state: AppScrollWidgetState::default(),
             table_width_state: CanvasTableWidthState::default(),
+            search_state: TableSearchState::init(
+                is_case_sensitive,
+                is_match_whole_word,
+                is_use_regex,
+            ),
+            // Hottest sensors first.
+            sort_state: TableSortState::init(1, true),
         }
     }
+
+    pub fn update_query(&mut self) {
+        self.search_state.update_query();
+        self.scroll_state.previous_scroll_position = 0;
+        self.scroll_state.current_scroll_position = 0;
+    }
 }
 
 pub struct TempState {
     pub widget_states: HashMap<u64, TempWidgetState>,
+    pub force_update: Option<u64>,
 }
 
 impl TempState {
     pub fn init(widget_states: HashMap<u64, TempWidgetState>) -> Self {
-        TempState { widget_states }
+        TempState {
+            widget_states,
+            force_update: None,
+        }
     }
 
     pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut TempWidgetState> {
@@ -849,27 +1402,335 @@ impl TempState {
     }
 }
 
+pub struct TempGraphWidgetState {
+    pub current_display_time: u64,
+    /// Whether the user wants the legend drawn.  Toggled with `l`.
+    pub show_legend: bool,
+    /// Whether the legend was actually hidden on the last draw, either by the user or due to
+    /// lack of space.
+    pub is_legend_hidden: bool,
+    pub autohide_timer: Option<Instant>,
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+    pub graph_style: GraphStyle,
+}
+
+impl TempGraphWidgetState {
+    pub fn init(
+        current_display_time: u64, autohide_timer: Option<Instant>, graph_style: GraphStyle,
+    ) -> Self {
+        TempGraphWidgetState {
+            current_display_time,
+            show_legend: true,
+            is_legend_hidden: false,
+            autohide_timer,
+            graph_style,
+            scroll_state: AppScrollWidgetState::default(),
+            table_width_state: CanvasTableWidthState::default(),
+        }
+    }
+}
+
+pub struct TempGraphState {
+    pub force_update: Option<u64>,
+    pub widget_states: HashMap<u64, TempGraphWidgetState>,
+}
+
+impl TempGraphState {
+    pub fn init(widget_states: HashMap<u64, TempGraphWidgetState>) -> Self {
+        TempGraphState {
+            force_update: None,
+            widget_states,
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut TempGraphWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&TempGraphWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+#[derive(Default)]
+pub struct SensorWidgetState {
+    pub scroll_state: AppScrollWidgetState,
+    pub table_width_state: CanvasTableWidthState,
+}
+
+pub struct SensorState {
+    pub widget_states: HashMap<u64, SensorWidgetState>,
+}
+
+impl SensorState {
+    pub fn init(widget_states: HashMap<u64, SensorWidgetState>) -> Self {
+        SensorState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut SensorWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&SensorWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+/// The pieces of information a status bar can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusItem {
+    Hostname,
+    Os,
+    Kernel,
+    Uptime,
+    Users,
+    Tasks,
+    Time,
+    Rate,
+}
+
+/// The items shown if a status bar's `columns` isn't set.
+pub const DEFAULT_STATUS_ITEMS: [StatusItem; 8] = [
+    StatusItem::Hostname,
+    StatusItem::Os,
+    StatusItem::Kernel,
+    StatusItem::Uptime,
+    StatusItem::Users,
+    StatusItem::Tasks,
+    StatusItem::Time,
+    StatusItem::Rate,
+];
+
+impl std::str::FromStr for StatusItem {
+    type Err = crate::utils::error::BottomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hostname" | "host" => Ok(StatusItem::Hostname),
+            "os" => Ok(StatusItem::Os),
+            "kernel" => Ok(StatusItem::Kernel),
+            "uptime" => Ok(StatusItem::Uptime),
+            "users" => Ok(StatusItem::Users),
+            "tasks" => Ok(StatusItem::Tasks),
+            "time" | "clock" => Ok(StatusItem::Time),
+            "rate" => Ok(StatusItem::Rate),
+            _ => Err(crate::utils::error::BottomError::ConfigError(format!(
+                "\"{}\" is an invalid status bar item, use \"<hostname|os|kernel|uptime|users|tasks|time|rate>\".",
+                s
+            ))),
+        }
+    }
+}
+
+pub struct StatusBarWidgetState {
+    /// The items to show, in order.
+    pub items: Vec<StatusItem>,
+}
+
+impl StatusBarWidgetState {
+    pub fn init(items: Vec<StatusItem>) -> Self {
+        StatusBarWidgetState { items }
+    }
+}
+
+pub struct StatusBarState {
+    pub widget_states: HashMap<u64, StatusBarWidgetState>,
+}
+
+impl StatusBarState {
+    pub fn init(widget_states: HashMap<u64, StatusBarWidgetState>) -> Self {
+        StatusBarState { widget_states }
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&StatusBarWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+/// The columns a disk table can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskColumn {
+    Disk,
+    Mount,
+    FsType,
+    Used,
+    Free,
+    Total,
+    InodesUsed,
+    InodesFree,
+    InodesTotal,
+    ReadOnly,
+    Options,
+    ReadPerSec,
+    WritePerSec,
+    ReadIops,
+    WriteIops,
+    Await,
+    Util,
+    Queue,
+    FillRate,
+    EtaFull,
+    History,
+}
+
+/// The columns shown if `disk_columns` isn't set.
+pub const DEFAULT_DISK_COLUMNS: [DiskColumn; 7] = [
+    DiskColumn::Disk,
+    DiskColumn::Mount,
+    DiskColumn::Used,
+    DiskColumn::Free,
+    DiskColumn::Total,
+    DiskColumn::ReadPerSec,
+    DiskColumn::WritePerSec,
+];
+
+impl DiskColumn {
+    pub fn get_header(&self) -> &'static str {
+        match self {
+            DiskColumn::Disk => "Disk",
+            DiskColumn::Mount => "Mount",
+            DiskColumn::FsType => "Type",
+            DiskColumn::Used => "Used",
+            DiskColumn::Free => "Free",
+            DiskColumn::Total => "Total",
+            DiskColumn::InodesUsed => "Inodes",
+            DiskColumn::InodesFree => "I-Free",
+            DiskColumn::InodesTotal => "I-Total",
+            DiskColumn::ReadOnly => "RO",
+            DiskColumn::Options => "Options",
+            DiskColumn::ReadPerSec => "R/s",
+            DiskColumn::WritePerSec => "W/s",
+            DiskColumn::ReadIops => "R-IOPS",
+            DiskColumn::WriteIops => "W-IOPS",
+            DiskColumn::Await => "Await",
+            DiskColumn::Util => "Util",
+            DiskColumn::Queue => "Queue",
+            DiskColumn::FillRate => "Fill rate",
+            DiskColumn::EtaFull => "ETA full",
+            DiskColumn::History => "History",
+        }
+    }
+
+    /// Returns the fixed width of the column, if its entries have a known maximum length.
+    pub fn get_hard_width(&self) -> Option<u16> {
+        match self {
+            DiskColumn::Disk | DiskColumn::Mount | DiskColumn::FsType | DiskColumn::Options => None,
+            DiskColumn::ReadOnly => Some(3),
+            DiskColumn::Used => Some(5),
+            DiskColumn::Free | DiskColumn::Total => Some(6),
+            DiskColumn::InodesUsed
+            | DiskColumn::InodesFree
+            | DiskColumn::InodesTotal
+            | DiskColumn::ReadPerSec
+            | DiskColumn::WritePerSec => Some(7),
+            DiskColumn::Util => Some(5),
+            DiskColumn::Queue => Some(6),
+            DiskColumn::ReadIops | DiskColumn::WriteIops => Some(7),
+            DiskColumn::EtaFull | DiskColumn::Await => Some(8),
+            DiskColumn::FillRate => Some(10),
+            DiskColumn::History => Some(crate::constants::DISK_HISTORY_SPARKLINE_WIDTH as u16),
+        }
+    }
+
+    /// Returns the maximum share of the table's width that a column without a hard width can
+    /// take up.
+    pub fn get_max_width_ratio(&self) -> Option<f64> {
+        match self {
+            DiskColumn::Disk | DiskColumn::Mount | DiskColumn::Options => Some(0.2),
+            DiskColumn::FsType => Some(0.1),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for DiskColumn {
+    type Err = crate::utils::error::BottomError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "disk" => Ok(DiskColumn::Disk),
+            "mount" => Ok(DiskColumn::Mount),
+            "type" | "fs_type" => Ok(DiskColumn::FsType),
+            "used" => Ok(DiskColumn::Used),
+            "free" => Ok(DiskColumn::Free),
+            "total" => Ok(DiskColumn::Total),
+            "inodes" | "inodes_used" => Ok(DiskColumn::InodesUsed),
+            "inodes_free" => Ok(DiskColumn::InodesFree),
+            "inodes_total" => Ok(DiskColumn::InodesTotal),
+            "ro" | "read_only" => Ok(DiskColumn::ReadOnly),
+            "options" => Ok(DiskColumn::Options),
+            "r/s" | "read" => Ok(DiskColumn::ReadPerSec),
+            "w/s" | "write" => Ok(DiskColumn::WritePerSec),
+            "read_iops" => Ok(DiskColumn::ReadIops),
+            "write_iops" => Ok(DiskColumn::WriteIops),
+            "await" => Ok(DiskColumn::Await),
+            "util" => Ok(DiskColumn::Util),
+            "queue" => Ok(DiskColumn::Queue),
+            "fill_rate" => Ok(DiskColumn::FillRate),
+            "eta_full" | "eta" => Ok(DiskColumn::EtaFull),
+            "history" => Ok(DiskColumn::History),
+            _ => Err(crate::utils::error::BottomError::ConfigError(format!(
+                "\"{}\" is an invalid disk column, use \"<disk|mount|type|used|free|total|inodes|inodes_free|inodes_total|read_only|options|r/s|w/s|read_iops|write_iops|await|util|queue|fill_rate|eta_full|history>\".",
+                s
+            ))),
+        }
+    }
+}
+
 pub struct DiskWidgetState {
     pub scroll_state: AppScrollWidgetState,
     pub table_width_state: CanvasTableWidthState,
+    pub search_state: TableSearchState,
+    pub sort_state: TableSortState,
+    /// The columns to show, in order.  The sort index is an index into this.
+    pub columns: Vec<DiskColumn>,
 }
 
 impl DiskWidgetState {
-    pub fn init() -> Self {
+    pub fn init(
+        is_case_sensitive: bool, is_match_whole_word: bool, is_use_regex: bool,
+        columns: Vec<DiskColumn>,
+    ) -> Self {
         DiskWidgetState {
             scroll_state: AppScrollWidgetState::default(),
             table_width_state: CanvasTableWidthState::default(),
+            search_state: TableSearchState::init(
+                is_case_sensitive,
+                is_match_whole_word,
+                is_use_regex,
+            ),
+            sort_state: TableSortState::init(0, false),
+            columns,
         }
     }
+
+    /// Returns the column the table is currently sorted by.
+    pub fn get_sort_column(&self) -> DiskColumn {
+        self.columns
+            .get(self.sort_state.sort_index)
+            .copied()
+            .unwrap_or(DiskColumn::Disk)
+    }
+
+    pub fn update_query(&mut self) {
+        self.search_state.update_query();
+        self.scroll_state.previous_scroll_position = 0;
+        self.scroll_state.current_scroll_position = 0;
+    }
 }
 
 pub struct DiskState {
     pub widget_states: HashMap<u64, DiskWidgetState>,
+    pub force_update: Option<u64>,
 }
 
 impl DiskState {
     pub fn init(widget_states: HashMap<u64, DiskWidgetState>) -> Self {
-        DiskState { widget_states }
+        DiskState {
+            widget_states,
+            force_update: None,
+        }
     }
 
     pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut DiskWidgetState> {
@@ -879,7 +1740,88 @@ impl DiskState {
     pub fn get_widget_state(&self, widget_id: u64) -> Option<&DiskWidgetState> {
         self.widget_states.get(&widget_id)
     }
+
+    /// Whether any disk widget shows the given column.
+    pub fn is_using_column(&self, column: DiskColumn) -> bool {
+        self.widget_states
+            .values()
+            .any(|widget_state| widget_state.columns.contains(&column))
+    }
+}
+
+/// Tracks the layouts that can be switched between as tabs.  Layouts with the same name are
+/// shown under one tab, and the one that fits the terminal is picked when drawing.
+pub struct LayoutTabState {
+    pub current_tab: usize,
+    pub tab_names: Vec<String>,
+    /// The layout being shown, which is one of the current tab's layouts.
+    pub current_layout: usize,
+    /// The tab each layout is shown under.
+    pub layout_tabs: Vec<usize>,
+    /// The widget last selected in each layout, which is selected again when switching back to it.
+    pub layout_widget_ids: Vec<u64>,
+    pub tab_click_locs: Option<Vec<((u16, u16), (u16, u16))>>,
+}
+
+impl LayoutTabState {
+    pub fn init(layouts: Vec<(String, u64)>, current_layout: usize) -> Self {
+        let mut tab_names: Vec<String> = Vec::new();
+        let mut layout_tabs = Vec::new();
+        let mut layout_widget_ids = Vec::new();
+
+        for (name, widget_id) in layouts {
+            let tab = match tab_names.iter().position(|tab_name| *tab_name == name) {
+                Some(tab) => tab,
+                None => {
+                    tab_names.push(name);
+                    tab_names.len() - 1
+                }
+            };
+            layout_tabs.push(tab);
+            layout_widget_ids.push(widget_id);
+        }
+
+        LayoutTabState {
+            current_tab: layout_tabs.get(current_layout).copied().unwrap_or_default(),
+            tab_names,
+            current_layout,
+            layout_tabs,
+            layout_widget_ids,
+            tab_click_locs: None,
+        }
+    }
+
+    /// Returns the layouts shown under a tab.
+    pub fn get_tab_layouts(&self, tab: usize) -> impl Iterator<Item = usize> + '_ {
+        self.layout_tabs
+            .iter()
+            .enumerate()
+            .filter(move |(_, layout_tab)| **layout_tab == tab)
+            .map(|(layout, _)| layout)
+    }
+}
+
+/// Tracks the layout editor, which changes the layout in the config and then rebuilds the widgets
+/// from it.
+#[derive(Default)]
+pub struct LayoutEditState {
+    pub is_editing: bool,
+    /// Set after an edit, when the widgets need to be rebuilt from the config.
+    pub needs_rebuild: bool,
+    /// Maps the old IDs of widgets that survived the edit to their new ones, so their state can be
+    /// kept.
+    pub widget_id_map: HashMap<u64, u64>,
+    pub widget_to_select: Option<u64>,
+    pub drag: Option<LayoutDrag>,
+    pub message: Option<String>,
 }
+
+/// A border of the selected widget being dragged with the mouse to resize it.
+#[derive(Clone, Copy)]
+pub struct LayoutDrag {
+    pub is_vertical: bool,
+}
+
 pub struct BasicTableWidgetState {
     // Since this is intended (currently) to only be used for ONE widget, that's
     // how it's going to be written.  If we want to allow for multiple of these,
@@ -39,15 +39,17 @@ fn main() -> Result<()> {
         .context("Unable to properly parse or create the config file.")?;
 
     // Get widget layout separately
-    let (widget_layout, default_widget_id, default_widget_type_option) =
+    let (widget_layouts, default_widget_id, default_widget_type_option) =
         get_widget_layout(&matches, &config)
             .context("Found an issue while trying to build the widget layout.")?;
 
+    let colour_scheme = get_color_scheme(&matches, &config, &config_path)?;
+
     // Create "app" struct, which will control most of the program and store settings/state
     let mut app = build_app(
         &matches,
         &mut config,
-        &widget_layout,
+        &widget_layouts,
         default_widget_id,
         &default_widget_type_option,
         config_path,
@@ -55,11 +57,12 @@ fn main() -> Result<()> {
 
     // Create painter and set colours.
     let mut painter = canvas::Painter::init(
-        widget_layout,
+        widget_layouts,
+        app.layout_tab_state.current_layout,
         app.app_config_fields.table_gap,
         app.app_config_fields.use_basic_mode,
         &config,
-        get_color_scheme(&matches, &config)?,
+        colour_scheme,
     )?;
 
     // Create termination mutex and cvar
@@ -132,17 +135,41 @@ fn main() -> Result<()> {
         if let Ok(recv) = receiver.recv_timeout(Duration::from_millis(TICK_RATE_IN_MILLISECONDS)) {
             match recv {
                 BottomEvent::KeyInput(event) => {
+                    let used_widgets = app.used_widgets.clone();
                     if handle_key_event_or_break(event, &mut app, &collection_thread_ctrl_sender) {
                         break;
                     }
+                    if app.used_widgets != used_widgets {
+                        collection_thread_ctrl_sender
+                            .send(ThreadControlEvent::UpdateUsedWidgets(Box::new(
+                                app.used_widgets.clone(),
+                            )))
+                            .ok();
+                    }
+                    if app.layout_edit_state.needs_rebuild {
+                        rebuild_layout(
+                            &matches,
+                            &mut app,
+                            &mut painter,
+                            &collection_thread_ctrl_sender,
+                        );
+                    }
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::MouseInput(event) => {
                     handle_mouse_event(event, &mut app);
+                    if app.layout_edit_state.needs_rebuild {
+                        rebuild_layout(
+                            &matches,
+                            &mut app,
+                            &mut painter,
+                            &collection_thread_ctrl_sender,
+                        );
+                    }
                     handle_force_redraws(&mut app);
                 }
                 BottomEvent::Update(data) => {
-                    app.data_collection.eat_data(data);
+                    app.data_collection.eat_data(data, &app.used_widgets);
 
                     // This thing is required as otherwise, some widgets can't draw correctly w/o
                     // some data (or they need to be re-drawn).
@@ -175,16 +202,31 @@ fn main() -> Result<()> {
                             if let Some(total_tx_display) = network_data.total_tx_display {
                                 app.canvas_data.total_tx_display = total_tx_display;
                             }
+                            update_network_widget_data(&mut app);
                         }
 
                         // Disk
                         if app.used_widgets.use_disk {
-                            app.canvas_data.disk_data = convert_disk_row(&app.data_collection);
+                            update_all_disk_tables(&mut app);
                         }
 
                         // Temperatures
                         if app.used_widgets.use_temp {
-                            app.canvas_data.temp_sensor_data = convert_temp_row(&app);
+                            update_all_temp_tables(&mut app);
+
+                            if !app.temp_graph_state.widget_states.is_empty() {
+                                convert_temp_data_points(
+                                    &app.data_collection,
+                                    &mut app.canvas_data.temp_graph_data,
+                                    &app.app_config_fields.temperature_type,
+                                    false,
+                                );
+                            }
+                        }
+
+                        // Other sensors
+                        if app.used_widgets.use_sensors {
+                            app.canvas_data.sensor_data = convert_sensor_row(&app.data_collection);
                         }
 
                         // Memory
@@ -193,6 +235,9 @@ fn main() -> Result<()> {
                                 convert_mem_data_points(&app.data_collection, false);
                             app.canvas_data.swap_data =
                                 convert_swap_data_points(&app.data_collection, false);
+                            app.canvas_data.numa_data =
+                                convert_numa_data_points(&app.data_collection, false);
+                            app.canvas_data.numa_labels = convert_numa_labels(&app.data_collection);
                             let (memory_labels, swap_labels) =
                                 convert_mem_labels(&app.data_collection);
 
@@ -207,8 +252,37 @@ fn main() -> Result<()> {
                                 &app.data_collection,
                                 &mut app.canvas_data.cpu_data,
                                 false,
+                                app.cpu_state
+                                    .is_using_graph_mode(app::CpuGraphMode::Breakdown),
+                                app.cpu_state
+                                    .is_using_graph_mode(app::CpuGraphMode::Frequency),
+                                app.app_config_fields.cpu_grouping,
                             );
                             app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
+                            app.canvas_data.load_avg_graph_data = if app
+                                .cpu_state
+                                .is_using_graph_mode(app::CpuGraphMode::LoadAverage)
+                            {
+                                convert_load_avg_data_points(
+                                    &app.data_collection,
+                                    false,
+                                    app.app_config_fields.normalize_load_avg,
+                                )
+                            } else {
+                                Default::default()
+                            };
+                            app.canvas_data.cpu_heatmap_data = if app
+                                .cpu_state
+                                .is_using_graph_mode(app::CpuGraphMode::Heatmap)
+                            {
+                                convert_cpu_heatmap_data(&app.data_collection, false)
+                            } else {
+                                Vec::new()
+                            };
+                            app.canvas_data.scheduler_stats = app
+                                .data_collection
+                                .scheduler_stats_and_prev
+                                .map(|(stats, _prev)| stats);
                         }
 
                         // Processes
@@ -229,6 +303,8 @@ fn main() -> Result<()> {
                 BottomEvent::Clean => {
                     app.data_collection
                         .clean_data(constants::STALE_MAX_MILLISECONDS);
+                    app.data_collection
+                        .clean_disk_history(app.app_config_fields.disk_history_window);
                 }
             }
         }
@@ -5,7 +5,7 @@ use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     text::{Span, Spans},
-    widgets::Paragraph,
+    widgets::{Paragraph, Tabs},
     Frame, Terminal,
 };
 
@@ -13,18 +13,23 @@ use tui::{
 
 use canvas_colours::*;
 use dialogs::*;
+use drawing_utils::split_layout_by_priority;
 use screens::*;
 use widgets::*;
 
 use crate::{
     app::{
         self,
-        layout_manager::{BottomColRow, BottomLayout, BottomWidgetType},
+        data_farmer::SchedulerStats,
+        layout_manager::{BottomColRow, BottomLayout, BottomWidget, BottomWidgetType, LayoutSize},
         App,
     },
     constants::*,
-    data_conversion::{ConvertedBatteryData, ConvertedCpuData, ConvertedProcessData},
-    options::Config,
+    data_conversion::{
+        ConvertedBatteryData, ConvertedCpuData, ConvertedDiskData, ConvertedNetworkData,
+        ConvertedProcessData, ConvertedTempData, ConvertedTempRow,
+    },
+    options::{Config, ConfigColours},
     utils::error,
     utils::error::BottomError,
     Pid,
@@ -47,8 +52,10 @@ pub struct DisplayableData {
     pub total_tx_display: String,
     pub network_data_rx: Vec<Point>,
     pub network_data_tx: Vec<Point>,
-    pub disk_data: Vec<Vec<String>>,
-    pub temp_sensor_data: Vec<Vec<String>>,
+    pub network_widget_data: HashMap<u64, ConvertedNetworkData>, // Only for widgets with their own unit or scale, key is the widget ID.
+    pub disk_data: HashMap<u64, Vec<ConvertedDiskData>>, // What's actually displayed, key is the widget ID.
+    pub temp_sensor_data: HashMap<u64, Vec<ConvertedTempRow>>, // What's actually displayed, key is the widget ID.
+    pub sensor_data: Vec<Vec<String>>,
     pub single_process_data: HashMap<Pid, ConvertedProcessData>, // Contains single process data, key is PID
     pub finalized_process_data_map: HashMap<u64, Vec<ConvertedProcessData>>, // What's actually displayed, key is the widget ID.
     pub stringified_process_data_map: HashMap<u64, Vec<(Vec<(String, Option<String>)>, bool)>>, // Represents the row and whether it is disabled, key is the widget ID
@@ -58,8 +65,14 @@ pub struct DisplayableData {
 
     pub mem_data: Vec<Point>, // TODO: Switch this and all data points over to a better data structure...
     pub swap_data: Vec<Point>,
+    pub numa_data: Vec<Vec<Point>>,
+    pub numa_labels: Vec<String>,
     pub load_avg_data: [f32; 3],
+    pub load_avg_graph_data: [Vec<Point>; 3],
+    pub scheduler_stats: Option<SchedulerStats>,
     pub cpu_data: Vec<ConvertedCpuData>,
+    pub cpu_heatmap_data: Vec<(usize, f64)>,
+    pub temp_graph_data: Vec<ConvertedTempData>,
     pub battery_data: Vec<ConvertedBatteryData>,
 }
 
@@ -71,7 +84,12 @@ pub enum ColourScheme {
     GruvboxLight,
     Nord,
     NordLight,
+    Dracula,
+    Solarized,
+    SolarizedLight,
     Custom,
+    /// A theme file's colours, applied on top of a built-in base scheme.
+    Theme(Box<ColourScheme>, Box<ConfigColours>),
 }
 
 impl FromStr for ColourScheme {
@@ -86,6 +104,9 @@ impl FromStr for ColourScheme {
             "gruvbox-light" => Ok(ColourScheme::GruvboxLight),
             "nord" => Ok(ColourScheme::Nord),
             "nord-light" => Ok(ColourScheme::NordLight),
+            "dracula" => Ok(ColourScheme::Dracula),
+            "solarized" => Ok(ColourScheme::Solarized),
+            "solarized-light" => Ok(ColourScheme::SolarizedLight),
             _ => Err(BottomError::ConfigError(format!(
                 "\"{}\" is an invalid built-in color scheme.",
                 s
@@ -106,18 +127,89 @@ pub struct Painter {
     col_row_constraints: Vec<Vec<Vec<Constraint>>>,
     layout_constraints: Vec<Vec<Vec<Vec<Constraint>>>>,
     derived_widget_draw_locs: Vec<Vec<Vec<Vec<Rect>>>>,
-    widget_layout: BottomLayout,
+    widget_layouts: Vec<BottomLayout>,
+    current_layout: usize,
     table_height_offset: u16,
 }
 
 impl Painter {
     pub fn init(
-        widget_layout: BottomLayout, table_gap: u16, is_basic_mode: bool, config: &Config,
-        colour_scheme: ColourScheme,
+        widget_layouts: Vec<BottomLayout>, current_layout: usize, table_gap: u16,
+        is_basic_mode: bool, config: &Config, colour_scheme: ColourScheme,
     ) -> anyhow::Result<Self> {
-        // Now for modularity; we have to also initialize the base layouts!
-        // We want to do this ONCE and reuse; after this we can just construct
-        // based on the console size.
+        let mut painter = Painter {
+            colours: CanvasColours::default(),
+            height: 0,
+            width: 0,
+            styled_help_text: Vec::default(),
+            is_mac_os: cfg!(target_os = "macos"),
+            row_constraints: Vec::default(),
+            col_constraints: Vec::default(),
+            col_row_constraints: Vec::default(),
+            layout_constraints: Vec::default(),
+            widget_layouts,
+            current_layout,
+            derived_widget_draw_locs: Vec::default(),
+            table_height_offset: if is_basic_mode { 2 } else { 4 } + table_gap,
+        };
+
+        match colour_scheme {
+            ColourScheme::Custom => {
+                painter.generate_config_colours(config)?;
+            }
+            ColourScheme::Theme(base, colours) => {
+                painter.generate_colour_scheme(*base)?;
+                painter.colours.set_colours_from_palette(&colours)?;
+            }
+            _ => {
+                painter.generate_colour_scheme(colour_scheme)?;
+            }
+        }
+        painter.complete_painter_init();
+        painter.generate_layout_constraints();
+
+        Ok(painter)
+    }
+
+    /// Replaces the layouts, such as after the layout was edited.
+    pub fn set_widget_layouts(&mut self, widget_layouts: Vec<BottomLayout>, current_layout: usize) {
+        self.widget_layouts = widget_layouts;
+        self.current_layout = current_layout;
+        self.generate_layout_constraints();
+    }
+
+    /// Picks the first of the current tab's layouts whose minimum size fits in the terminal, or
+    /// the tab's last layout if none do.  Returns whether this switched to another layout.
+    pub fn select_layout_for_size(&self, terminal_size: Rect, app_state: &mut App) -> bool {
+        let layout_tab_state = &app_state.layout_tab_state;
+        let tab_layouts: Vec<usize> = layout_tab_state
+            .get_tab_layouts(layout_tab_state.current_tab)
+            .collect();
+        let fitting_layout = tab_layouts
+            .iter()
+            .find(
+                |layout_index| match self.widget_layouts.get(**layout_index) {
+                    Some(layout) => {
+                        layout.min_width <= terminal_size.width
+                            && layout.min_height <= terminal_size.height
+                    }
+                    None => false,
+                },
+            )
+            .or_else(|| tab_layouts.last());
+
+        match fitting_layout {
+            Some(layout_index) if *layout_index != layout_tab_state.current_layout => {
+                app_state.select_layout(*layout_index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Builds the constraints for the current layout, which are reused until the layout changes.
+    fn generate_layout_constraints(&mut self) {
+        let widget_layout = &self.widget_layouts[self.current_layout];
 
         let mut row_constraints = Vec::new();
         let mut col_constraints = Vec::new();
@@ -128,7 +220,8 @@ impl Painter {
             if row.canvas_handle_height {
                 row_constraints.push(Constraint::Length(0));
             } else {
-                row_constraints.push(Constraint::Ratio(
+                row_constraints.push(get_layout_constraint(
+                    row.size,
                     row.row_height_ratio,
                     widget_layout.total_row_height_ratio,
                 ));
@@ -141,8 +234,11 @@ impl Painter {
                 if col.canvas_handle_width {
                     new_col_constraints.push(Constraint::Length(0));
                 } else {
-                    new_col_constraints
-                        .push(Constraint::Ratio(col.col_width_ratio, row.total_col_ratio));
+                    new_col_constraints.push(get_layout_constraint(
+                        col.size,
+                        col.col_width_ratio,
+                        row.total_col_ratio,
+                    ));
                 }
 
                 let mut new_new_col_row_constraints = Vec::new();
@@ -153,7 +249,8 @@ impl Painter {
                     } else if col_row.flex_grow {
                         new_new_col_row_constraints.push(Constraint::Min(0));
                     } else {
-                        new_new_col_row_constraints.push(Constraint::Ratio(
+                        new_new_col_row_constraints.push(get_layout_constraint(
+                            col_row.size,
                             col_row.col_row_height_ratio,
                             col.total_col_row_ratio,
                         ));
@@ -182,29 +279,11 @@ impl Painter {
             col_constraints.push(new_col_constraints);
         });
 
-        let mut painter = Painter {
-            colours: CanvasColours::default(),
-            height: 0,
-            width: 0,
-            styled_help_text: Vec::default(),
-            is_mac_os: cfg!(target_os = "macos"),
-            row_constraints,
-            col_constraints,
-            col_row_constraints,
-            layout_constraints,
-            widget_layout,
-            derived_widget_draw_locs: Vec::default(),
-            table_height_offset: if is_basic_mode { 2 } else { 4 } + table_gap,
-        };
-
-        if let ColourScheme::Custom = colour_scheme {
-            painter.generate_config_colours(config)?;
-        } else {
-            painter.generate_colour_scheme(colour_scheme)?;
-        }
-        painter.complete_painter_init();
-
-        Ok(painter)
+        self.row_constraints = row_constraints;
+        self.col_constraints = col_constraints;
+        self.col_row_constraints = col_row_constraints;
+        self.layout_constraints = layout_constraints;
+        self.derived_widget_draw_locs = Vec::default();
     }
 
     fn generate_config_colours(&mut self, config: &Config) -> anyhow::Result<()> {
@@ -240,7 +319,19 @@ impl Painter {
                 self.colours
                     .set_colours_from_palette(&*NORD_LIGHT_COLOUR_PALETTE)?;
             }
-            ColourScheme::Custom => {
+            ColourScheme::Dracula => {
+                self.colours
+                    .set_colours_from_palette(&DRACULA_COLOUR_PALETTE)?;
+            }
+            ColourScheme::Solarized => {
+                self.colours
+                    .set_colours_from_palette(&SOLARIZED_COLOUR_PALETTE)?;
+            }
+            ColourScheme::SolarizedLight => {
+                self.colours
+                    .set_colours_from_palette(&SOLARIZED_LIGHT_COLOUR_PALETTE)?;
+            }
+            ColourScheme::Custom | ColourScheme::Theme(..) => {
                 // This case should never occur, just do nothing.
             }
         }
@@ -285,10 +376,57 @@ impl Painter {
     // FIXME: [CONFIG] write this, should call painter init and any changed colour functions...
     pub fn update_painter_colours(&mut self) {}
 
-    fn draw_frozen_indicator<B: Backend>(&self, f: &mut Frame<'_, B>, draw_loc: Rect) {
+    fn draw_layout_tabs<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect,
+    ) {
+        let should_get_widget_bounds = app_state.should_get_widget_bounds();
+        let layout_tab_state = &mut app_state.layout_tab_state;
+
+        f.render_widget(
+            Tabs::new(
+                layout_tab_state
+                    .tab_names
+                    .iter()
+                    .map(|name| Spans::from(name.as_str()))
+                    .collect::<Vec<_>>(),
+            )
+            .divider(tui::symbols::line::VERTICAL)
+            .style(self.colours.text_style)
+            .highlight_style(self.colours.currently_selected_text_style)
+            .select(layout_tab_state.current_tab),
+            draw_loc,
+        );
+
+        if should_get_widget_bounds {
+            // Each tab label has a space on either side, and a divider after it.
+            let mut current_x = draw_loc.x + 1;
+            let current_y = draw_loc.y;
+            let mut tab_click_locs: Vec<((u16, u16), (u16, u16))> = vec![];
+            for name in &layout_tab_state.tab_names {
+                let width = unicode_width::UnicodeWidthStr::width(name.as_str()) as u16;
+                tab_click_locs.push(((current_x, current_y), (current_x + width, current_y)));
+                current_x += width + 3;
+            }
+            layout_tab_state.tab_click_locs = Some(tab_click_locs);
+        }
+    }
+
+    /// Draws the line along the bottom that shows when the data is frozen or the layout is being
+    /// edited.
+    fn draw_status_indicator<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &app::App, draw_loc: Rect,
+    ) {
+        let text = if app_state.is_editing_layout() {
+            app_state.layout_edit_state.message.as_deref().unwrap_or(
+                "Editing layout: arrows resize, Shift+arrows swap, Ctrl+arrows select, t/T change type, w saves, Esc finishes",
+            )
+        } else {
+            "Frozen, press 'f' to unfreeze"
+        };
+
         f.render_widget(
             Paragraph::new(Span::styled(
-                "Frozen, press 'f' to unfreeze",
+                text,
                 self.colours.currently_selected_text_style,
             )),
             Layout::default()
@@ -304,14 +442,15 @@ impl Painter {
         use BottomWidgetType::*;
 
         terminal.draw(|mut f| {
-            let (terminal_size, frozen_draw_loc) = if app_state.is_frozen {
-                let split_loc = Layout::default()
-                    .constraints([Constraint::Min(0), Constraint::Length(1)])
-                    .split(f.size());
-                (split_loc[0], Some(split_loc[1]))
-            } else {
-                (f.size(), None)
-            };
+            let (terminal_size, status_draw_loc) =
+                if app_state.is_frozen || app_state.is_editing_layout() {
+                    let split_loc = Layout::default()
+                        .constraints([Constraint::Min(0), Constraint::Length(1)])
+                        .split(f.size());
+                    (split_loc[0], Some(split_loc[1]))
+                } else {
+                    (f.size(), None)
+                };
             let terminal_height = terminal_size.height;
             let terminal_width = terminal_size.width;
 
@@ -333,6 +472,9 @@ impl Painter {
                 // Reset dd_dialog...
                 app_state.delete_dialog_state.button_positions = vec![];
 
+                // Reset layout tabs...
+                app_state.layout_tab_state.tab_click_locs = None;
+
                 // Reset battery dialog...
                 for battery_widget in app_state.battery_state.widget_states.values_mut() {
                     battery_widget.tab_click_locs = None;
@@ -454,8 +596,8 @@ impl Painter {
                 app_state.delete_dialog_state.is_showing_dd =
                     self.draw_dd_dialog(&mut f, dd_text, app_state, middle_dialog_chunk[1]);
             } else if app_state.is_expanded {
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(&mut f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
                 let rect = Layout::default()
@@ -475,6 +617,25 @@ impl Painter {
                         rect[0],
                         app_state.current_widget.widget_id - 1,
                     ),
+                    Sensors => self.draw_sensor_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        true,
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraph => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraphLegend => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id - 1,
+                    ),
                     Mem | BasicMem => self.draw_memory_graph(
                         &mut f,
                         app_state,
@@ -531,8 +692,8 @@ impl Painter {
             } else if app_state.app_config_fields.use_basic_mode {
                 // Basic mode.  This basically removes all graphs but otherwise
                 // the same info.
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(&mut f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
                 let actual_cpu_data_len = app_state.canvas_data.cpu_data.len().saturating_sub(1);
@@ -549,24 +710,58 @@ impl Painter {
                     }
                 };
 
+                // The header is every row of compact widgets before the tables.
+                let header_rows = self.widget_layouts[self.current_layout]
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        row.children
+                            .iter()
+                            .flat_map(|col| &col.children)
+                            .flat_map(|col_row| &col_row.children)
+                            .collect::<Vec<_>>()
+                    })
+                    .take_while(|widgets| {
+                        widgets
+                            .iter()
+                            .all(|widget| widget.widget_type.is_widget_basic())
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut constraints = header_rows
+                    .iter()
+                    .map(|widgets| {
+                        if widgets.iter().any(|widget| widget.widget_type == BasicCpu) {
+                            Constraint::Length(cpu_height)
+                        } else {
+                            Constraint::Length(2)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                constraints.push(Constraint::Length(2));
+                constraints.push(Constraint::Min(5));
+
                 let vertical_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .margin(0)
-                    .constraints([
-                        Constraint::Length(cpu_height),
-                        Constraint::Length(2),
-                        Constraint::Length(2),
-                        Constraint::Min(5),
-                    ])
+                    .constraints(constraints)
                     .split(terminal_size);
 
-                let middle_chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(vertical_chunks[1]);
-                self.draw_basic_cpu(&mut f, app_state, vertical_chunks[0], 1);
-                self.draw_basic_memory(&mut f, app_state, middle_chunks[0], 2);
-                self.draw_basic_network(&mut f, app_state, middle_chunks[1], 3);
+                for (widgets, draw_loc) in header_rows.iter().zip(&vertical_chunks) {
+                    let widget_draw_locs = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(vec![
+                            Constraint::Ratio(1, widgets.len() as u32);
+                            widgets.len()
+                        ])
+                        .split(*draw_loc);
+
+                    for (widget, widget_draw_loc) in widgets.iter().zip(widget_draw_locs) {
+                        self.draw_basic_widget(f, app_state, widget, widget_draw_loc);
+                    }
+                }
+                let arrows_draw_loc = vertical_chunks[header_rows.len()];
+                let table_draw_loc = vertical_chunks[header_rows.len() + 1];
 
                 let mut later_widget_id: Option<u64> = None;
                 if let Some(basic_table_widget_state) = &app_state.basic_table_widget_state {
@@ -576,7 +771,7 @@ impl Painter {
                         Disk => self.draw_disk_table(
                             &mut f,
                             app_state,
-                            vertical_chunks[3],
+                            table_draw_loc,
                             false,
                             widget_id,
                         ),
@@ -590,7 +785,7 @@ impl Painter {
                             self.draw_process_features(
                                 &mut f,
                                 app_state,
-                                vertical_chunks[3],
+                                table_draw_loc,
                                 false,
                                 wid,
                             );
@@ -598,14 +793,14 @@ impl Painter {
                         Temp => self.draw_temp_table(
                             &mut f,
                             app_state,
-                            vertical_chunks[3],
+                            table_draw_loc,
                             false,
                             widget_id,
                         ),
                         Battery => self.draw_battery_display(
                             &mut f,
                             app_state,
-                            vertical_chunks[3],
+                            table_draw_loc,
                             false,
                             widget_id,
                         ),
@@ -614,81 +809,144 @@ impl Painter {
                 }
 
                 if let Some(widget_id) = later_widget_id {
-                    self.draw_basic_table_arrows(&mut f, app_state, vertical_chunks[2], widget_id);
+                    self.draw_basic_table_arrows(f, app_state, arrows_draw_loc, widget_id);
                 }
             } else {
                 // Draws using the passed in (or default) layout.
-                if let Some(frozen_draw_loc) = frozen_draw_loc {
-                    self.draw_frozen_indicator(&mut f, frozen_draw_loc);
+                if let Some(status_draw_loc) = status_draw_loc {
+                    self.draw_status_indicator(f, app_state, status_draw_loc);
                 }
 
-                if self.derived_widget_draw_locs.is_empty() || app_state.is_force_redraw {
-                    let draw_locs = Layout::default()
-                        .margin(0)
-                        .constraints(self.row_constraints.as_ref())
-                        .direction(Direction::Vertical)
+                if self.current_layout != app_state.layout_tab_state.current_layout {
+                    self.current_layout = app_state.layout_tab_state.current_layout;
+                    self.generate_layout_constraints();
+                }
+
+                // Multiple layouts get a tab bar along the top to show which one is shown.
+                let terminal_size = if app_state.layout_tab_state.tab_names.len() > 1 {
+                    let split_loc = Layout::default()
+                        .constraints([Constraint::Length(1), Constraint::Min(0)])
                         .split(terminal_size);
+                    self.draw_layout_tabs(f, app_state, split_loc[0]);
+                    split_loc[1]
+                } else {
+                    terminal_size
+                };
+
+                if self.derived_widget_draw_locs.is_empty() || app_state.is_force_redraw {
+                    app_state.hidden_widget_ids.clear();
+
+                    let rows = &self.widget_layouts[self.current_layout].rows;
+                    let (draw_locs, are_rows_hidden) = split_layout_by_priority(
+                        &self.row_constraints,
+                        &rows.iter().map(|row| row.priority).collect::<Vec<_>>(),
+                        Direction::Vertical,
+                        terminal_size,
+                    );
 
                     self.derived_widget_draw_locs = izip!(
                         draw_locs,
+                        are_rows_hidden,
                         &self.col_constraints,
                         &self.col_row_constraints,
                         &self.layout_constraints,
-                        &self.widget_layout.rows
+                        rows
                     )
                     .map(
                         |(
                             draw_loc,
+                            is_row_hidden,
                             col_constraint,
                             col_row_constraint,
                             row_constraint_vec,
                             cols,
                         )| {
+                            let (col_draw_locs, are_cols_hidden) = split_layout_by_priority(
+                                col_constraint,
+                                &cols
+                                    .children
+                                    .iter()
+                                    .map(|col| col.priority)
+                                    .collect::<Vec<_>>(),
+                                Direction::Horizontal,
+                                draw_loc,
+                            );
                             izip!(
-                                Layout::default()
-                                    .constraints(col_constraint.as_ref())
-                                    .direction(Direction::Horizontal)
-                                    .split(draw_loc)
-                                    .into_iter(),
+                                col_draw_locs,
+                                are_cols_hidden,
                                 col_row_constraint,
                                 row_constraint_vec,
                                 &cols.children
                             )
-                            .map(|(split_loc, constraint, col_constraint_vec, col_rows)| {
-                                izip!(
-                                    Layout::default()
-                                        .constraints(constraint.as_ref())
-                                        .direction(Direction::Vertical)
-                                        .split(split_loc)
-                                        .into_iter(),
+                            .map(
+                                |(
+                                    split_loc,
+                                    is_col_hidden,
+                                    constraint,
                                     col_constraint_vec,
-                                    &col_rows.children
-                                )
-                                .map(|(draw_loc, col_row_constraint_vec, widgets)| {
-                                    // Note that col_row_constraint_vec CONTAINS the widget constraints
-                                    let widget_draw_locs = Layout::default()
-                                        .constraints(col_row_constraint_vec.as_ref())
-                                        .direction(Direction::Horizontal)
-                                        .split(draw_loc);
-
-                                    // Side effect, draw here.
-                                    self.draw_widgets_with_constraints(
-                                        &mut f,
-                                        app_state,
-                                        widgets,
-                                        &widget_draw_locs,
-                                    );
-
-                                    widget_draw_locs
-                                })
-                                .collect()
-                            })
+                                    col_rows,
+                                )| {
+                                    let (col_row_draw_locs, are_col_rows_hidden) =
+                                        split_layout_by_priority(
+                                            constraint,
+                                            &col_rows
+                                                .children
+                                                .iter()
+                                                .map(|col_row| col_row.priority)
+                                                .collect::<Vec<_>>(),
+                                            Direction::Vertical,
+                                            split_loc,
+                                        );
+                                    izip!(
+                                        col_row_draw_locs,
+                                        are_col_rows_hidden,
+                                        col_constraint_vec,
+                                        &col_rows.children
+                                    )
+                                    .map(
+                                        |(
+                                            draw_loc,
+                                            is_col_row_hidden,
+                                            col_row_constraint_vec,
+                                            widgets,
+                                        )| {
+                                            // Widgets hidden to make room for others can't be
+                                            // selected until they're shown again.
+                                            if is_row_hidden || is_col_hidden || is_col_row_hidden {
+                                                app_state.hidden_widget_ids.extend(
+                                                    widgets
+                                                        .children
+                                                        .iter()
+                                                        .map(|widget| widget.widget_id),
+                                                );
+                                            }
+
+                                            // Note that col_row_constraint_vec CONTAINS the widget constraints
+                                            let widget_draw_locs = Layout::default()
+                                                .constraints(col_row_constraint_vec.as_slice())
+                                                .direction(Direction::Horizontal)
+                                                .split(draw_loc);
+
+                                            // Side effect, draw here.
+                                            self.draw_widgets_with_constraints(
+                                                f,
+                                                app_state,
+                                                widgets,
+                                                &widget_draw_locs,
+                                            );
+
+                                            widget_draw_locs
+                                        },
+                                    )
+                                    .collect()
+                                },
+                            )
                             .collect()
                         },
                     )
                     .collect();
                 } else {
-                    self.widget_layout
+                    self.widget_layouts[self.current_layout]
                         .rows
                         .iter()
                         .map(|row| &row.children)
@@ -708,6 +966,10 @@ impl Painter {
             }
         })?;
 
+        if !app_state.is_expanded && !app_state.app_config_fields.use_basic_mode {
+            app_state.move_selection_off_hidden_widget(&self.widget_layouts[self.current_layout]);
+        }
+
         app_state.is_force_redraw = false;
         app_state.is_determining_widget_boundary = false;
 
@@ -720,6 +982,11 @@ impl Painter {
     ) {
         use BottomWidgetType::*;
         for (widget, widget_draw_loc) in widgets.children.iter().zip(widget_draw_locs) {
+            // Widgets hidden to make room for others have nowhere to be drawn.
+            if widget_draw_loc.area() == 0 {
+                continue;
+            }
+
             match &widget.widget_type {
                 Empty => {}
                 Cpu => self.draw_cpu(f, app_state, *widget_draw_loc, widget.widget_id),
@@ -728,6 +995,10 @@ impl Painter {
                 Temp => {
                     self.draw_temp_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
                 }
+                TempGraph => self.draw_temp_graph(f, app_state, *widget_draw_loc, widget.widget_id),
+                Sensors => {
+                    self.draw_sensor_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
+                }
                 Disk => {
                     self.draw_disk_table(f, app_state, *widget_draw_loc, true, widget.widget_id)
                 }
@@ -745,8 +1016,41 @@ impl Painter {
                     true,
                     widget.widget_id,
                 ),
+                StatusBar => self.draw_status_bar(f, app_state, *widget_draw_loc, widget.widget_id),
+                _ if widget.widget_type.is_widget_basic() => {
+                    self.draw_basic_widget(f, app_state, widget, *widget_draw_loc)
+                }
                 _ => {}
             }
         }
     }
+
+    /// Draws one of the compact widgets used in basic mode.
+    fn draw_basic_widget<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, widget: &BottomWidget, draw_loc: Rect,
+    ) {
+        use BottomWidgetType::*;
+        match &widget.widget_type {
+            BasicCpu => self.draw_basic_cpu(f, app_state, draw_loc, widget.widget_id),
+            BasicMem => self.draw_basic_memory(f, app_state, draw_loc, widget.widget_id),
+            BasicNet => self.draw_basic_network(f, app_state, draw_loc, widget.widget_id),
+            BasicTemp => self.draw_basic_temp(f, app_state, draw_loc, widget.widget_id),
+            BasicDisk => self.draw_basic_disk(f, app_state, draw_loc, widget.widget_id),
+            BasicLoadAvg => self.draw_basic_load_avg(f, app_state, draw_loc, widget.widget_id),
+            BasicBattery => self.draw_basic_battery(f, app_state, draw_loc, widget.widget_id),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the constraint for part of the layout, from its size if it has one, or otherwise from
+/// its ratio.
+fn get_layout_constraint(size: Option<LayoutSize>, ratio: u32, total_ratio: u32) -> Constraint {
+    match size {
+        Some(LayoutSize::Length(length)) => Constraint::Length(length),
+        Some(LayoutSize::Percentage(percentage)) => Constraint::Percentage(percentage),
+        Some(LayoutSize::Min(min_size)) => Constraint::Min(min_size),
+        Some(LayoutSize::Max(max_size)) => Constraint::Max(max_size),
+        None => Constraint::Ratio(ratio, total_ratio),
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{options::ConfigColours, utils::error};
+use crate::{canvas::drawing_utils::get_value_colour, options::ConfigColours, utils::error};
 use anyhow::Context;
 use colour_utils::*;
 use tui::style::{Color, Style};
@@ -23,11 +23,24 @@ pub struct CanvasColours {
     pub text_style: Style,
     pub widget_title_style: Style,
     pub graph_style: Style,
+    pub graph_label_style: Style,
     pub high_battery_colour: Style,
     pub medium_battery_colour: Style,
     pub low_battery_colour: Style,
+    pub disk_warning_style: Style,
+    pub disk_critical_style: Style,
     pub invalid_query_style: Style,
     pub disabled_text_style: Style,
+    /// Patched onto every other row of tables, which by default leaves them unchanged.
+    pub alternate_row_style: Style,
+    /// Colour stops from 0 to 1 that usage percentages in tables are shaded along.  Usage isn't
+    /// shaded if this is empty.
+    pub value_gradient: Vec<(f64, Color)>,
+    pub temp_warm_colour: Color,
+    pub temp_hot_colour: Color,
+    /// Temperatures in Celsius at which temperatures are coloured, if set.
+    pub temp_warm_threshold: Option<f64>,
+    pub temp_hot_threshold: Option<f64>,
 }
 
 impl Default for CanvasColours {
@@ -66,16 +79,34 @@ impl Default for CanvasColours {
             text_style: Style::default().fg(text_colour),
             widget_title_style: Style::default().fg(text_colour),
             graph_style: Style::default().fg(text_colour),
+            graph_label_style: Style::default().fg(text_colour),
             high_battery_colour: Style::default().fg(Color::Green),
             medium_battery_colour: Style::default().fg(Color::Yellow),
             low_battery_colour: Style::default().fg(Color::Red),
+            disk_warning_style: Style::default().fg(Color::Yellow),
+            disk_critical_style: Style::default().fg(Color::Red),
             invalid_query_style: Style::default().fg(tui::style::Color::Red),
             disabled_text_style: Style::default().fg(Color::DarkGray),
+            alternate_row_style: Style::default(),
+            value_gradient: Vec::new(),
+            temp_warm_colour: Color::Yellow,
+            temp_hot_colour: Color::Red,
+            temp_warm_threshold: None,
+            temp_hot_threshold: None,
         }
     }
 }
 
 impl CanvasColours {
+    /// Returns the style of a table row, striping every other row with the alternate row style.
+    pub fn get_row_style(&self, style: Style, row_index: usize) -> Style {
+        if row_index % 2 == 1 {
+            style.patch(self.alternate_row_style)
+        } else {
+            style
+        }
+    }
+
     pub fn set_colours_from_palette(&mut self, colours: &ConfigColours) -> anyhow::Result<()> {
         if let Some(border_color) = &colours.border_color {
             self.set_border_colour(border_color)
@@ -152,6 +183,11 @@ impl CanvasColours {
                 .context("Update 'graph_color' in your config file..")?;
         }
 
+        if let Some(graph_label_color) = &colours.graph_label_color {
+            self.set_graph_label_colour(graph_label_color)
+                .context("Update 'graph_label_color' in your config file.")?;
+        }
+
         if let Some(high_battery_color) = &colours.high_battery_color {
             self.set_high_battery_color(high_battery_color)
                 .context("Update 'high_battery_color' in your config file.")?;
@@ -167,11 +203,54 @@ impl CanvasColours {
                 .context("Update 'low_battery_color' in your config file.")?;
         }
 
+        if let Some(disk_warning_color) = &colours.disk_warning_color {
+            self.set_disk_warning_colour(disk_warning_color)
+                .context("Update 'disk_warning_color' in your config file.")?;
+        }
+
+        if let Some(disk_critical_color) = &colours.disk_critical_color {
+            self.set_disk_critical_colour(disk_critical_color)
+                .context("Update 'disk_critical_color' in your config file.")?;
+        }
+
         if let Some(disabled_text_color) = &colours.disabled_text_color {
             self.set_disabled_text_colour(disabled_text_color)
                 .context("Update 'disabled_text_color' in your config file.")?;
         }
 
+        if let Some(invalid_query_color) = &colours.invalid_query_color {
+            self.set_invalid_query_colour(invalid_query_color)
+                .context("Update 'invalid_query_color' in your config file.")?;
+        }
+
+        if let Some(alternate_row_color) = &colours.alternate_row_color {
+            self.set_alternate_row_colour(alternate_row_color)
+                .context("Update 'alternate_row_color' in your config file.")?;
+        }
+
+        if let Some(gradient_colors) = &colours.gradient_colors {
+            self.set_value_gradient(gradient_colors)
+                .context("Update 'gradient_colors' in your config file.")?;
+        }
+
+        if let Some(temp_warm_color) = &colours.temp_warm_color {
+            self.temp_warm_colour = get_colour_from_config(temp_warm_color)
+                .context("Update 'temp_warm_color' in your config file.")?;
+        }
+
+        if let Some(temp_hot_color) = &colours.temp_hot_color {
+            self.temp_hot_colour = get_colour_from_config(temp_hot_color)
+                .context("Update 'temp_hot_color' in your config file.")?;
+        }
+
+        if colours.temp_warm_threshold.is_some() {
+            self.temp_warm_threshold = colours.temp_warm_threshold;
+        }
+
+        if colours.temp_hot_threshold.is_some() {
+            self.temp_hot_threshold = colours.temp_hot_threshold;
+        }
+
         if let Some(rx_total_color) = &colours.rx_total_color {
             self.set_rx_total_colour(rx_total_color)?;
         }
@@ -258,6 +337,46 @@ impl CanvasColours {
         Ok(())
     }
 
+    pub fn set_value_gradient(&mut self, colours: &[String]) -> error::Result<()> {
+        let num_stops = colours.len();
+        self.value_gradient = colours
+            .iter()
+            .enumerate()
+            .map(|(itx, colour)| {
+                let stop = if num_stops > 1 {
+                    itx as f64 / (num_stops - 1) as f64
+                } else {
+                    0.0
+                };
+                Ok((stop, get_colour_from_config(colour)?))
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Returns the style of a usage percentage, shaded along the value gradient.
+    pub fn get_usage_style(&self, usage_percent: f64) -> Option<Style> {
+        get_value_colour(usage_percent / 100.0, &self.value_gradient, true)
+            .map(|colour| Style::default().fg(colour))
+    }
+
+    /// Returns the style of a temperature in Celsius, if it's at or above one of the thresholds.
+    pub fn get_temperature_style(&self, celsius: f64) -> Option<Style> {
+        let mut stops = [
+            self.temp_warm_threshold
+                .map(|threshold| (threshold, self.temp_warm_colour)),
+            self.temp_hot_threshold
+                .map(|threshold| (threshold, self.temp_hot_colour)),
+        ]
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        get_value_colour(celsius, &stops, false).map(|colour| Style::default().fg(colour))
+    }
+
     pub fn set_scroll_entry_text_color(&mut self, colour: &str) -> error::Result<()> {
         self.currently_selected_text_colour = get_colour_from_config(colour)?;
         self.currently_selected_text_style = Style::default()
@@ -281,6 +400,23 @@ impl CanvasColours {
 
     pub fn set_graph_colour(&mut self, colour: &str) -> error::Result<()> {
         self.graph_style = get_style_from_config(colour)?;
+        // Labels follow the graph colour unless they're set separately.
+        self.graph_label_style = self.graph_style;
+        Ok(())
+    }
+
+    pub fn set_graph_label_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.graph_label_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_invalid_query_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.invalid_query_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_alternate_row_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.alternate_row_style = Style::default().bg(get_colour_from_config(colour)?);
         Ok(())
     }
 
@@ -298,4 +434,14 @@ impl CanvasColours {
         self.low_battery_colour = get_style_from_config(colour)?;
         Ok(())
     }
+
+    pub fn set_disk_warning_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.disk_warning_style = get_style_from_config(colour)?;
+        Ok(())
+    }
+
+    pub fn set_disk_critical_colour(&mut self, colour: &str) -> error::Result<()> {
+        self.disk_critical_style = get_style_from_config(colour)?;
+        Ok(())
+    }
 }
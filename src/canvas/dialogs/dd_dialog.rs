@@ -90,7 +90,7 @@ impl KillDialog for Painter {
                         Constraint::Percentage(30),
                         Constraint::Percentage(35),
                     ]
-                    .as_ref(),
+                    .as_slice(),
                 )
                 .split(*button_draw_loc);
 
@@ -255,7 +255,7 @@ impl KillDialog for Painter {
                             Constraint::Min(0),
                             Constraint::Length((button_draw_loc.width - 14) / 2),
                         ]
-                        .as_ref(),
+                        .as_slice(),
                     )
                     .split(*button_draw_loc)[1];
 
@@ -382,7 +382,7 @@ impl KillDialog for Painter {
                     } else {
                         vec![Constraint::Min(3), Constraint::Length(btn_height)]
                     }
-                    .as_ref(),
+                    .as_slice(),
                 )
                 .split(draw_loc);
 
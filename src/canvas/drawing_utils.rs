@@ -1,5 +1,175 @@
-use crate::app;
-use std::cmp::{max, min};
+use crate::app::{self, layout_manager::BottomWidget, GraphStyle};
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+use tui::{
+    layout::{Constraint, Direction, Rect},
+    style::Color,
+    symbols::Marker,
+};
+
+/// Splits an area between the parts of a layout.  Lengths and percentages are taken out first, and
+/// then ratios, minimums, and maximums share whatever is left, with minimums and maximums sized
+/// like a ratio of 1 within their bounds.  Any space that nothing can grow into is left empty.
+///
+/// This is used over [`tui::layout::Layout::split`] as tui's solver treats every constraint as
+/// equally weak, which makes mixing them unpredictable.
+pub fn split_layout(constraints: &[Constraint], direction: Direction, area: Rect) -> Vec<Rect> {
+    let total_size = match direction {
+        Direction::Horizontal => area.width,
+        Direction::Vertical => area.height,
+    };
+    let mut sizes = vec![0; constraints.len()];
+    let mut remaining = total_size;
+
+    for (size, constraint) in sizes.iter_mut().zip(constraints) {
+        let fixed_size = match constraint {
+            Constraint::Length(length) => *length,
+            Constraint::Percentage(percentage) => {
+                (u32::from(total_size) * u32::from(*percentage) / 100) as u16
+            }
+            _ => continue,
+        };
+        *size = min(fixed_size, remaining);
+        remaining -= *size;
+    }
+
+    let get_weight = |constraint: &Constraint| match constraint {
+        Constraint::Ratio(ratio, _) => *ratio,
+        Constraint::Min(_) | Constraint::Max(_) => 1,
+        _ => 0,
+    };
+    let mut shared: Vec<usize> = (0..constraints.len())
+        .filter(|index| get_weight(&constraints[*index]) > 0)
+        .collect();
+
+    // Parts whose share is out of their bounds are set to the bound, and the rest is shared again.
+    loop {
+        let total_weight: u32 = shared
+            .iter()
+            .map(|index| get_weight(&constraints[*index]))
+            .sum();
+        let available = remaining;
+        let get_share = |index: usize| {
+            (u32::from(available) * get_weight(&constraints[index]) / max(total_weight, 1)) as u16
+        };
+
+        let bounded = shared
+            .iter()
+            .enumerate()
+            .find_map(|(shared_index, index)| match constraints[*index] {
+                Constraint::Min(min_size) if get_share(*index) < min_size => {
+                    Some((shared_index, min_size))
+                }
+                Constraint::Max(max_size) if get_share(*index) > max_size => {
+                    Some((shared_index, max_size))
+                }
+                _ => None,
+            });
+
+        match bounded {
+            Some((shared_index, bound)) => {
+                let index = shared.remove(shared_index);
+                sizes[index] = min(bound, remaining);
+                remaining -= sizes[index];
+            }
+            None => {
+                for index in &shared {
+                    sizes[*index] = get_share(*index);
+                    remaining -= sizes[*index];
+                }
+                break;
+            }
+        }
+    }
+
+    // Hand out what rounding left over, one line or column at a time.
+    for index in shared {
+        if remaining == 0 {
+            break;
+        }
+        if !matches!(constraints[index], Constraint::Max(max_size) if sizes[index] >= max_size) {
+            sizes[index] += 1;
+            remaining -= 1;
+        }
+    }
+
+    let mut position = match direction {
+        Direction::Horizontal => area.x,
+        Direction::Vertical => area.y,
+    };
+    sizes
+        .into_iter()
+        .map(|size| {
+            let rect = match direction {
+                Direction::Horizontal => Rect::new(position, area.y, size, area.height),
+                Direction::Vertical => Rect::new(area.x, position, area.width, size),
+            };
+            position += size;
+            rect
+        })
+        .collect()
+}
+
+/// The smallest width a part of the layout can be drawn in before it counts as too small.
+const MIN_LAYOUT_PART_WIDTH: u16 = 10;
+
+/// The smallest height a part of the layout can be drawn in before it counts as too small; a
+/// border on either side and one line between them.
+const MIN_LAYOUT_PART_HEIGHT: u16 = 3;
+
+/// Splits an area like [`split_layout`], but while any part would be too small, hides whichever
+/// part has the lowest priority and splits again.  Only parts with a priority can be hidden, and
+/// hidden parts are given an empty area.
+///
+/// Returns the area of each part, along with whether it was hidden.
+pub fn split_layout_by_priority(
+    constraints: &[Constraint], priorities: &[Option<u32>], direction: Direction, area: Rect,
+) -> (Vec<Rect>, Vec<bool>) {
+    let min_size = match direction {
+        Direction::Horizontal => MIN_LAYOUT_PART_WIDTH,
+        Direction::Vertical => MIN_LAYOUT_PART_HEIGHT,
+    };
+    let mut constraints = constraints.to_vec();
+    let mut is_hidden = vec![false; constraints.len()];
+
+    loop {
+        let split = split_layout(&constraints, direction.clone(), area);
+        let is_too_small = split.iter().zip(&constraints).any(|(rect, constraint)| {
+            let size = match direction {
+                Direction::Horizontal => rect.width,
+                Direction::Vertical => rect.height,
+            };
+            // Parts that asked to be smaller, like the hidden ones, are left alone.
+            let needed_size = match constraint {
+                Constraint::Length(length) => min(*length, min_size),
+                Constraint::Min(min_length) => max(*min_length, min_size),
+                _ => min_size,
+            };
+            size < needed_size
+        });
+
+        // Ties hide the later part first.
+        let lowest_priority = priorities
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, priority)| match priority {
+                Some(priority) if !is_hidden[index] => Some((index, *priority)),
+                _ => None,
+            })
+            .min_by_key(|(_, priority)| *priority);
+
+        match lowest_priority {
+            Some((index, _)) if is_too_small => {
+                is_hidden[index] = true;
+                constraints[index] = Constraint::Length(0);
+            }
+            _ => return (split, is_hidden),
+        }
+    }
+}
 
 /// Return a (hard)-width vector for column widths.
 ///
@@ -216,3 +386,177 @@ pub fn interpolate_points(point_one: &(f64, f64), point_two: &(f64, f64), time:
 
     (point_one.1 + (time - point_one.0) * slope).max(0.0)
 }
+
+/// Replaces the last point before the left edge of the graph with one interpolated to lie on the
+/// edge, returning the replaced point so it can be restored after drawing.
+pub fn interpolate_left_edge(
+    data: &mut [(f64, f64)], time_start: f64,
+) -> Option<(usize, (f64, f64))> {
+    let end_pos = data.iter().position(|(time, _data)| *time >= time_start)?;
+    if end_pos > 1 {
+        let start_pos = end_pos - 1;
+        let old = data[start_pos];
+        let new_point = (
+            time_start,
+            interpolate_points(&data[start_pos], &data[end_pos], time_start),
+        );
+        data[start_pos] = new_point;
+        Some((start_pos, old))
+    } else {
+        None
+    }
+}
+
+/// Returns the name shown in a widget's title, which is the title given to it in the layout if it
+/// has one.
+pub fn get_widget_name(
+    widget_map: &HashMap<u64, BottomWidget>, widget_id: u64, default_name: &str,
+) -> String {
+    widget_map
+        .get(&widget_id)
+        .and_then(|widget| widget.options.title.clone())
+        .unwrap_or_else(|| default_name.to_string())
+}
+
+/// Returns the marker a graph is drawn with.
+pub fn get_graph_marker(graph_style: GraphStyle) -> Marker {
+    match graph_style {
+        GraphStyle::Dot => Marker::Dot,
+        GraphStyle::Block => Marker::Block,
+        GraphStyle::Braille | GraphStyle::Filled | GraphStyle::Stacked => Marker::Braille,
+    }
+}
+
+/// Returns how many points a filled graph needs across its width, which is one per cell of the
+/// marker it's drawn with.
+pub fn get_filled_sample_count(graph_style: GraphStyle, width: u16) -> usize {
+    match get_graph_marker(graph_style) {
+        Marker::Braille => usize::from(width) * 2,
+        _ => usize::from(width),
+    }
+}
+
+/// Returns the value of a series at a time, interpolated from the points around it, or `None` if
+/// the series hasn't started yet.  `index` is where to start looking, and is moved forward so that
+/// increasing times can be looked up without searching from the start each time.
+fn get_value_at(data: &[(f64, f64)], time: f64, index: &mut usize) -> Option<f64> {
+    while *index + 1 < data.len() && data[*index + 1].0 <= time {
+        *index += 1;
+    }
+
+    let point = data.get(*index)?;
+    if time < point.0 {
+        None
+    } else if let Some(next_point) = data.get(*index + 1) {
+        Some(interpolate_points(point, next_point, time))
+    } else {
+        Some(point.1)
+    }
+}
+
+/// Turns series of points into ones that fill in the area under each series when drawn as lines,
+/// by going up and down at `num_samples` evenly spaced times from `time_start` to 0.
+///
+/// If `stacked` is set, each series is drawn on top of the ones before it instead of from zero.
+pub fn get_filled_points(
+    series: &[&[(f64, f64)]], time_start: f64, num_samples: usize, stacked: bool,
+) -> Vec<Vec<(f64, f64)>> {
+    let mut indices = vec![0; series.len()];
+    let mut filled_points = vec![Vec::with_capacity(num_samples * 2); series.len()];
+    let step = if num_samples > 1 {
+        -time_start / (num_samples - 1) as f64
+    } else {
+        0.0
+    };
+
+    for sample in 0..num_samples {
+        let time = time_start + step * sample as f64;
+        let mut base = 0.0;
+
+        for ((data, index), points) in series
+            .iter()
+            .zip(indices.iter_mut())
+            .zip(filled_points.iter_mut())
+        {
+            if let Some(value) = get_value_at(data, time, index) {
+                let (lower, upper) = if stacked {
+                    (base, base + value)
+                } else {
+                    (0.0, value)
+                };
+                base = upper;
+
+                // Alternating the direction joins each sample to the next along the top or bottom
+                // of the area, rather than cutting across it.
+                if sample % 2 == 0 {
+                    points.push((time, lower));
+                    points.push((time, upper));
+                } else {
+                    points.push((time, upper));
+                    points.push((time, lower));
+                }
+            }
+        }
+    }
+
+    filled_points
+}
+
+/// Returns the points to draw for a series, which are its filled points if the graph is filled,
+/// or its own data otherwise.
+pub fn get_drawn_points<'a>(
+    filled_points: &'a [Vec<(f64, f64)>], itx: usize, data: &'a [(f64, f64)],
+) -> &'a [(f64, f64)] {
+    filled_points
+        .get(itx)
+        .map(|points| &points[..])
+        .unwrap_or(data)
+}
+
+/// Returns the colour of a value, given colour stops sorted by their value.
+///
+/// If `blend` is set, a value between two RGB stops is blended between them, a value between other
+/// stops takes the nearer one, and a value outside the stops takes the closest one.  Otherwise,
+/// the value takes the highest stop at or below it, and has no colour if it's below every stop.
+pub fn get_value_colour(value: f64, stops: &[(f64, Color)], blend: bool) -> Option<Color> {
+    let upper = stops.iter().position(|(stop, _colour)| *stop > value);
+
+    if !blend {
+        return match upper {
+            Some(0) => None,
+            Some(upper) => Some(stops[upper - 1].1),
+            None => stops.last().map(|(_stop, colour)| *colour),
+        };
+    }
+
+    match upper {
+        Some(0) => stops.first().map(|(_stop, colour)| *colour),
+        Some(upper) => {
+            let (lower_stop, lower_colour) = stops[upper - 1];
+            let (upper_stop, upper_colour) = stops[upper];
+            let ratio = (value - lower_stop) / (upper_stop - lower_stop);
+
+            match (lower_colour, upper_colour) {
+                (Color::Rgb(lower_r, lower_g, lower_b), Color::Rgb(upper_r, upper_g, upper_b)) => {
+                    let blend_component = |lower: u8, upper: u8| {
+                        (lower as f64 + (upper as f64 - lower as f64) * ratio).round() as u8
+                    };
+
+                    Some(Color::Rgb(
+                        blend_component(lower_r, upper_r),
+                        blend_component(lower_g, upper_g),
+                        blend_component(lower_b, upper_b),
+                    ))
+                }
+                _ => {
+                    if ratio < 0.5 {
+                        Some(lower_colour)
+                    } else {
+                        Some(upper_colour)
+                    }
+                }
+            }
+        }
+        None => stops.last().map(|(_stop, colour)| *colour),
+    }
+}
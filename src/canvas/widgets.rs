@@ -1,23 +1,39 @@
 pub mod basic_table_arrows;
+pub mod battery_basic;
 pub mod battery_display;
 pub mod cpu_basic;
 pub mod cpu_graph;
+pub mod disk_basic;
 pub mod disk_table;
+pub mod load_avg_basic;
 pub mod mem_basic;
 pub mod mem_graph;
 pub mod network_basic;
 pub mod network_graph;
 pub mod process_table;
+pub mod sensor_table;
+pub mod status_bar;
+pub mod table_search;
+pub mod temp_basic;
+pub mod temp_graph;
 pub mod temp_table;
 
 pub use basic_table_arrows::BasicTableArrows;
+pub use battery_basic::BatteryBasicWidget;
 pub use battery_display::BatteryDisplayWidget;
 pub use cpu_basic::CpuBasicWidget;
 pub use cpu_graph::CpuGraphWidget;
+pub use disk_basic::DiskBasicWidget;
 pub use disk_table::DiskTableWidget;
+pub use load_avg_basic::LoadAvgBasicWidget;
 pub use mem_basic::MemBasicWidget;
 pub use mem_graph::MemGraphWidget;
 pub use network_basic::NetworkBasicWidget;
 pub use network_graph::NetworkGraphWidget;
 pub use process_table::ProcessTableWidget;
+pub use sensor_table::SensorTableWidget;
+pub use status_bar::StatusBarWidget;
+pub use table_search::TableSearchWidget;
+pub use temp_basic::TempBasicWidget;
+pub use temp_graph::TempGraphWidget;
 pub use temp_table::TempTableWidget;
@@ -0,0 +1,99 @@
+use crate::{
+    app::App,
+    canvas::{drawing_utils::*, Painter},
+    constants::*,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Paragraph},
+};
+
+pub trait BatteryBasicWidget {
+    fn draw_basic_battery<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl BatteryBasicWidget for Painter {
+    /// Draws the first battery's charge as a bar, and how long it has until it is full or empty.
+    fn draw_basic_battery<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let margined_loc = Layout::default()
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(1)
+            .split(draw_loc);
+
+        if app_state.current_widget.widget_id == widget_id {
+            f.render_widget(
+                Block::default()
+                    .borders(*SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style),
+                draw_loc,
+            );
+        }
+
+        let battery_text = if let Some(battery_details) = app_state.canvas_data.battery_data.first()
+        {
+            let charge_percentage = battery_details.charge_percentage;
+
+            // +7 due to 3 + 2 + 2 columns for the name & space + bar bounds + margin spacing
+            // Then + length of the percentage
+            let charge_label = format!("{:3.0}%", charge_percentage.round());
+            let bar_length =
+                usize::from(draw_loc.width.saturating_sub(7)).saturating_sub(charge_label.len());
+            let num_bars = calculate_basic_use_bars(charge_percentage, bar_length);
+
+            let time_label = if let Some(duration_until_full) = &battery_details.duration_until_full
+            {
+                format!("Full in {}", duration_until_full)
+            } else if let Some(duration_until_empty) = &battery_details.duration_until_empty {
+                format!("Empty in {}", duration_until_empty)
+            } else {
+                format!("Using {}", battery_details.watt_consumption)
+            };
+
+            vec![
+                Spans::from(Span::styled(
+                    format!(
+                        "BAT[{}{}{}]",
+                        "|".repeat(num_bars),
+                        " ".repeat(bar_length - num_bars),
+                        charge_label
+                    ),
+                    if charge_percentage < 10.0 {
+                        self.colours.low_battery_colour
+                    } else if charge_percentage < 50.0 {
+                        self.colours.medium_battery_colour
+                    } else {
+                        self.colours.high_battery_colour
+                    },
+                )),
+                Spans::from(Span::styled(time_label, self.colours.text_style)),
+            ]
+        } else {
+            vec![Spans::from(Span::styled(
+                "No Battery Found",
+                self.colours.text_style,
+            ))]
+        };
+
+        f.render_widget(
+            Paragraph::new(battery_text).block(Block::default()),
+            margined_loc[0],
+        );
+
+        // Update draw loc in widget map
+        if app_state.should_get_widget_bounds() {
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
     app::App,
-    canvas::{drawing_utils::calculate_basic_use_bars, Painter},
+    canvas::{
+        drawing_utils::{calculate_basic_use_bars, get_widget_name},
+        Painter,
+    },
     constants::*,
 };
 
@@ -41,25 +44,27 @@ impl BatteryDisplayWidget for Painter {
                 app_state.app_config_fields.table_gap
             };
 
+            let title_name = format!(
+                " {} ",
+                get_widget_name(&app_state.widget_map, widget_id, "Battery")
+            );
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Battery ── Esc to go back ";
+                let title_base = format!("{}── Esc to go back ", title_name);
                 Spans::from(vec![
-                    Span::styled(" Battery ".to_string(), self.colours.widget_title_style),
+                    Span::styled(title_name, self.colours.widget_title_style),
                     Span::styled(
                         format!(
                             "─{}─ Esc to go back ",
                             "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
+                                UnicodeSegmentation::graphemes(title_base.as_str(), true).count()
+                                    + 2
                             ))
                         ),
                         border_style,
                     ),
                 ])
             } else {
-                Spans::from(Span::styled(
-                    " Battery ".to_string(),
-                    self.colours.widget_title_style,
-                ))
+                Spans::from(Span::styled(title_name, self.colours.widget_title_style))
             };
 
             let battery_block = if draw_border {
@@ -2,26 +2,33 @@ use once_cell::sync::Lazy;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    app::{layout_manager::WidgetDirection, App},
+    app::{
+        data_harvester::temperature::TemperatureType, layout_manager::WidgetDirection, App,
+        CpuGraphMode, CpuLegendColumn, GraphStyle,
+    },
     canvas::{
-        drawing_utils::{get_column_widths, get_start_position, interpolate_points},
+        drawing_utils::{
+            get_column_widths, get_drawn_points, get_filled_points, get_filled_sample_count,
+            get_graph_marker, get_start_position, get_widget_name, interpolate_left_edge,
+        },
         Painter,
     },
     constants::*,
-    data_conversion::ConvertedCpuData,
+    data_conversion::{get_temperature_unit, ConvertedCpuData},
 };
 
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    symbols::Marker,
+    style::{Color, Style},
     terminal::Frame,
     text::Span,
     text::{Spans, Text},
-    widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Paragraph, Row, Table},
 };
 
 const CPU_LEGEND_HEADER: [&str; 2] = ["CPU", "Use%"];
+const CPU_BREAKDOWN_LEGEND_WIDTH: u16 = 4;
 const AVG_POSITION: usize = 1;
 const ALL_POSITION: usize = 0;
 
@@ -39,6 +46,9 @@ pub trait CpuGraphWidget {
     fn draw_cpu_graph<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     );
+    fn draw_cpu_heatmap<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &App, draw_loc: Rect, widget_id: u64,
+    );
     fn draw_cpu_legend<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     );
@@ -48,10 +58,16 @@ impl CpuGraphWidget for Painter {
     fn draw_cpu<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
+        let left_legend = app_state
+            .widget_map
+            .get(&widget_id)
+            .map(|widget| widget.left_legend)
+            .unwrap_or_default();
+
         if draw_loc.width as f64 * 0.15 <= 6.0 {
             // Skip drawing legend
             if app_state.current_widget.widget_id == (widget_id + 1) {
-                if app_state.app_config_fields.left_legend {
+                if left_legend {
                     app_state.move_widget_selection(&WidgetDirection::Right);
                 } else {
                     app_state.move_widget_selection(&WidgetDirection::Left);
@@ -71,20 +87,39 @@ impl CpuGraphWidget for Painter {
                 }
             }
         } else {
-            let (graph_index, legend_index, constraints) =
-                if app_state.app_config_fields.left_legend {
-                    (
-                        1,
-                        0,
-                        [Constraint::Percentage(15), Constraint::Percentage(85)],
-                    )
-                } else {
-                    (
-                        0,
-                        1,
-                        [Constraint::Percentage(85), Constraint::Percentage(15)],
+            // Make more room for any extra legend columns.
+            let legend_percentage = app_state
+                .cpu_state
+                .get_widget_state(widget_id)
+                .map(|cpu_widget_state| {
+                    let num_extra_columns = get_extra_legend_columns(
+                        cpu_widget_state.graph_mode,
+                        &cpu_widget_state.legend_columns,
                     )
-                };
+                    .len() as u16;
+                    (15 + 4 * num_extra_columns).min(45)
+                })
+                .unwrap_or(15);
+
+            let (graph_index, legend_index, constraints) = if left_legend {
+                (
+                    1,
+                    0,
+                    [
+                        Constraint::Percentage(legend_percentage),
+                        Constraint::Percentage(100 - legend_percentage),
+                    ],
+                )
+            } else {
+                (
+                    0,
+                    1,
+                    [
+                        Constraint::Percentage(100 - legend_percentage),
+                        Constraint::Percentage(legend_percentage),
+                    ],
+                )
+            };
 
             let partitioned_draw_loc = Layout::default()
                 .margin(0)
@@ -135,20 +170,89 @@ impl CpuGraphWidget for Painter {
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
         if let Some(cpu_widget_state) = app_state.cpu_state.widget_states.get_mut(&widget_id) {
+            if cpu_widget_state.graph_mode == CpuGraphMode::Heatmap {
+                self.draw_cpu_heatmap(f, app_state, draw_loc, widget_id);
+                return;
+            }
+
             let cpu_data: &mut [ConvertedCpuData] = &mut app_state.canvas_data.cpu_data;
 
             let display_time_labels = vec![
                 Span::styled(
                     format!("{}s", cpu_widget_state.current_display_time / 1000),
-                    self.colours.graph_style,
+                    self.colours.graph_label_style,
                 ),
-                Span::styled("0s".to_string(), self.colours.graph_style),
+                Span::styled("0s".to_string(), self.colours.graph_label_style),
             ];
 
-            let y_axis_labels = vec![
-                Span::styled("  0%", self.colours.graph_style),
-                Span::styled("100%", self.colours.graph_style),
-            ];
+            let graph_mode = cpu_widget_state.graph_mode;
+
+            let (y_axis_max, y_axis_labels) = match graph_mode {
+                CpuGraphMode::Frequency => {
+                    // Frequencies are scaled to the highest maximum frequency, or the highest
+                    // frequency seen if the maximum is unknown.  Fall back to 1GHz if there is no
+                    // data at all.
+                    let max_frequency = cpu_data
+                        .iter()
+                        .flat_map(|cpu| {
+                            cpu.frequency
+                                .and_then(|frequency| frequency.max)
+                                .into_iter()
+                                .chain(
+                                    cpu.frequency_data
+                                        .iter()
+                                        .map(|(_time, frequency)| *frequency),
+                                )
+                        })
+                        .fold(0.0, f64::max);
+                    let max_frequency = if max_frequency > 0.0 {
+                        max_frequency
+                    } else {
+                        1000.0
+                    };
+
+                    (
+                        max_frequency,
+                        vec![
+                            Span::styled("   0GHz", self.colours.graph_label_style),
+                            Span::styled(
+                                format!("{:4.1}GHz", max_frequency / 1000.0),
+                                self.colours.graph_label_style,
+                            ),
+                        ],
+                    )
+                }
+                CpuGraphMode::LoadAverage => {
+                    // Round up to the next whole load so the lines don't touch the top.
+                    let max_load = app_state
+                        .canvas_data
+                        .load_avg_graph_data
+                        .iter()
+                        .flatten()
+                        .map(|(_time, load)| *load)
+                        .fold(0.0, f64::max)
+                        .ceil()
+                        .max(1.0);
+
+                    (
+                        max_load,
+                        vec![
+                            Span::styled("   0", self.colours.graph_label_style),
+                            Span::styled(
+                                format!("{:4.0}", max_load),
+                                self.colours.graph_label_style,
+                            ),
+                        ],
+                    )
+                }
+                CpuGraphMode::Usage | CpuGraphMode::Breakdown | CpuGraphMode::Heatmap => (
+                    100.0,
+                    vec![
+                        Span::styled("  0%", self.colours.graph_label_style),
+                        Span::styled("100%", self.colours.graph_label_style),
+                    ],
+                ),
+            };
 
             let time_start = -(cpu_widget_state.current_display_time as f64);
 
@@ -180,13 +284,248 @@ impl CpuGraphWidget for Painter {
 
             let y_axis = Axis::default()
                 .style(self.colours.graph_style)
-                .bounds([0.0, 100.5])
+                .bounds([0.0, y_axis_max + 0.5])
                 .labels(y_axis_labels);
 
-            let use_dot = app_state.app_config_fields.use_dot;
+            let graph_style = cpu_widget_state.graph_style;
+            let widget_name = get_widget_name(&app_state.widget_map, widget_id, "CPU");
+            let marker = get_graph_marker(graph_style);
+            let num_samples = get_filled_sample_count(graph_style, draw_loc.width);
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
             let current_scroll_position = cpu_widget_state.scroll_state.current_scroll_position;
 
+            if graph_mode == CpuGraphMode::Breakdown {
+                // Only the selected entry is shown, where "All" is the average of the cores.
+                if let Some(cpu) = cpu_data.get_mut(current_scroll_position) {
+                    let interpolated_points = cpu
+                        .stacked_breakdown_data
+                        .iter_mut()
+                        .map(|stacked_data| interpolate_left_edge(stacked_data, time_start))
+                        .collect::<Vec<_>>();
+
+                    // The breakdown is already stacked, and each part is drawn over the ones above
+                    // it, so filling each from zero is enough for both filled and stacked graphs.
+                    let filled_points = if graph_style.is_filled() {
+                        let series = cpu
+                            .stacked_breakdown_data
+                            .iter()
+                            .map(|stacked_data| &stacked_data[..])
+                            .collect::<Vec<_>>();
+                        get_filled_points(&series, time_start, num_samples, false)
+                    } else {
+                        vec![]
+                    };
+
+                    let dataset_vector: Vec<Dataset<'_>> = cpu
+                        .stacked_breakdown_data
+                        .iter()
+                        .zip(CPU_BREAKDOWN_HEADERS.iter())
+                        .enumerate()
+                        .rev()
+                        .map(|(itx, (stacked_data, name))| {
+                            Dataset::default()
+                                .name(*name)
+                                .marker(marker)
+                                .style(
+                                    self.colours.cpu_colour_styles
+                                        [itx % self.colours.cpu_colour_styles.len()],
+                                )
+                                .data(get_drawn_points(&filled_points, itx, stacked_data))
+                                .graph_type(tui::widgets::GraphType::Line)
+                        })
+                        .collect();
+
+                    let is_on_widget = widget_id == app_state.current_widget.widget_id;
+                    let border_style = if is_on_widget {
+                        self.colours.highlighted_border_style
+                    } else {
+                        self.colours.border_style
+                    };
+
+                    let title = format!(" {} Breakdown ", cpu.cpu_name);
+                    let title = if app_state.is_expanded {
+                        Spans::from(vec![
+                            Span::styled(title.clone(), self.colours.widget_title_style),
+                            Span::styled(
+                                format!(
+                                    "─{}─ Esc to go back ",
+                                    "─".repeat(
+                                        usize::from(draw_loc.width).saturating_sub(
+                                            UnicodeSegmentation::graphemes(title.as_str(), true)
+                                                .count()
+                                                + UnicodeSegmentation::graphemes(
+                                                    "── Esc to go back ",
+                                                    true
+                                                )
+                                                .count()
+                                                + 2
+                                        )
+                                    )
+                                ),
+                                border_style,
+                            ),
+                        ])
+                    } else {
+                        Spans::from(vec![Span::styled(title, self.colours.widget_title_style)])
+                    };
+
+                    f.render_widget(
+                        Chart::new(dataset_vector)
+                            .block(
+                                Block::default()
+                                    .title(title)
+                                    .borders(Borders::ALL)
+                                    .border_style(border_style),
+                            )
+                            .x_axis(x_axis)
+                            .y_axis(y_axis)
+                            .hidden_legend_constraints((
+                                Constraint::Ratio(1, 3),
+                                Constraint::Ratio(1, 2),
+                            )),
+                        draw_loc,
+                    );
+
+                    // Reset interpolated points
+                    for (stacked_data, interpolation) in cpu
+                        .stacked_breakdown_data
+                        .iter_mut()
+                        .zip(interpolated_points)
+                    {
+                        if let Some((index, old_value)) = interpolation {
+                            if let Some(to_replace) = stacked_data.get_mut(index) {
+                                *to_replace = old_value;
+                            }
+                        }
+                    }
+                }
+
+                return;
+            }
+
+            if graph_mode == CpuGraphMode::LoadAverage {
+                let load_avg_graph_data = &mut app_state.canvas_data.load_avg_graph_data;
+                let interpolated_points = load_avg_graph_data
+                    .iter_mut()
+                    .map(|load_data| interpolate_left_edge(load_data, time_start))
+                    .collect::<Vec<_>>();
+
+                let filled_points = if graph_style.is_filled() {
+                    let series = load_avg_graph_data
+                        .iter()
+                        .map(|load_data| &load_data[..])
+                        .collect::<Vec<_>>();
+                    get_filled_points(&series, time_start, num_samples, false)
+                } else {
+                    vec![]
+                };
+
+                let dataset_vector: Vec<Dataset<'_>> = load_avg_graph_data
+                    .iter()
+                    .zip(LOAD_AVG_GRAPH_NAMES.iter())
+                    .enumerate()
+                    .map(|(itx, (load_data, name))| {
+                        Dataset::default()
+                            .name(*name)
+                            .marker(marker)
+                            .style(
+                                self.colours.cpu_colour_styles
+                                    [itx % self.colours.cpu_colour_styles.len()],
+                            )
+                            .data(get_drawn_points(&filled_points, itx, load_data))
+                            .graph_type(tui::widgets::GraphType::Line)
+                    })
+                    .collect();
+
+                let is_on_widget = widget_id == app_state.current_widget.widget_id;
+                let border_style = if is_on_widget {
+                    self.colours.highlighted_border_style
+                } else {
+                    self.colours.border_style
+                };
+
+                let load_avg = app_state.canvas_data.load_avg_data;
+                let mut title = format!(
+                    " {} Load ─ {:.2} {:.2} {:.2} ",
+                    widget_name, load_avg[0], load_avg[1], load_avg[2]
+                );
+                if let Some(scheduler_stats) = &app_state.canvas_data.scheduler_stats {
+                    title.push_str(&format!(
+                        "─ {} running, {} blocked",
+                        scheduler_stats.running_tasks, scheduler_stats.blocked_tasks
+                    ));
+                    if let (Some(context_switches), Some(interrupts)) = (
+                        scheduler_stats.context_switches_per_sec,
+                        scheduler_stats.interrupts_per_sec,
+                    ) {
+                        title.push_str(&format!(
+                            ", {:.0} ctx/s, {:.0} intr/s",
+                            context_switches, interrupts
+                        ));
+                    }
+                    title.push(' ');
+                }
+
+                let title = if app_state.is_expanded {
+                    let title_size = UnicodeSegmentation::graphemes(title.as_str(), true).count();
+                    Spans::from(vec![
+                        Span::styled(title, self.colours.widget_title_style),
+                        Span::styled(
+                            format!(
+                                "─{}─ Esc to go back ",
+                                "─".repeat(
+                                    usize::from(draw_loc.width).saturating_sub(
+                                        title_size
+                                            + UnicodeSegmentation::graphemes(
+                                                "── Esc to go back ",
+                                                true
+                                            )
+                                            .count()
+                                            + 2
+                                    )
+                                )
+                            ),
+                            border_style,
+                        ),
+                    ])
+                } else {
+                    Spans::from(vec![Span::styled(title, self.colours.widget_title_style)])
+                };
+
+                f.render_widget(
+                    Chart::new(dataset_vector)
+                        .block(
+                            Block::default()
+                                .title(title)
+                                .borders(Borders::ALL)
+                                .border_style(border_style),
+                        )
+                        .x_axis(x_axis)
+                        .y_axis(y_axis)
+                        .hidden_legend_constraints((
+                            Constraint::Ratio(1, 3),
+                            Constraint::Ratio(1, 2),
+                        )),
+                    draw_loc,
+                );
+
+                // Reset interpolated points
+                for (load_data, interpolation) in app_state
+                    .canvas_data
+                    .load_avg_graph_data
+                    .iter_mut()
+                    .zip(interpolated_points)
+                {
+                    if let Some((index, old_value)) = interpolation {
+                        if let Some(to_replace) = load_data.get_mut(index) {
+                            *to_replace = old_value;
+                        }
+                    }
+                }
+
+                return;
+            }
+
             let interpolated_cpu_points = cpu_data
                 .iter_mut()
                 .enumerate()
@@ -198,47 +537,65 @@ impl CpuGraphWidget for Painter {
                     };
 
                     if to_show {
-                        if let Some(end_pos) = cpu
-                            .cpu_data
-                            .iter()
-                            .position(|(time, _data)| *time >= time_start)
-                        {
-                            if end_pos > 1 {
-                                let start_pos = end_pos - 1;
-                                let outside_point = cpu.cpu_data.get(start_pos);
-                                let inside_point = cpu.cpu_data.get(end_pos);
-
-                                if let (Some(outside_point), Some(inside_point)) =
-                                    (outside_point, inside_point)
-                                {
-                                    let old = *outside_point;
-
-                                    let new_point = (
-                                        time_start,
-                                        interpolate_points(outside_point, inside_point, time_start),
-                                    );
-
-                                    if let Some(to_replace) = cpu.cpu_data.get_mut(start_pos) {
-                                        *to_replace = new_point;
-                                        Some((start_pos, old))
-                                    } else {
-                                        None // Failed to get mutable reference.
-                                    }
-                                } else {
-                                    None // Point somehow doesn't exist in our data
-                                }
-                            } else {
-                                None // Point is already "leftmost", no need to interpolate.
-                            }
-                        } else {
-                            None // There is no point.
-                        }
+                        interpolate_left_edge(get_graph_points_mut(cpu, graph_mode), time_start)
                     } else {
                         None
                     }
                 })
                 .collect::<Vec<_>>();
 
+            // Only the shown entries get filled points, but the rest keep their place so that the
+            // points line up with the entries.
+            let filled_points = if graph_style.is_filled() {
+                let is_shown = |itx: usize| {
+                    current_scroll_position == ALL_POSITION || itx == current_scroll_position
+                };
+
+                if graph_style == GraphStyle::Stacked
+                    && graph_mode == CpuGraphMode::Usage
+                    && current_scroll_position == ALL_POSITION
+                {
+                    // Each core is stacked as its share of the total usage, so the top of the
+                    // stack is the overall usage.  The "All" and average entries aren't cores.
+                    let is_core =
+                        |itx: usize| itx != ALL_POSITION && !(show_avg_cpu && itx == AVG_POSITION);
+                    let num_cores = (0..cpu_data.len()).filter(|itx| is_core(*itx)).count();
+                    let scaled_data = cpu_data
+                        .iter()
+                        .enumerate()
+                        .map(|(itx, cpu)| {
+                            if is_core(itx) {
+                                cpu.cpu_data
+                                    .iter()
+                                    .map(|(time, usage)| (*time, usage / num_cores as f64))
+                                    .collect::<Vec<_>>()
+                            } else {
+                                vec![]
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    let series = scaled_data.iter().map(|data| &data[..]).collect::<Vec<_>>();
+
+                    get_filled_points(&series, time_start, num_samples, true)
+                } else {
+                    let series = cpu_data
+                        .iter()
+                        .enumerate()
+                        .map(|(itx, cpu)| {
+                            if is_shown(itx) {
+                                get_graph_points(cpu, graph_mode)
+                            } else {
+                                &[]
+                            }
+                        })
+                        .collect::<Vec<_>>();
+
+                    get_filled_points(&series, time_start, num_samples, false)
+                }
+            } else {
+                vec![]
+            };
+
             let dataset_vector: Vec<Dataset<'_>> = if current_scroll_position == ALL_POSITION {
                 cpu_data
                     .iter()
@@ -246,11 +603,7 @@ impl CpuGraphWidget for Painter {
                     .rev()
                     .map(|(itx, cpu)| {
                         Dataset::default()
-                            .marker(if use_dot {
-                                Marker::Dot
-                            } else {
-                                Marker::Braille
-                            })
+                            .marker(marker)
                             .style(if show_avg_cpu && itx == AVG_POSITION {
                                 self.colours.avg_colour_style
                             } else if itx == ALL_POSITION {
@@ -264,17 +617,17 @@ impl CpuGraphWidget for Painter {
                                         }))
                                     % self.colours.cpu_colour_styles.len()]
                             })
-                            .data(&cpu.cpu_data[..])
+                            .data(get_drawn_points(
+                                &filled_points,
+                                itx,
+                                get_graph_points(cpu, graph_mode),
+                            ))
                             .graph_type(tui::widgets::GraphType::Line)
                     })
                     .collect()
             } else if let Some(cpu) = cpu_data.get(current_scroll_position) {
                 vec![Dataset::default()
-                    .marker(if use_dot {
-                        Marker::Dot
-                    } else {
-                        Marker::Braille
-                    })
+                    .marker(marker)
                     .style(if show_avg_cpu && current_scroll_position == AVG_POSITION {
                         self.colours.avg_colour_style
                     } else {
@@ -289,7 +642,11 @@ impl CpuGraphWidget for Painter {
                             }))
                             % self.colours.cpu_colour_styles.len()]
                     })
-                    .data(&cpu.cpu_data[..])
+                    .data(get_drawn_points(
+                        &filled_points,
+                        current_scroll_position,
+                        get_graph_points(cpu, graph_mode),
+                    ))
                     .graph_type(tui::widgets::GraphType::Line)]
             } else {
                 vec![]
@@ -302,6 +659,14 @@ impl CpuGraphWidget for Painter {
                 self.colours.border_style
             };
 
+            let title_name = if graph_mode == CpuGraphMode::Frequency {
+                format!(" {} Frequency ", widget_name)
+            } else {
+                format!(" {} ", widget_name)
+            };
+            let title_base_size = UnicodeSegmentation::graphemes(title_name.as_str(), true).count()
+                + UnicodeSegmentation::graphemes("── Esc to go back ", true).count();
+
             let title = if cfg!(target_family = "unix") {
                 let load_avg = app_state.canvas_data.load_avg_data;
                 let load_avg_str = format!(
@@ -312,46 +677,44 @@ impl CpuGraphWidget for Painter {
                     UnicodeSegmentation::graphemes(load_avg_str.as_str(), true).count();
 
                 if app_state.is_expanded {
-                    const TITLE_BASE: &str = " CPU ── Esc to go back ";
-
                     Spans::from(vec![
-                        Span::styled(" CPU ", self.colours.widget_title_style),
+                        Span::styled(title_name, self.colours.widget_title_style),
                         Span::styled(load_avg_str, self.colours.widget_title_style),
                         Span::styled(
                             format!(
                                 "─{}─ Esc to go back ",
-                                "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                    load_avg_str_size
-                                        + UnicodeSegmentation::graphemes(TITLE_BASE, true).count()
-                                        + 2
-                                ))
+                                "─".repeat(
+                                    usize::from(draw_loc.width)
+                                        .saturating_sub(load_avg_str_size + title_base_size + 2)
+                                )
                             ),
                             border_style,
                         ),
                     ])
                 } else {
                     Spans::from(vec![
-                        Span::styled(" CPU ", self.colours.widget_title_style),
+                        Span::styled(title_name, self.colours.widget_title_style),
                         Span::styled(load_avg_str, self.colours.widget_title_style),
                     ])
                 }
             } else if app_state.is_expanded {
-                const TITLE_BASE: &str = " CPU ── Esc to go back ";
-
                 Spans::from(vec![
-                    Span::styled(" CPU ", self.colours.widget_title_style),
+                    Span::styled(title_name, self.colours.widget_title_style),
                     Span::styled(
                         format!(
                             "─{}─ Esc to go back ",
-                            "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
-                            ))
+                            "─".repeat(
+                                usize::from(draw_loc.width).saturating_sub(title_base_size + 2)
+                            )
                         ),
                         border_style,
                     ),
                 ])
             } else {
-                Spans::from(vec![Span::styled(" CPU ", self.colours.widget_title_style)])
+                Spans::from(vec![Span::styled(
+                    title_name,
+                    self.colours.widget_title_style,
+                )])
             };
 
             f.render_widget(
@@ -373,7 +736,9 @@ impl CpuGraphWidget for Painter {
                 .zip(interpolated_cpu_points)
                 .for_each(|(cpu, interpolation)| {
                     if let Some((index, old_value)) = interpolation {
-                        if let Some(to_replace) = cpu.cpu_data.get_mut(index) {
+                        if let Some(to_replace) =
+                            get_graph_points_mut(cpu, graph_mode).get_mut(index)
+                        {
                             *to_replace = old_value;
                         }
                     }
@@ -381,6 +746,95 @@ impl CpuGraphWidget for Painter {
         }
     }
 
+    fn draw_cpu_heatmap<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let heatmap_data = &app_state.canvas_data.cpu_heatmap_data;
+
+        let is_on_widget = widget_id == app_state.current_widget.widget_id;
+        let border_style = if is_on_widget {
+            self.colours.highlighted_border_style
+        } else {
+            self.colours.border_style
+        };
+
+        let title_name = format!(
+            " {} Heatmap ",
+            get_widget_name(&app_state.widget_map, widget_id, "CPU")
+        );
+        let title = if app_state.is_expanded {
+            Spans::from(vec![
+                Span::styled(title_name.clone(), self.colours.widget_title_style),
+                Span::styled(
+                    format!(
+                        "─{}─ Esc to go back ",
+                        "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                            UnicodeSegmentation::graphemes(title_name.as_str(), true).count()
+                                + UnicodeSegmentation::graphemes("── Esc to go back ", true).count()
+                                + 2
+                        ))
+                    ),
+                    border_style,
+                ),
+            ])
+        } else {
+            Spans::from(vec![Span::styled(
+                title_name,
+                self.colours.widget_title_style,
+            )])
+        };
+
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let inner_area = block.inner(draw_loc);
+
+        // Each cell is labelled with its core number, followed by a gap.  If that doesn't fit,
+        // fall back to smaller, unlabelled cells.
+        let label_width = heatmap_data
+            .iter()
+            .map(|(cpu_count, _)| cpu_count.to_string().len())
+            .max()
+            .unwrap_or(1)
+            + 2;
+        let cells_per_row = (usize::from(inner_area.width) / (label_width + 1)).max(1);
+        let is_labelled = heatmap_data.len() <= cells_per_row * usize::from(inner_area.height);
+        let cells_per_row = if is_labelled {
+            cells_per_row
+        } else {
+            (usize::from(inner_area.width) / 2).max(1)
+        };
+
+        let lines = heatmap_data
+            .chunks(cells_per_row)
+            .map(|row| {
+                Spans::from(
+                    row.iter()
+                        .flat_map(|(cpu_count, cpu_usage)| {
+                            let style = Style::default()
+                                .fg(Color::Black)
+                                .bg(get_heatmap_colour(*cpu_usage));
+                            if is_labelled {
+                                vec![
+                                    Span::styled(
+                                        format!("{:^width$}", cpu_count, width = label_width),
+                                        style,
+                                    ),
+                                    Span::raw(" "),
+                                ]
+                            } else {
+                                vec![Span::styled("  ", style)]
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        f.render_widget(Paragraph::new(lines).block(block), draw_loc);
+    }
+
     fn draw_cpu_legend<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
@@ -420,8 +874,43 @@ impl CpuGraphWidget for Painter {
                 .saturating_sub(start_position);
             let show_avg_cpu = app_state.app_config_fields.show_average_cpu;
 
+            let is_showing_breakdown = cpu_widget_state.graph_mode == CpuGraphMode::Breakdown;
+            let legend_columns = cpu_widget_state.legend_columns.clone();
+            let extra_columns =
+                get_extra_legend_columns(cpu_widget_state.graph_mode, &legend_columns);
+            let temp_type = &app_state.app_config_fields.temperature_type;
+
             // Calculate widths
-            if recalculate_column_widths {
+            if recalculate_column_widths && !extra_columns.is_empty() {
+                // Keep the CPU name and usage, and drop extra columns from the right if there
+                // isn't enough room.
+                let num_columns = CPU_LEGEND_HEADER.len() + extra_columns.len();
+                cpu_widget_state.table_width_state.desired_column_widths = vec![6, 4]
+                    .into_iter()
+                    .chain(extra_columns.iter().map(|(_header, width)| *width))
+                    .collect();
+                cpu_widget_state.table_width_state.calculated_column_widths = get_column_widths(
+                    draw_loc.width,
+                    &[None, Some(4)]
+                        .iter()
+                        .copied()
+                        .chain(extra_columns.iter().map(|(_header, width)| Some(*width)))
+                        .collect::<Vec<_>>(),
+                    &std::iter::once(Some(CPU_LEGEND_HEADER_LENS[0]))
+                        .chain(std::iter::repeat(None))
+                        .take(num_columns)
+                        .collect::<Vec<_>>(),
+                    &std::iter::once(Some(0.5))
+                        .chain(std::iter::repeat(None))
+                        .take(num_columns)
+                        .collect::<Vec<_>>(),
+                    &std::iter::once(Some(6))
+                        .chain(std::iter::repeat(None))
+                        .take(num_columns)
+                        .collect::<Vec<_>>(),
+                    true,
+                );
+            } else if recalculate_column_widths {
                 cpu_widget_state.table_width_state.desired_column_widths = vec![6, 4];
                 cpu_widget_state.table_width_state.calculated_column_widths = get_column_widths(
                     draw_loc.width,
@@ -444,7 +933,7 @@ impl CpuGraphWidget for Painter {
             let dcw = &cpu_widget_state.table_width_state.desired_column_widths;
             let ccw = &cpu_widget_state.table_width_state.calculated_column_widths;
             let cpu_rows = sliced_cpu_data.iter().enumerate().map(|(itx, cpu)| {
-                let mut truncated_name =
+                let truncated_name =
                     if let (Some(desired_column_width), Some(calculated_column_width)) =
                         (dcw.get(0), ccw.get(0))
                     {
@@ -470,15 +959,27 @@ impl CpuGraphWidget for Painter {
                     Text::raw(&cpu.legend_value)
                 };
 
+                let mut cpu_string_row = vec![truncated_name, truncated_legend];
+                if is_showing_breakdown {
+                    cpu_string_row.extend(
+                        cpu.breakdown_legend_values
+                            .iter()
+                            .map(|value| Text::raw(value.as_str())),
+                    );
+                }
+                cpu_string_row.extend(
+                    legend_columns
+                        .iter()
+                        .map(|column| Text::raw(get_legend_column_value(cpu, column, temp_type))),
+                );
+
                 if !is_first_column_hidden
                     && itx == offset_scroll_index
                     && itx + start_position == ALL_POSITION
                 {
-                    truncated_name.patch_style(self.colours.currently_selected_text_style);
-                    Row::new(vec![truncated_name, truncated_legend])
+                    cpu_string_row[0].patch_style(self.colours.currently_selected_text_style);
+                    Row::new(cpu_string_row)
                 } else {
-                    let cpu_string_row = vec![truncated_name, truncated_legend];
-
                     Row::new(cpu_string_row).style(if itx == offset_scroll_index {
                         self.colours.currently_selected_text_style
                     } else if itx + start_position == ALL_POSITION {
@@ -515,9 +1016,15 @@ impl CpuGraphWidget for Painter {
                             .border_style(border_and_title_style),
                     )
                     .header(
-                        Row::new(CPU_LEGEND_HEADER.to_vec())
-                            .style(self.colours.table_header_style)
-                            .bottom_margin(table_gap),
+                        Row::new(
+                            CPU_LEGEND_HEADER
+                                .iter()
+                                .copied()
+                                .chain(extra_columns.iter().map(|(header, _width)| *header))
+                                .collect::<Vec<_>>(),
+                        )
+                        .style(self.colours.table_header_style)
+                        .bottom_margin(table_gap),
                     )
                     .widths(
                         &(cpu_widget_state
@@ -533,3 +1040,90 @@ impl CpuGraphWidget for Painter {
         }
     }
 }
+
+/// Returns the header and width of each legend column shown after the CPU name and usage.
+fn get_extra_legend_columns(
+    graph_mode: CpuGraphMode, legend_columns: &[CpuLegendColumn],
+) -> Vec<(&'static str, u16)> {
+    let breakdown_columns = if graph_mode == CpuGraphMode::Breakdown {
+        CPU_BREAKDOWN_HEADERS.to_vec()
+    } else {
+        vec![]
+    };
+
+    breakdown_columns
+        .into_iter()
+        .map(|header| (header, CPU_BREAKDOWN_LEGEND_WIDTH))
+        .chain(
+            legend_columns
+                .iter()
+                .map(|column| (column.get_header(), column.get_width())),
+        )
+        .collect()
+}
+
+fn get_legend_column_value(
+    cpu: &ConvertedCpuData, column: &CpuLegendColumn, temp_type: &TemperatureType,
+) -> String {
+    let frequency_string = |frequency: Option<f64>| {
+        if let Some(frequency) = frequency {
+            if frequency >= 1000.0 {
+                format!("{:.1}GHz", frequency / 1000.0)
+            } else {
+                format!("{:.0}MHz", frequency)
+            }
+        } else {
+            String::default()
+        }
+    };
+
+    match column {
+        CpuLegendColumn::Frequency => {
+            frequency_string(cpu.frequency.map(|frequency| frequency.current))
+        }
+        CpuLegendColumn::MinFrequency => {
+            frequency_string(cpu.frequency.and_then(|frequency| frequency.min))
+        }
+        CpuLegendColumn::MaxFrequency => {
+            frequency_string(cpu.frequency.and_then(|frequency| frequency.max))
+        }
+        CpuLegendColumn::Temperature => {
+            if let Some(temperature) = cpu.temperature {
+                format!("{:.0}{}", temperature, get_temperature_unit(temp_type))
+            } else {
+                String::default()
+            }
+        }
+    }
+}
+
+fn get_graph_points(cpu: &ConvertedCpuData, graph_mode: CpuGraphMode) -> &[(f64, f64)] {
+    if graph_mode == CpuGraphMode::Frequency {
+        &cpu.frequency_data
+    } else {
+        &cpu.cpu_data
+    }
+}
+
+fn get_graph_points_mut(cpu: &mut ConvertedCpuData, graph_mode: CpuGraphMode) -> &mut [(f64, f64)] {
+    if graph_mode == CpuGraphMode::Frequency {
+        &mut cpu.frequency_data
+    } else {
+        &mut cpu.cpu_data
+    }
+}
+
+/// Returns the colour of a heatmap cell, going from green for an idle core to red for a busy one.
+fn get_heatmap_colour(cpu_usage: f64) -> Color {
+    if cpu_usage < 20.0 {
+        Color::Green
+    } else if cpu_usage < 40.0 {
+        Color::LightGreen
+    } else if cpu_usage < 60.0 {
+        Color::Yellow
+    } else if cpu_usage < 80.0 {
+        Color::LightRed
+    } else {
+        Color::Red
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{app::App, canvas::Painter, constants::*, utils::gen_util::get_decimal_bytes};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Paragraph},
+};
+
+pub trait DiskBasicWidget {
+    fn draw_basic_disk<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl DiskBasicWidget for Painter {
+    /// Draws the read and write rates of all disks together, and how much they have read and
+    /// written in total.
+    fn draw_basic_disk<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let divided_loc = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(draw_loc);
+
+        let io_loc = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(1)
+            .split(divided_loc[0]);
+
+        let total_loc = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(1)
+            .split(divided_loc[1]);
+
+        if app_state.current_widget.widget_id == widget_id {
+            f.render_widget(
+                Block::default()
+                    .borders(*SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style),
+                draw_loc,
+            );
+        }
+
+        // Each disk has its current rates, and the total it had read and written when they were
+        // last measured.
+        let ((read_rate, write_rate), (total_read, total_write)) =
+            app_state.data_collection.io_labels_and_prev.iter().fold(
+                ((0, 0), (0, 0)),
+                |((read_rate, write_rate), (total_read, total_write)), (io_curr, io_prev)| {
+                    (
+                        (read_rate + io_curr.0, write_rate + io_curr.1),
+                        (total_read + io_prev.0, total_write + io_prev.1),
+                    )
+                },
+            );
+
+        let format_bytes = |bytes: u64| {
+            let (value, unit) = get_decimal_bytes(bytes);
+            format!("{:.1}{}", value, unit)
+        };
+
+        let io_text = vec![
+            Spans::from(Span::styled(
+                format!("R: {}/s", format_bytes(read_rate)),
+                self.colours.rx_style,
+            )),
+            Spans::from(Span::styled(
+                format!("W: {}/s", format_bytes(write_rate)),
+                self.colours.tx_style,
+            )),
+        ];
+
+        let total_io_text = vec![
+            Spans::from(Span::styled(
+                format!("Total R: {}", format_bytes(total_read)),
+                self.colours.total_rx_style,
+            )),
+            Spans::from(Span::styled(
+                format!("Total W: {}", format_bytes(total_write)),
+                self.colours.total_tx_style,
+            )),
+        ];
+
+        f.render_widget(Paragraph::new(io_text).block(Block::default()), io_loc[0]);
+
+        f.render_widget(
+            Paragraph::new(total_io_text).block(Block::default()),
+            total_loc[0],
+        );
+
+        // Update draw loc in widget map
+        if app_state.should_get_widget_bounds() {
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -1,32 +1,24 @@
-use once_cell::sync::Lazy;
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
     terminal::Frame,
     text::Span,
     text::{Spans, Text},
-    widgets::{Block, Borders, Row, Table},
+    widgets::{Block, Borders, Cell, Row, Table},
 };
 
 use crate::{
     app,
     canvas::{
-        drawing_utils::{get_column_widths, get_start_position},
+        drawing_utils::{get_column_widths, get_start_position, get_widget_name},
+        widgets::TableSearchWidget,
         Painter,
     },
     constants::*,
+    data_conversion::ConvertedDiskData,
 };
 use unicode_segmentation::UnicodeSegmentation;
 
-const DISK_HEADERS: [&str; 7] = ["Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s"];
-
-static DISK_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
-    DISK_HEADERS
-        .iter()
-        .map(|entry| entry.len() as u16)
-        .collect::<Vec<_>>()
-});
-
 pub trait DiskTableWidget {
     fn draw_disk_table<B: Backend>(
         &self, f: &mut Frame<'_, B>, app_state: &mut app::App, draw_loc: Rect, draw_border: bool,
@@ -41,6 +33,29 @@ impl DiskTableWidget for Painter {
     ) {
         let recalculate_column_widths = app_state.should_get_widget_bounds();
         if let Some(disk_widget_state) = app_state.disk_state.widget_states.get_mut(&widget_id) {
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let is_search_enabled = disk_widget_state.search_state.is_search_enabled();
+            let draw_loc = if is_search_enabled {
+                let search_height = if draw_border { 5 } else { 3 };
+                let disk_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(search_height)])
+                    .split(draw_loc);
+
+                self.draw_table_search_field(
+                    f,
+                    &mut disk_widget_state.search_state,
+                    disk_chunk[1],
+                    draw_border,
+                    is_on_widget,
+                    app_state.is_force_redraw,
+                );
+
+                disk_chunk[0]
+            } else {
+                draw_loc
+            };
+
             let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
                 0
             } else {
@@ -55,7 +70,6 @@ impl DiskTableWidget for Painter {
                 disk_widget_state.scroll_state.current_scroll_position,
                 app_state.is_force_redraw,
             );
-            let is_on_widget = app_state.current_widget.widget_id == widget_id;
             let disk_table_state = &mut disk_widget_state.scroll_state.table_state;
             disk_table_state.select(Some(
                 disk_widget_state
@@ -63,15 +77,43 @@ impl DiskTableWidget for Painter {
                     .current_scroll_position
                     .saturating_sub(start_position),
             ));
-            let sliced_vec = &app_state.canvas_data.disk_data[start_position..];
+            let disk_data: &[ConvertedDiskData] = app_state
+                .canvas_data
+                .disk_data
+                .get(&widget_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            // Sanity check
+            let start_position = if start_position >= disk_data.len() {
+                disk_data.len().saturating_sub(1)
+            } else {
+                start_position
+            };
+            let sliced_vec = &disk_data[start_position..];
+            let columns = &disk_widget_state.columns;
+            let disk_headers = disk_widget_state.sort_state.get_column_headers(
+                &columns
+                    .iter()
+                    .map(|column| column.get_header())
+                    .collect::<Vec<_>>(),
+            );
+            let header_lens = columns
+                .iter()
+                // Leave room for the sort arrow.
+                .map(|column| column.get_header().len() as u16 + 1)
+                .collect::<Vec<_>>();
 
             // Calculate widths
-            let hard_widths = [None, None, Some(4), Some(6), Some(6), Some(7), Some(7)];
+            let hard_widths = columns
+                .iter()
+                .map(|column| column.get_hard_width())
+                .collect::<Vec<_>>();
             if recalculate_column_widths {
                 disk_widget_state.table_width_state.desired_column_widths = {
-                    let mut column_widths = DISK_HEADERS_LENS.clone();
-                    for row in sliced_vec {
-                        for (col, entry) in row.iter().enumerate() {
+                    let mut column_widths = header_lens.clone();
+                    for disk in sliced_vec {
+                        for (col, entry) in disk.row.iter().enumerate() {
                             if entry.len() as u16 > column_widths[col] {
                                 column_widths[col] = entry.len() as u16;
                             }
@@ -100,11 +142,11 @@ impl DiskTableWidget for Painter {
                 disk_widget_state.table_width_state.calculated_column_widths = get_column_widths(
                     draw_loc.width,
                     &hard_widths,
-                    &(DISK_HEADERS_LENS
+                    &(header_lens.iter().map(|w| Some(*w)).collect::<Vec<_>>()),
+                    &(columns
                         .iter()
-                        .map(|w| Some(*w))
+                        .map(|column| column.get_max_width_ratio())
                         .collect::<Vec<_>>()),
-                    &[Some(0.2), Some(0.2), None, None, None, None, None],
                     &(disk_widget_state
                         .table_width_state
                         .desired_column_widths
@@ -117,40 +159,72 @@ impl DiskTableWidget for Painter {
 
             let dcw = &disk_widget_state.table_width_state.desired_column_widths;
             let ccw = &disk_widget_state.table_width_state.calculated_column_widths;
-            let disk_rows =
-                sliced_vec.iter().map(|disk_row| {
-                    let truncated_data = disk_row.iter().zip(&hard_widths).enumerate().map(
-                        |(itx, (entry, width))| {
-                            if width.is_none() {
-                                if let (Some(desired_col_width), Some(calculated_col_width)) =
-                                    (dcw.get(itx), ccw.get(itx))
+            let selected_position = disk_widget_state.scroll_state.current_scroll_position;
+            let disk_rows = sliced_vec.iter().enumerate().map(|(row_itx, disk)| {
+                // Leave the selected row alone so that it stays readable.
+                let is_shaded = !(is_on_widget && start_position + row_itx == selected_position);
+
+                let truncated_data = disk
+                    .row
+                    .iter()
+                    .zip(&hard_widths)
+                    .enumerate()
+                    .map(|(itx, (entry, width))| {
+                        if width.is_none() {
+                            if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                (dcw.get(itx), ccw.get(itx))
+                            {
+                                if *desired_col_width > *calculated_col_width
+                                    && *calculated_col_width > 0
                                 {
-                                    if *desired_col_width > *calculated_col_width
-                                        && *calculated_col_width > 0
+                                    let graphemes =
+                                        UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                            .collect::<Vec<&str>>();
+
+                                    if graphemes.len() > *calculated_col_width as usize
+                                        && *calculated_col_width > 1
                                     {
-                                        let graphemes =
-                                            UnicodeSegmentation::graphemes(entry.as_str(), true)
-                                                .collect::<Vec<&str>>();
-
-                                        if graphemes.len() > *calculated_col_width as usize
-                                            && *calculated_col_width > 1
-                                        {
-                                            // Truncate with ellipsis
-                                            let first_n = graphemes
-                                                [..(*calculated_col_width as usize - 1)]
-                                                .concat();
-                                            return Text::raw(format!("{}…", first_n));
-                                        }
+                                        // Truncate with ellipsis
+                                        let first_n = graphemes
+                                            [..(*calculated_col_width as usize - 1)]
+                                            .concat();
+                                        return Text::raw(format!("{}…", first_n));
                                     }
                                 }
                             }
+                        }
 
-                            Text::raw(entry)
-                        },
-                    );
+                        Text::raw(entry)
+                    })
+                    .enumerate()
+                    .map(|(itx, text)| {
+                        let usage_style = disk
+                            .cell_usage
+                            .get(itx)
+                            .copied()
+                            .flatten()
+                            .filter(|_| is_shaded)
+                            .and_then(|usage| self.colours.get_usage_style(usage));
+
+                        if let Some(usage_style) = usage_style {
+                            Cell::from(text).style(usage_style)
+                        } else {
+                            Cell::from(text)
+                        }
+                    });
+
+                // Highlight disks that are running out of either space or inodes.
+                let row_style = match disk.highest_usage {
+                    Some(usage) if usage >= DISK_CRITICAL_USAGE => self.colours.disk_critical_style,
+                    Some(usage) if usage >= DISK_WARNING_USAGE => self.colours.disk_warning_style,
+                    _ => self.colours.text_style,
+                };
 
-                    Row::new(truncated_data)
-                });
+                Row::new(truncated_data).style(
+                    self.colours
+                        .get_row_style(row_style, start_position + row_itx),
+                )
+            });
 
             let (border_style, highlight_style) = if is_on_widget {
                 (
@@ -161,26 +235,28 @@ impl DiskTableWidget for Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let widget_name = get_widget_name(&app_state.widget_map, widget_id, "Disk");
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 let title_string = format!(
-                    " Disk ({} of {}) ",
+                    " {} ({} of {}) ",
+                    widget_name,
                     disk_widget_state
                         .scroll_state
                         .current_scroll_position
                         .saturating_add(1),
-                    app_state.canvas_data.disk_data.len()
+                    disk_data.len()
                 );
 
                 if title_string.len() <= draw_loc.width as usize {
                     title_string
                 } else {
-                    " Disk ".to_string()
+                    format!(" {} ", widget_name)
                 }
             } else {
-                " Disk ".to_string()
+                format!(" {} ", widget_name)
             };
 
-            let title = if app_state.is_expanded {
+            let title = if app_state.is_expanded && !is_search_enabled {
                 const ESCAPE_ENDING: &str = "── Esc to go back ";
 
                 let (chosen_title_base, expanded_title_base) = {
@@ -188,8 +264,8 @@ impl DiskTableWidget for Painter {
 
                     if temp_title_base.len() > draw_loc.width as usize {
                         (
-                            " Disk ".to_string(),
-                            format!("{}{}", " Disk ".to_string(), ESCAPE_ENDING),
+                            format!(" {} ", widget_name),
+                            format!(" {} {}", widget_name, ESCAPE_ENDING),
                         )
                     } else {
                         (title_base, temp_title_base)
@@ -243,7 +319,7 @@ impl DiskTableWidget for Painter {
                 Table::new(disk_rows)
                     .block(disk_block)
                     .header(
-                        Row::new(DISK_HEADERS.to_vec())
+                        Row::new(disk_headers)
                             .style(self.colours.table_header_style)
                             .bottom_margin(table_gap),
                     )
@@ -261,6 +337,38 @@ impl DiskTableWidget for Painter {
                 disk_table_state,
             );
 
+            // Check if we need to update columnar bounds...
+            if recalculate_column_widths
+                || disk_widget_state.sort_state.column_header_x_locs.is_none()
+                || disk_widget_state.sort_state.column_header_y_loc.is_none()
+            {
+                disk_widget_state.sort_state.column_header_y_loc =
+                    Some(draw_loc.y + if draw_border { 1 } else { 0 });
+
+                let mut current_x_left = draw_loc.x + 1;
+                let max_x_right = draw_loc.x + draw_loc.width - 1;
+
+                let mut x_locs = vec![];
+
+                for width in disk_widget_state
+                    .table_width_state
+                    .calculated_column_widths
+                    .iter()
+                {
+                    let right_bound = current_x_left + width;
+
+                    if right_bound < max_x_right {
+                        x_locs.push((current_x_left, right_bound));
+                        current_x_left = right_bound + 1;
+                    } else {
+                        x_locs.push((current_x_left, max_x_right));
+                        break;
+                    }
+                }
+
+                disk_widget_state.sort_state.column_header_x_locs = Some(x_locs);
+            }
+
             if app_state.should_get_widget_bounds() {
                 // Update draw loc in widget map
                 if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
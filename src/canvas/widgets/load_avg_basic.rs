@@ -0,0 +1,88 @@
+use crate::{
+    app::App,
+    canvas::{drawing_utils::*, Painter},
+    constants::*,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Paragraph},
+};
+
+pub trait LoadAvgBasicWidget {
+    fn draw_basic_load_avg<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl LoadAvgBasicWidget for Painter {
+    /// Draws the one minute load average as a bar, filled up when every core is busy, and the five
+    /// and fifteen minute load averages below it.
+    fn draw_basic_load_avg<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let margined_loc = Layout::default()
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(1)
+            .split(draw_loc);
+
+        if app_state.current_widget.widget_id == widget_id {
+            f.render_widget(
+                Block::default()
+                    .borders(*SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style),
+                draw_loc,
+            );
+        }
+
+        let load_avg = app_state.canvas_data.load_avg_data;
+        let num_cores = app_state
+            .data_collection
+            .cpu_harvest
+            .iter()
+            .filter(|cpu| cpu.cpu_count.is_some())
+            .count()
+            .max(1);
+        let load_percentage = f64::from(load_avg[0]) / num_cores as f64 * 100.0;
+
+        // +8 due to 4 + 2 + 2 columns for the name & space + bar bounds + margin spacing
+        // Then + length of the load average
+        let load_label = format!("{:.2}", load_avg[0]);
+        let bar_length =
+            usize::from(draw_loc.width.saturating_sub(8)).saturating_sub(load_label.len());
+        let num_bars = calculate_basic_use_bars(load_percentage, bar_length);
+
+        let load_text = vec![
+            Spans::from(Span::styled(
+                format!(
+                    "LOAD[{}{}{}]",
+                    "|".repeat(num_bars),
+                    " ".repeat(bar_length - num_bars),
+                    load_label
+                ),
+                self.colours.avg_colour_style,
+            )),
+            Spans::from(Span::styled(
+                format!("5m: {:.2}  15m: {:.2}", load_avg[1], load_avg[2]),
+                self.colours.text_style,
+            )),
+        ];
+
+        f.render_widget(
+            Paragraph::new(load_text).block(Block::default()),
+            margined_loc[0],
+        );
+
+        // Update draw loc in widget map
+        if app_state.should_get_widget_bounds() {
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
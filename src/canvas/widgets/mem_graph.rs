@@ -1,13 +1,18 @@
 use crate::{
-    app::App,
-    canvas::{drawing_utils::interpolate_points, Painter},
+    app::{App, GraphStyle},
+    canvas::{
+        drawing_utils::{
+            get_drawn_points, get_filled_points, get_filled_sample_count, get_graph_marker,
+            get_widget_name, interpolate_left_edge, interpolate_points,
+        },
+        Painter,
+    },
     constants::*,
 };
 
 use tui::{
     backend::Backend,
     layout::{Constraint, Rect},
-    symbols::Marker,
     terminal::Frame,
     text::Span,
     text::Spans,
@@ -34,13 +39,13 @@ impl MemGraphWidget for Painter {
             let display_time_labels = vec![
                 Span::styled(
                     format!("{}s", mem_widget_state.current_display_time / 1000),
-                    self.colours.graph_style,
+                    self.colours.graph_label_style,
                 ),
-                Span::styled("0s".to_string(), self.colours.graph_style),
+                Span::styled("0s".to_string(), self.colours.graph_label_style),
             ];
             let y_axis_label = vec![
-                Span::styled("  0%", self.colours.graph_style),
-                Span::styled("100%", self.colours.graph_style),
+                Span::styled("  0%", self.colours.graph_label_style),
+                Span::styled("100%", self.colours.graph_label_style),
             ];
 
             let x_axis = if app_state.app_config_fields.hide_time
@@ -143,6 +148,62 @@ impl MemGraphWidget for Painter {
                 None // There is no point.
             };
 
+            // Per-node usage is only shown if there's more than one NUMA node.
+            let numa_data = &mut app_state.canvas_data.numa_data;
+            let interpolated_numa_points = numa_data
+                .iter_mut()
+                .map(|node_data| interpolate_left_edge(node_data, time_start))
+                .collect::<Vec<_>>();
+
+            let graph_style = mem_widget_state.graph_style;
+            let marker = get_graph_marker(graph_style);
+
+            // Filled graphs are drawn from points covering the area under each line, in the same
+            // order as the datasets below.
+            let filled_points = if graph_style.is_filled() {
+                let num_samples = get_filled_sample_count(graph_style, draw_loc.width);
+                let numa_series = numa_data.iter().map(|node_data| &node_data[..]);
+                let mem_total = app_state.data_collection.memory_harvest.mem_total_in_kib as f64;
+                let swap_total = app_state.data_collection.swap_harvest.mem_total_in_kib as f64;
+
+                if graph_style == GraphStyle::Stacked && mem_total + swap_total > 0.0 {
+                    // Scale RAM and swap to the combined total so that their stack is the usage of
+                    // both together.
+                    let scale = |data: &[(f64, f64)], total: f64| {
+                        data.iter()
+                            .map(|(time, percent)| {
+                                (*time, percent * total / (mem_total + swap_total))
+                            })
+                            .collect::<Vec<_>>()
+                    };
+                    let scaled_mem = scale(mem_data, mem_total);
+                    let scaled_swap = scale(swap_data, swap_total);
+
+                    let mut filled_points = get_filled_points(
+                        &[&scaled_mem, &scaled_swap],
+                        time_start,
+                        num_samples,
+                        true,
+                    );
+                    filled_points.extend(get_filled_points(
+                        &numa_series.collect::<Vec<_>>(),
+                        time_start,
+                        num_samples,
+                        false,
+                    ));
+                    filled_points
+                } else {
+                    let series = [&mem_data[..], &swap_data[..]]
+                        .iter()
+                        .copied()
+                        .chain(numa_series)
+                        .collect::<Vec<_>>();
+                    get_filled_points(&series, time_start, num_samples, false)
+                }
+            } else {
+                vec![]
+            };
+
             let mut mem_canvas_vec: Vec<Dataset<'_>> = vec![];
 
             if let Some((label_percent, label_frac)) = &app_state.canvas_data.mem_labels {
@@ -150,13 +211,9 @@ impl MemGraphWidget for Painter {
                 mem_canvas_vec.push(
                     Dataset::default()
                         .name(mem_label)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .marker(marker)
                         .style(self.colours.ram_style)
-                        .data(mem_data)
+                        .data(get_drawn_points(&filled_points, 0, mem_data))
                         .graph_type(tui::widgets::GraphType::Line),
                 );
             }
@@ -166,13 +223,27 @@ impl MemGraphWidget for Painter {
                 mem_canvas_vec.push(
                     Dataset::default()
                         .name(swap_label)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .marker(marker)
                         .style(self.colours.swap_style)
-                        .data(swap_data)
+                        .data(get_drawn_points(&filled_points, 1, swap_data))
+                        .graph_type(tui::widgets::GraphType::Line),
+                );
+            }
+
+            for (itx, (node_data, node_label)) in numa_data
+                .iter()
+                .zip(&app_state.canvas_data.numa_labels)
+                .enumerate()
+            {
+                mem_canvas_vec.push(
+                    Dataset::default()
+                        .name(node_label.as_str())
+                        .marker(marker)
+                        .style(
+                            self.colours.cpu_colour_styles
+                                [itx % self.colours.cpu_colour_styles.len()],
+                        )
+                        .data(get_drawn_points(&filled_points, itx + 2, node_data))
                         .graph_type(tui::widgets::GraphType::Line),
                 );
             }
@@ -184,25 +255,27 @@ impl MemGraphWidget for Painter {
                 self.colours.border_style
             };
 
+            let title_name = format!(
+                " {} ",
+                get_widget_name(&app_state.widget_map, widget_id, "Memory")
+            );
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Memory ── Esc to go back ";
+                let title_base = format!("{}── Esc to go back ", title_name);
                 Spans::from(vec![
-                    Span::styled(" Memory ", self.colours.widget_title_style),
+                    Span::styled(title_name.clone(), self.colours.widget_title_style),
                     Span::styled(
                         format!(
                             "─{}─ Esc to go back ",
                             "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
+                                UnicodeSegmentation::graphemes(title_base.as_str(), true).count()
+                                    + 2
                             ))
                         ),
                         border_style,
                     ),
                 ])
             } else {
-                Spans::from(Span::styled(
-                    " Memory ".to_string(),
-                    self.colours.widget_title_style,
-                ))
+                Spans::from(Span::styled(title_name, self.colours.widget_title_style))
             };
 
             f.render_widget(
@@ -235,6 +308,19 @@ impl MemGraphWidget for Painter {
                     *to_replace = old_value;
                 }
             }
+
+            for (node_data, interpolation) in app_state
+                .canvas_data
+                .numa_data
+                .iter_mut()
+                .zip(interpolated_numa_points)
+            {
+                if let Some((index, old_value)) = interpolation {
+                    if let Some(to_replace) = node_data.get_mut(index) {
+                        *to_replace = old_value;
+                    }
+                }
+            }
         }
 
         if app_state.should_get_widget_bounds() {
@@ -3,9 +3,12 @@ use std::cmp::max;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    app::{App, AxisScaling},
+    app::{App, AxisScaling, GraphStyle},
     canvas::{
-        drawing_utils::{get_column_widths, interpolate_points},
+        drawing_utils::{
+            get_column_widths, get_drawn_points, get_filled_points, get_filled_sample_count,
+            get_graph_marker, get_widget_name, interpolate_points,
+        },
         Painter,
     },
     constants::*,
@@ -16,7 +19,6 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    symbols::Marker,
     terminal::Frame,
     text::Span,
     text::{Spans, Text},
@@ -419,17 +421,42 @@ impl NetworkGraphWidget for Painter {
         }
 
         if let Some(network_widget_state) = app_state.net_state.widget_states.get_mut(&widget_id) {
-            let network_data_rx: &mut [(f64, f64)] = &mut app_state.canvas_data.network_data_rx;
-            let network_data_tx: &mut [(f64, f64)] = &mut app_state.canvas_data.network_data_tx;
+            let canvas_data = &mut app_state.canvas_data;
+            let (
+                network_data_rx,
+                network_data_tx,
+                rx_display,
+                tx_display,
+                total_rx_display,
+                total_tx_display,
+            ): (&mut [Point], &mut [Point], &str, &str, &str, &str) =
+                match canvas_data.network_widget_data.get_mut(&widget_id) {
+                    Some(network_data) => (
+                        &mut network_data.rx,
+                        &mut network_data.tx,
+                        &network_data.rx_display,
+                        &network_data.tx_display,
+                        network_data.total_rx_display.as_deref().unwrap_or_default(),
+                        network_data.total_tx_display.as_deref().unwrap_or_default(),
+                    ),
+                    None => (
+                        &mut canvas_data.network_data_rx,
+                        &mut canvas_data.network_data_tx,
+                        &canvas_data.rx_display,
+                        &canvas_data.tx_display,
+                        &canvas_data.total_rx_display,
+                        &canvas_data.total_tx_display,
+                    ),
+                };
 
             let time_start = -(network_widget_state.current_display_time as f64);
 
             let display_time_labels = vec![
                 Span::styled(
                     format!("{}s", network_widget_state.current_display_time / 1000),
-                    self.colours.graph_style,
+                    self.colours.graph_label_style,
                 ),
-                Span::styled("0s".to_string(), self.colours.graph_style),
+                Span::styled("0s".to_string(), self.colours.graph_label_style),
             ];
             let x_axis = if app_state.app_config_fields.hide_time
                 || (app_state.app_config_fields.autohide_time
@@ -547,18 +574,53 @@ impl NetworkGraphWidget for Painter {
 
             // Find the maximal rx/tx so we know how to scale, and return it.
 
-            let (_best_time, max_entry) = get_max_entry(
-                network_data_rx,
-                network_data_tx,
-                time_start,
-                &app_state.app_config_fields.network_scale_type,
-                app_state.app_config_fields.network_use_binary_prefix,
-            );
+            let graph_style = network_widget_state.graph_style;
+            let marker = get_graph_marker(graph_style);
+
+            // Stacking TX on top of RX only adds up on a linear scale, so log scales are just
+            // filled instead.
+            let is_stacked = graph_style == GraphStyle::Stacked
+                && matches!(network_widget_state.scale_type, AxisScaling::Linear);
+
+            let (_best_time, max_entry) = if is_stacked {
+                let total_data = network_data_rx
+                    .iter()
+                    .zip(network_data_tx.iter())
+                    .map(|((time, rx), (_time, tx))| (*time, rx + tx))
+                    .collect::<Vec<_>>();
+
+                get_max_entry(
+                    &total_data,
+                    &total_data,
+                    time_start,
+                    &network_widget_state.scale_type,
+                    app_state.app_config_fields.network_use_binary_prefix,
+                )
+            } else {
+                get_max_entry(
+                    network_data_rx,
+                    network_data_tx,
+                    time_start,
+                    &network_widget_state.scale_type,
+                    app_state.app_config_fields.network_use_binary_prefix,
+                )
+            };
+
+            let filled_points = if graph_style.is_filled() {
+                get_filled_points(
+                    &[network_data_rx, network_data_tx],
+                    time_start,
+                    get_filled_sample_count(graph_style, draw_loc.width),
+                    is_stacked,
+                )
+            } else {
+                vec![]
+            };
 
             let (max_range, labels) = adjust_network_data_point(
                 max_entry,
-                &app_state.app_config_fields.network_scale_type,
-                &app_state.app_config_fields.network_unit_type,
+                &network_widget_state.scale_type,
+                &network_widget_state.unit_type,
                 app_state.app_config_fields.network_use_binary_prefix,
             );
 
@@ -569,7 +631,7 @@ impl NetworkGraphWidget for Painter {
 
             let y_axis_labels = labels
                 .iter()
-                .map(|label| Span::styled(label, self.colours.graph_style))
+                .map(|label| Span::styled(label, self.colours.graph_label_style))
                 .collect::<Vec<_>>();
             let y_axis = Axis::default()
                 .style(self.colours.graph_style)
@@ -583,22 +645,27 @@ impl NetworkGraphWidget for Painter {
                 self.colours.border_style
             };
 
+            let title_name = format!(
+                " {} ",
+                get_widget_name(&app_state.widget_map, widget_id, "Network")
+            );
             let title = if app_state.is_expanded {
-                const TITLE_BASE: &str = " Network ── Esc to go back ";
+                let title_base = format!("{}── Esc to go back ", title_name);
                 Spans::from(vec![
-                    Span::styled(" Network ", self.colours.widget_title_style),
+                    Span::styled(title_name.clone(), self.colours.widget_title_style),
                     Span::styled(
                         format!(
                             "─{}─ Esc to go back ",
                             "─".repeat(usize::from(draw_loc.width).saturating_sub(
-                                UnicodeSegmentation::graphemes(TITLE_BASE, true).count() + 2
+                                UnicodeSegmentation::graphemes(title_base.as_str(), true).count()
+                                    + 2
                             ))
                         ),
                         border_style,
                     ),
                 ])
             } else {
-                Spans::from(Span::styled(" Network ", self.colours.widget_title_style))
+                Spans::from(Span::styled(title_name, self.colours.widget_title_style))
             };
 
             let legend_constraints = if hide_legend {
@@ -611,59 +678,37 @@ impl NetworkGraphWidget for Painter {
             let dataset = if app_state.app_config_fields.use_old_network_legend && !hide_legend {
                 vec![
                     Dataset::default()
-                        .name(format!("RX: {:7}", app_state.canvas_data.rx_display))
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .name(format!("RX: {:7}", rx_display))
+                        .marker(marker)
                         .style(self.colours.rx_style)
-                        .data(network_data_rx)
+                        .data(get_drawn_points(&filled_points, 0, network_data_rx))
                         .graph_type(tui::widgets::GraphType::Line),
                     Dataset::default()
-                        .name(format!("TX: {:7}", app_state.canvas_data.tx_display))
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .name(format!("TX: {:7}", tx_display))
+                        .marker(marker)
                         .style(self.colours.tx_style)
-                        .data(network_data_tx)
+                        .data(get_drawn_points(&filled_points, 1, network_data_tx))
                         .graph_type(tui::widgets::GraphType::Line),
                     Dataset::default()
-                        .name(format!(
-                            "Total RX: {:7}",
-                            app_state.canvas_data.total_rx_display
-                        ))
+                        .name(format!("Total RX: {:7}", total_rx_display))
                         .style(self.colours.total_rx_style),
                     Dataset::default()
-                        .name(format!(
-                            "Total TX: {:7}",
-                            app_state.canvas_data.total_tx_display
-                        ))
+                        .name(format!("Total TX: {:7}", total_tx_display))
                         .style(self.colours.total_tx_style),
                 ]
             } else {
                 vec![
                     Dataset::default()
-                        .name(&app_state.canvas_data.rx_display)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .name(rx_display)
+                        .marker(marker)
                         .style(self.colours.rx_style)
-                        .data(network_data_rx)
+                        .data(get_drawn_points(&filled_points, 0, network_data_rx))
                         .graph_type(tui::widgets::GraphType::Line),
                     Dataset::default()
-                        .name(&app_state.canvas_data.tx_display)
-                        .marker(if app_state.app_config_fields.use_dot {
-                            Marker::Dot
-                        } else {
-                            Marker::Braille
-                        })
+                        .name(tx_display)
+                        .marker(marker)
                         .style(self.colours.tx_style)
-                        .data(network_data_tx)
+                        .data(get_drawn_points(&filled_points, 1, network_data_tx))
                         .graph_type(tui::widgets::GraphType::Line),
                 ]
             };
@@ -710,10 +755,22 @@ impl NetworkGraphWidget for Painter {
             app_state.app_config_fields.table_gap
         };
 
-        let rx_display = &app_state.canvas_data.rx_display;
-        let tx_display = &app_state.canvas_data.tx_display;
-        let total_rx_display = &app_state.canvas_data.total_rx_display;
-        let total_tx_display = &app_state.canvas_data.total_tx_display;
+        let canvas_data = &app_state.canvas_data;
+        let (rx_display, tx_display, total_rx_display, total_tx_display) =
+            match canvas_data.network_widget_data.get(&widget_id) {
+                Some(network_data) => (
+                    network_data.rx_display.as_str(),
+                    network_data.tx_display.as_str(),
+                    network_data.total_rx_display.as_deref().unwrap_or_default(),
+                    network_data.total_tx_display.as_deref().unwrap_or_default(),
+                ),
+                None => (
+                    canvas_data.rx_display.as_str(),
+                    canvas_data.tx_display.as_str(),
+                    canvas_data.total_rx_display.as_str(),
+                    canvas_data.total_tx_display.as_str(),
+                ),
+            };
 
         // Gross but I need it to work...
         let total_network = vec![vec![
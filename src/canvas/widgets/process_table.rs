@@ -1,7 +1,9 @@
 use crate::{
-    app::App,
+    app::{data_harvester::processes::ProcessSorting, App},
     canvas::{
-        drawing_utils::{get_column_widths, get_search_start_position, get_start_position},
+        drawing_utils::{
+            get_column_widths, get_search_start_position, get_start_position, get_widget_name,
+        },
         Painter,
     },
     constants::*,
@@ -10,9 +12,10 @@ use crate::{
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
     terminal::Frame,
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
 };
 
 use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
@@ -20,6 +23,16 @@ use unicode_width::UnicodeWidthStr;
 
 use once_cell::sync::Lazy;
 
+// Where the CPU and memory usage cells are in each row, which never move.
+const PROCESS_CPU_COLUMN_INDEX: usize = 2;
+const PROCESS_MEM_COLUMN_INDEX: usize = 3;
+
+const PROCESS_HISTORY_COLUMNS: [ProcessSorting; 3] = [
+    ProcessSorting::CpuHistory,
+    ProcessSorting::MemHistory,
+    ProcessSorting::MemGrowth,
+];
+
 static PROCESS_HEADERS_HARD_WIDTH_NO_GROUP: Lazy<Vec<Option<u16>>> = Lazy::new(|| {
     vec![
         Some(7),
@@ -212,6 +225,7 @@ impl ProcessTableWidget for Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let widget_name = get_widget_name(&app_state.widget_map, widget_id, "Processes");
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 if let Some(finalized_process_data) = app_state
                     .canvas_data
@@ -219,7 +233,8 @@ impl ProcessTableWidget for Painter {
                     .get(&widget_id)
                 {
                     let title = format!(
-                        " Processes ({} of {}) ",
+                        " {} ({} of {}) ",
+                        widget_name,
                         proc_widget_state
                             .scroll_state
                             .current_scroll_position
@@ -230,13 +245,13 @@ impl ProcessTableWidget for Painter {
                     if title.len() <= draw_loc.width as usize {
                         title
                     } else {
-                        " Processes ".to_string()
+                        format!(" {} ", widget_name)
                     }
                 } else {
-                    " Processes ".to_string()
+                    format!(" {} ", widget_name)
                 }
             } else {
-                " Processes ".to_string()
+                format!(" {} ", widget_name)
             };
 
             let title = if app_state.is_expanded
@@ -253,8 +268,8 @@ impl ProcessTableWidget for Painter {
 
                     if temp_title_base.len() > draw_loc.width as usize {
                         (
-                            " Processes ".to_string(),
-                            format!("{}{}", " Processes ".to_string(), ESCAPE_ENDING),
+                            format!(" {} ", widget_name),
+                            format!(" {} {}", widget_name, ESCAPE_ENDING),
                         )
                     } else {
                         (title_base, temp_title_base)
@@ -357,6 +372,21 @@ impl ProcessTableWidget for Painter {
                     &*PROCESS_HEADERS_HARD_WIDTH_NO_GROUP
                 };
 
+                // The history columns are optional, and always come after the other columns.
+                let columns = &proc_widget_state.columns;
+                let history_columns = PROCESS_HISTORY_COLUMNS
+                    .iter()
+                    .filter(|column| columns.is_enabled(column))
+                    .collect::<Vec<_>>();
+                let hard_widths = hard_widths
+                    .iter()
+                    .copied()
+                    .chain(history_columns.iter().map(|column| match column {
+                        ProcessSorting::MemGrowth => Some(8),
+                        _ => Some(PROCESS_HISTORY_SPARKLINE_WIDTH as u16),
+                    }))
+                    .collect::<Vec<_>>();
+
                 if recalculate_column_widths {
                     let mut column_widths = process_headers
                         .iter()
@@ -386,7 +416,7 @@ impl ProcessTableWidget for Painter {
                         .table_width_state
                         .desired_column_widths
                         .iter()
-                        .zip(hard_widths)
+                        .zip(&hard_widths)
                         .map(|(current, hard)| {
                             if let Some(hard) = hard {
                                 if *hard > *current {
@@ -415,13 +445,18 @@ impl ProcessTableWidget for Painter {
                     } else {
                         &*PROCESS_HEADERS_SOFT_WIDTH_MAX_NO_GROUP_ELSE
                     };
+                    let soft_widths_max = soft_widths_max
+                        .iter()
+                        .copied()
+                        .chain(history_columns.iter().map(|_| None))
+                        .collect::<Vec<_>>();
 
                     proc_widget_state.table_width_state.calculated_column_widths =
                         get_column_widths(
                             draw_loc.width,
-                            hard_widths,
+                            &hard_widths,
                             &soft_widths_min,
-                            soft_widths_max,
+                            &soft_widths_max,
                             &(proc_widget_state
                                 .table_width_state
                                 .desired_column_widths
@@ -444,51 +479,105 @@ impl ProcessTableWidget for Painter {
                 let dcw = &proc_widget_state.table_width_state.desired_column_widths;
                 let ccw = &proc_widget_state.table_width_state.calculated_column_widths;
 
-                let process_rows = sliced_vec.iter().map(|(data, disabled)| {
-                    let truncated_data = data.iter().zip(hard_widths).enumerate().map(
-                        |(itx, ((entry, alternative), width))| {
-                            if let (Some(desired_col_width), Some(calculated_col_width)) =
-                                (dcw.get(itx), ccw.get(itx))
-                            {
-                                if width.is_none() {
-                                    if *desired_col_width > *calculated_col_width
-                                        && *calculated_col_width > 0
+                // The stringified rows line up with the finalized data they were made from, which
+                // has the usage values used to shade the CPU and memory cells.
+                let finalized_process_data = app_state
+                    .canvas_data
+                    .finalized_process_data_map
+                    .get(&widget_id)
+                    .map(|data| &data[start_position.min(data.len())..])
+                    .unwrap_or_default();
+                let selected_position = proc_widget_state.scroll_state.current_scroll_position;
+
+                let process_rows =
+                    sliced_vec
+                        .iter()
+                        .enumerate()
+                        .map(|(row_itx, (data, disabled))| {
+                            let is_selected =
+                                is_on_widget && start_position + row_itx == selected_position;
+                            let usage_styles = if *disabled || is_selected {
+                                (None, None)
+                            } else {
+                                finalized_process_data
+                                    .get(row_itx)
+                                    .map(|process| {
+                                        (
+                                            self.colours.get_usage_style(process.cpu_percent_usage),
+                                            self.colours.get_usage_style(process.mem_percent_usage),
+                                        )
+                                    })
+                                    .unwrap_or_default()
+                            };
+
+                            let truncated_data = data
+                                .iter()
+                                .zip(&hard_widths)
+                                .enumerate()
+                                .map(|(itx, ((entry, alternative), width))| {
+                                    if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                        (dcw.get(itx), ccw.get(itx))
                                     {
-                                        let graphemes =
-                                            UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                        if width.is_none() {
+                                            if *desired_col_width > *calculated_col_width
+                                                && *calculated_col_width > 0
+                                            {
+                                                let graphemes = UnicodeSegmentation::graphemes(
+                                                    entry.as_str(),
+                                                    true,
+                                                )
                                                 .collect::<Vec<&str>>();
 
-                                        if let Some(alternative) = alternative {
-                                            Text::raw(alternative)
-                                        } else if graphemes.len() > *calculated_col_width as usize
-                                            && *calculated_col_width > 1
-                                        {
-                                            // Truncate with ellipsis
-                                            let first_n = graphemes
-                                                [..(*calculated_col_width as usize - 1)]
-                                                .concat();
-                                            Text::raw(format!("{}…", first_n))
+                                                if let Some(alternative) = alternative {
+                                                    Text::raw(alternative)
+                                                } else if graphemes.len()
+                                                    > *calculated_col_width as usize
+                                                    && *calculated_col_width > 1
+                                                {
+                                                    // Truncate with ellipsis
+                                                    let first_n = graphemes
+                                                        [..(*calculated_col_width as usize - 1)]
+                                                        .concat();
+                                                    Text::raw(format!("{}…", first_n))
+                                                } else {
+                                                    Text::raw(entry)
+                                                }
+                                            } else {
+                                                Text::raw(entry)
+                                            }
                                         } else {
                                             Text::raw(entry)
                                         }
                                     } else {
                                         Text::raw(entry)
                                     }
-                                } else {
-                                    Text::raw(entry)
-                                }
+                                })
+                                .enumerate()
+                                .map(|(itx, text)| {
+                                    let usage_style = match itx {
+                                        PROCESS_CPU_COLUMN_INDEX => usage_styles.0,
+                                        PROCESS_MEM_COLUMN_INDEX => usage_styles.1,
+                                        _ => None,
+                                    };
+
+                                    if let Some(usage_style) = usage_style {
+                                        Cell::from(text).style(usage_style)
+                                    } else {
+                                        Cell::from(text)
+                                    }
+                                });
+
+                            let row_style = if *disabled {
+                                self.colours.disabled_text_style
                             } else {
-                                Text::raw(entry)
-                            }
-                        },
-                    );
+                                Style::default()
+                            };
 
-                    if *disabled {
-                        Row::new(truncated_data).style(self.colours.disabled_text_style)
-                    } else {
-                        Row::new(truncated_data)
-                    }
-                });
+                            Row::new(truncated_data).style(
+                                self.colours
+                                    .get_row_style(row_style, start_position + row_itx),
+                            )
+                        });
 
                 f.render_stateful_widget(
                     Table::new(process_rows)
@@ -690,7 +779,7 @@ impl ProcessTableWidget for Painter {
 
             // FIXME: [MOUSE] Mouse support for these in search
             // FIXME: [MOVEMENT] Movement support for these in search
-            let option_text = Spans::from(vec![
+            let mut option_spans = vec![
                 Span::styled(
                     format!("Case({})", if self.is_mac_os { "F1" } else { "Alt+C" }),
                     case_style,
@@ -705,7 +794,29 @@ impl ProcessTableWidget for Painter {
                     format!("Regex({})", if self.is_mac_os { "F3" } else { "Alt+R" }),
                     regex_style,
                 ),
-            ]);
+            ];
+
+            let saved_filters = &app_state.saved_filters;
+            if !saved_filters.is_empty() {
+                let current_saved_filter = proc_widget_state
+                    .get_current_saved_filter_index(saved_filters)
+                    .map(|index| &saved_filters[index]);
+
+                option_spans.push(Span::raw("  "));
+                option_spans.push(Span::styled(
+                    format!(
+                        "Saved(Up/Down): {}",
+                        current_saved_filter.map_or("-", |saved_filter| saved_filter.name.as_str())
+                    ),
+                    if current_saved_filter.is_some() {
+                        self.colours.currently_selected_text_style
+                    } else {
+                        self.colours.text_style
+                    },
+                ));
+            }
+
+            let option_text = Spans::from(option_spans);
 
             search_text.push(Spans::from(Span::styled(
                 if let Some(err) = &proc_widget_state
@@ -0,0 +1,162 @@
+use crate::{
+    app::{states::StatusItem, App},
+    canvas::Painter,
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::Paragraph,
+};
+use unicode_width::UnicodeWidthStr;
+
+const STATUS_SEPARATOR: &str = " | ";
+
+pub trait StatusBarWidget {
+    fn draw_status_bar<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl StatusBarWidget for Painter {
+    /// Draws a single line of information about the host, such as its name and uptime, followed
+    /// by the time and how often the data is refreshed.  Items that don't fit are left off the end.
+    fn draw_status_bar<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let status_bar_state = match app_state.status_bar_state.get_widget_state(widget_id) {
+            Some(status_bar_state) => status_bar_state,
+            None => return,
+        };
+        let host = &app_state.data_collection.host_harvest;
+
+        let items = status_bar_state.items.iter().filter_map(|item| match item {
+            StatusItem::Hostname => host
+                .hostname
+                .clone()
+                .map(|hostname| Span::styled(hostname, self.colours.widget_title_style)),
+            StatusItem::Os => host
+                .os_name
+                .clone()
+                .map(|os_name| Span::styled(os_name, self.colours.text_style)),
+            StatusItem::Kernel => host
+                .kernel_version
+                .clone()
+                .map(|kernel_version| Span::styled(kernel_version, self.colours.text_style)),
+            StatusItem::Uptime => host.uptime_secs.map(|uptime_secs| {
+                Span::styled(
+                    format!("up {}", get_uptime_string(uptime_secs)),
+                    self.colours.text_style,
+                )
+            }),
+            StatusItem::Users => host.user_count.map(|user_count| {
+                Span::styled(
+                    if user_count == 1 {
+                        "1 user".to_string()
+                    } else {
+                        format!("{} users", user_count)
+                    },
+                    self.colours.text_style,
+                )
+            }),
+            StatusItem::Tasks => host.process_count.map(|process_count| {
+                Span::styled(
+                    match host.thread_count {
+                        Some(thread_count) => {
+                            format!("{} procs, {} threads", process_count, thread_count)
+                        }
+                        None => format!("{} procs", process_count),
+                    },
+                    self.colours.text_style,
+                )
+            }),
+            StatusItem::Time => Some(Span::styled(
+                get_time_string(host.utc_offset_secs),
+                self.colours.text_style,
+            )),
+            StatusItem::Rate => Some(if app_state.is_frozen {
+                Span::styled("Frozen", self.colours.currently_selected_text_style)
+            } else {
+                Span::styled(
+                    format!(
+                        "every {}",
+                        get_rate_string(app_state.app_config_fields.update_rate_in_milliseconds)
+                    ),
+                    self.colours.text_style,
+                )
+            }),
+        });
+
+        let margined_loc = Layout::default()
+            .constraints([Constraint::Length(1)])
+            .horizontal_margin(1)
+            .split(draw_loc)[0];
+        let available_width = usize::from(margined_loc.width);
+
+        let mut spans = vec![];
+        let mut used_width = 0;
+        for item in items {
+            let item_width = UnicodeWidthStr::width(item.content.as_ref());
+            let separator_width = if spans.is_empty() {
+                0
+            } else {
+                STATUS_SEPARATOR.len()
+            };
+            if !spans.is_empty() && used_width + separator_width + item_width > available_width {
+                break;
+            }
+
+            if separator_width > 0 {
+                spans.push(Span::styled(STATUS_SEPARATOR, self.colours.border_style));
+            }
+            spans.push(item);
+            used_width += separator_width + item_width;
+        }
+
+        f.render_widget(Paragraph::new(Spans::from(spans)), margined_loc);
+    }
+}
+
+fn get_uptime_string(uptime_secs: u64) -> String {
+    let (days, hours, minutes) = (
+        uptime_secs / 86400,
+        (uptime_secs % 86400) / 3600,
+        (uptime_secs % 3600) / 60,
+    );
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Returns the current time in the given time zone, or in UTC if it isn't known.
+fn get_time_string(utc_offset_secs: Option<i32>) -> String {
+    let now = time::OffsetDateTime::now_utc();
+    let format = time::macros::format_description!("[hour]:[minute]:[second]");
+
+    match utc_offset_secs.and_then(|offset| time::UtcOffset::from_whole_seconds(offset).ok()) {
+        Some(offset) => now.to_offset(offset).format(&format).unwrap_or_default(),
+        None => format!("{} UTC", now.format(&format).unwrap_or_default()),
+    }
+}
+
+fn get_rate_string(update_rate_in_milliseconds: u64) -> String {
+    let (secs, millis) = (
+        update_rate_in_milliseconds / 1000,
+        update_rate_in_milliseconds % 1000,
+    );
+
+    if secs == 0 {
+        format!("{}ms", millis)
+    } else if millis == 0 {
+        format!("{}s", secs)
+    } else {
+        format!("{:.1}s", update_rate_in_milliseconds as f64 / 1000.0)
+    }
+}
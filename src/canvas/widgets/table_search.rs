@@ -0,0 +1,177 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::{
+    app::TableSearchState,
+    canvas::{drawing_utils::get_search_start_position, Painter},
+    constants::*,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub trait TableSearchWidget {
+    /// Draws the search field of a simple table (temperatures or disks).  This is drawn
+    /// as part of the table's own widget.
+    ///
+    /// This should not be directly called.
+    fn draw_table_search_field<B: Backend>(
+        &self, f: &mut Frame<'_, B>, search_state: &mut TableSearchState, draw_loc: Rect,
+        draw_border: bool, is_on_widget: bool, is_force_redraw: bool,
+    );
+}
+
+impl TableSearchWidget for Painter {
+    fn draw_table_search_field<B: Backend>(
+        &self, f: &mut Frame<'_, B>, search_state: &mut TableSearchState, draw_loc: Rect,
+        draw_border: bool, is_on_widget: bool, is_force_redraw: bool,
+    ) {
+        let num_columns = usize::from(draw_loc.width);
+        let search_title = "> ";
+
+        let num_chars_for_text = search_title.len();
+        let cursor_position = search_state.search_state.get_cursor_position();
+        let current_cursor_position = search_state.search_state.char_cursor_position;
+
+        let start_position: usize = get_search_start_position(
+            num_columns.saturating_sub(num_chars_for_text + 5),
+            &search_state.search_state.cursor_direction,
+            &mut search_state.search_state.cursor_bar,
+            current_cursor_position,
+            is_force_redraw,
+        );
+
+        let query = search_state.search_state.current_search_query.as_str();
+        let query_with_cursor = if is_on_widget {
+            let mut current_grapheme_posn = 0;
+            let mut res = UnicodeSegmentation::grapheme_indices(query, true)
+                .filter_map(|grapheme| {
+                    current_grapheme_posn += UnicodeWidthStr::width(grapheme.1);
+
+                    if current_grapheme_posn <= start_position {
+                        None
+                    } else if grapheme.0 == cursor_position {
+                        Some(Span::styled(
+                            grapheme.1,
+                            self.colours.currently_selected_text_style,
+                        ))
+                    } else {
+                        Some(Span::styled(grapheme.1, self.colours.text_style))
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if cursor_position == query.len() {
+                res.push(Span::styled(
+                    " ",
+                    self.colours.currently_selected_text_style,
+                ))
+            }
+
+            res
+        } else {
+            vec![Span::styled(query.to_string(), self.colours.text_style)]
+        };
+
+        let mut search_text = vec![Spans::from({
+            let mut search_vec = vec![Span::styled(
+                search_title,
+                if is_on_widget {
+                    self.colours.table_header_style
+                } else {
+                    self.colours.text_style
+                },
+            )];
+            search_vec.extend(query_with_cursor);
+
+            search_vec
+        })];
+
+        let option_style = |is_enabled: bool| {
+            if is_enabled {
+                self.colours.currently_selected_text_style
+            } else {
+                self.colours.text_style
+            }
+        };
+
+        let option_text = Spans::from(vec![
+            Span::styled(
+                format!("Case({})", if self.is_mac_os { "F1" } else { "Alt+C" }),
+                option_style(!search_state.is_ignoring_case),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("Whole({})", if self.is_mac_os { "F2" } else { "Alt+W" }),
+                option_style(search_state.is_searching_whole_word),
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("Regex({})", if self.is_mac_os { "F3" } else { "Alt+R" }),
+                option_style(search_state.is_searching_with_regex),
+            ),
+        ]);
+
+        search_text.push(Spans::from(Span::styled(
+            if let Some(err) = &search_state.search_state.error_message {
+                err.as_str()
+            } else {
+                ""
+            },
+            self.colours.invalid_query_style,
+        )));
+        search_text.push(option_text);
+
+        let current_border_style = if search_state.search_state.is_invalid_search {
+            self.colours.invalid_query_style
+        } else if is_on_widget {
+            self.colours.highlighted_border_style
+        } else {
+            self.colours.border_style
+        };
+
+        let title = Span::styled(
+            if draw_border {
+                const TITLE_BASE: &str = " Esc to close ";
+                let repeat_num =
+                    usize::from(draw_loc.width).saturating_sub(TITLE_BASE.chars().count() + 2);
+                format!("{} Esc to close ", "─".repeat(repeat_num))
+            } else {
+                String::new()
+            },
+            current_border_style,
+        );
+
+        let search_block = if draw_border {
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(current_border_style)
+        } else if is_on_widget {
+            Block::default()
+                .borders(*SIDE_BORDERS)
+                .border_style(current_border_style)
+        } else {
+            Block::default().borders(Borders::NONE)
+        };
+
+        let margined_draw_loc = Layout::default()
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(if is_on_widget || draw_border { 0 } else { 1 })
+            .direction(Direction::Horizontal)
+            .split(draw_loc)[0];
+
+        f.render_widget(
+            Paragraph::new(search_text)
+                .block(search_block)
+                .style(self.colours.text_style)
+                .alignment(Alignment::Left),
+            margined_draw_loc,
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    app::App,
+    canvas::Painter,
+    constants::*,
+    data_conversion::{get_celsius, get_temperature_unit},
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Layout, Rect},
+    terminal::Frame,
+    text::{Span, Spans},
+    widgets::{Block, Paragraph},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub trait TempBasicWidget {
+    fn draw_basic_temp<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl TempBasicWidget for Painter {
+    /// Draws the hottest sensors, one per line.
+    fn draw_basic_temp<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let margined_loc = Layout::default()
+            .constraints([Constraint::Percentage(100)])
+            .horizontal_margin(1)
+            .split(draw_loc);
+
+        if app_state.current_widget.widget_id == widget_id {
+            f.render_widget(
+                Block::default()
+                    .borders(*SIDE_BORDERS)
+                    .border_style(self.colours.highlighted_border_style),
+                draw_loc,
+            );
+        }
+
+        let temp_type = &app_state.app_config_fields.temperature_type;
+        let mut temp_harvest = app_state
+            .data_collection
+            .temp_harvest
+            .iter()
+            .collect::<Vec<_>>();
+        temp_harvest.sort_by(|a, b| {
+            b.temperature
+                .partial_cmp(&a.temperature)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let text_width = usize::from(margined_loc[0].width);
+        let temp_text = if temp_harvest.is_empty() {
+            vec![Spans::from(Span::styled(
+                "No Sensors Found",
+                self.colours.text_style,
+            ))]
+        } else {
+            temp_harvest
+                .into_iter()
+                .take(usize::from(margined_loc[0].height))
+                .map(|temp_harvest| {
+                    let temperature = format!(
+                        "{}{}",
+                        temp_harvest.temperature.ceil() as u64,
+                        get_temperature_unit(temp_type)
+                    );
+                    let name_width = text_width.saturating_sub(temperature.len() + 1);
+                    let name = UnicodeSegmentation::graphemes(temp_harvest.name.as_str(), true)
+                        .take(name_width)
+                        .collect::<String>();
+
+                    Spans::from(Span::styled(
+                        format!("{:<width$} {}", name, temperature, width = name_width),
+                        self.colours
+                            .get_temperature_style(get_celsius(temp_harvest.temperature, temp_type))
+                            .unwrap_or(self.colours.text_style),
+                    ))
+                })
+                .collect()
+        };
+
+        f.render_widget(
+            Paragraph::new(temp_text).block(Block::default()),
+            margined_loc[0],
+        );
+
+        // Update draw loc in widget map
+        if app_state.should_get_widget_bounds() {
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -0,0 +1,506 @@
+use once_cell::sync::Lazy;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    app::{layout_manager::WidgetDirection, App},
+    canvas::{
+        drawing_utils::{
+            get_column_widths, get_drawn_points, get_filled_points, get_filled_sample_count,
+            get_graph_marker, get_start_position, get_widget_name, interpolate_points,
+        },
+        Painter,
+    },
+    constants::*,
+    data_conversion::{get_temperature_unit, ConvertedTempData},
+};
+
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    terminal::Frame,
+    text::Span,
+    text::{Spans, Text},
+    widgets::{Axis, Block, Borders, Chart, Dataset, Row, Table},
+};
+
+const TEMP_LEGEND_HEADER: [&str; 2] = ["Sensor", "Temp"];
+const ALL_POSITION: usize = 0;
+
+static TEMP_LEGEND_HEADER_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
+    TEMP_LEGEND_HEADER
+        .iter()
+        .map(|entry| entry.len() as u16)
+        .collect::<Vec<_>>()
+});
+
+pub trait TempGraphWidget {
+    fn draw_temp_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+    fn draw_temp_graph_chart<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+    fn draw_temp_graph_legend<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    );
+}
+
+impl TempGraphWidget for Painter {
+    fn draw_temp_graph<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let left_legend = app_state
+            .widget_map
+            .get(&widget_id)
+            .map(|widget| widget.left_legend)
+            .unwrap_or_default();
+
+        let show_legend = app_state
+            .temp_graph_state
+            .get_widget_state(widget_id)
+            .map(|temp_graph_widget_state| temp_graph_widget_state.show_legend)
+            .unwrap_or(true);
+
+        if !show_legend || draw_loc.width as f64 * 0.3 <= 10.0 {
+            // Skip drawing legend
+            if app_state.current_widget.widget_id == (widget_id + 1) {
+                if left_legend {
+                    app_state.move_widget_selection(&WidgetDirection::Right);
+                } else {
+                    app_state.move_widget_selection(&WidgetDirection::Left);
+                }
+            }
+            self.draw_temp_graph_chart(f, app_state, draw_loc, widget_id);
+            if let Some(temp_graph_widget_state) =
+                app_state.temp_graph_state.get_mut_widget_state(widget_id)
+            {
+                temp_graph_widget_state.is_legend_hidden = true;
+            }
+
+            // Update draw loc in widget map
+            if app_state.should_get_widget_bounds() {
+                if let Some(bottom_widget) = app_state.widget_map.get_mut(&widget_id) {
+                    bottom_widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                    bottom_widget.bottom_right_corner =
+                        Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+                }
+            }
+        } else {
+            let (graph_index, legend_index, constraints) = if left_legend {
+                (
+                    1,
+                    0,
+                    [Constraint::Percentage(30), Constraint::Percentage(70)],
+                )
+            } else {
+                (
+                    0,
+                    1,
+                    [Constraint::Percentage(70), Constraint::Percentage(30)],
+                )
+            };
+
+            let partitioned_draw_loc = Layout::default()
+                .margin(0)
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(draw_loc);
+
+            self.draw_temp_graph_chart(f, app_state, partitioned_draw_loc[graph_index], widget_id);
+            self.draw_temp_graph_legend(
+                f,
+                app_state,
+                partitioned_draw_loc[legend_index],
+                widget_id + 1,
+            );
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                if let Some(graph_widget) = app_state.widget_map.get_mut(&widget_id) {
+                    graph_widget.top_left_corner = Some((
+                        partitioned_draw_loc[graph_index].x,
+                        partitioned_draw_loc[graph_index].y,
+                    ));
+                    graph_widget.bottom_right_corner = Some((
+                        partitioned_draw_loc[graph_index].x
+                            + partitioned_draw_loc[graph_index].width,
+                        partitioned_draw_loc[graph_index].y
+                            + partitioned_draw_loc[graph_index].height,
+                    ));
+                }
+
+                if let Some(legend_widget) = app_state.widget_map.get_mut(&(widget_id + 1)) {
+                    legend_widget.top_left_corner = Some((
+                        partitioned_draw_loc[legend_index].x,
+                        partitioned_draw_loc[legend_index].y,
+                    ));
+                    legend_widget.bottom_right_corner = Some((
+                        partitioned_draw_loc[legend_index].x
+                            + partitioned_draw_loc[legend_index].width,
+                        partitioned_draw_loc[legend_index].y
+                            + partitioned_draw_loc[legend_index].height,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn draw_temp_graph_chart<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(temp_graph_widget_state) =
+            app_state.temp_graph_state.widget_states.get_mut(&widget_id)
+        {
+            let temp_data: &mut [ConvertedTempData] = &mut app_state.canvas_data.temp_graph_data;
+            let temp_unit = get_temperature_unit(&app_state.app_config_fields.temperature_type);
+
+            let display_time_labels = vec![
+                Span::styled(
+                    format!("{}s", temp_graph_widget_state.current_display_time / 1000),
+                    self.colours.graph_label_style,
+                ),
+                Span::styled("0s".to_string(), self.colours.graph_label_style),
+            ];
+
+            let time_start = -(temp_graph_widget_state.current_display_time as f64);
+
+            let x_axis = if app_state.app_config_fields.hide_time
+                || (app_state.app_config_fields.autohide_time
+                    && temp_graph_widget_state.autohide_timer.is_none())
+            {
+                Axis::default().bounds([time_start, 0.0])
+            } else if let Some(time) = temp_graph_widget_state.autohide_timer {
+                if std::time::Instant::now().duration_since(time).as_millis()
+                    < AUTOHIDE_TIMEOUT_MILLISECONDS as u128
+                {
+                    Axis::default()
+                        .bounds([time_start, 0.0])
+                        .style(self.colours.graph_style)
+                        .labels(display_time_labels)
+                } else {
+                    temp_graph_widget_state.autohide_timer = None;
+                    Axis::default().bounds([time_start, 0.0])
+                }
+            } else if draw_loc.height < TIME_LABEL_HEIGHT_LIMIT {
+                Axis::default().bounds([time_start, 0.0])
+            } else {
+                Axis::default()
+                    .bounds([time_start, 0.0])
+                    .style(self.colours.graph_style)
+                    .labels(display_time_labels)
+            };
+
+            let current_scroll_position =
+                temp_graph_widget_state.scroll_state.current_scroll_position;
+            let is_shown = |itx: usize| {
+                current_scroll_position == ALL_POSITION || itx == current_scroll_position
+            };
+
+            // Unlike percentages, temperatures have no natural upper bound - scale the y-axis to
+            // the hottest visible point, rounded up to the next multiple of ten.
+            let max_temp = temp_data
+                .iter()
+                .enumerate()
+                .filter(|(itx, _)| is_shown(*itx))
+                .flat_map(|(_, sensor)| sensor.temp_data.iter())
+                .filter(|(time, _)| *time >= time_start)
+                .map(|(_, temp)| *temp)
+                .fold(0.0, f64::max);
+            let y_max = ((max_temp / 10.0).floor() + 1.0) * 10.0;
+
+            let y_max_label = format!("{}{}", y_max, temp_unit);
+            let y_axis_labels = vec![
+                Span::styled(
+                    format!(
+                        "{:>width$}",
+                        format!("0{}", temp_unit),
+                        width = y_max_label.chars().count()
+                    ),
+                    self.colours.graph_label_style,
+                ),
+                Span::styled(y_max_label, self.colours.graph_label_style),
+            ];
+
+            let y_axis = Axis::default()
+                .style(self.colours.graph_style)
+                .bounds([0.0, y_max])
+                .labels(y_axis_labels);
+
+            let interpolated_temp_points = temp_data
+                .iter_mut()
+                .enumerate()
+                .map(|(itx, sensor)| {
+                    if is_shown(itx) {
+                        if let Some(end_pos) = sensor
+                            .temp_data
+                            .iter()
+                            .position(|(time, _data)| *time >= time_start)
+                        {
+                            if end_pos > 1 {
+                                let start_pos = end_pos - 1;
+                                let outside_point = sensor.temp_data.get(start_pos);
+                                let inside_point = sensor.temp_data.get(end_pos);
+
+                                if let (Some(outside_point), Some(inside_point)) =
+                                    (outside_point, inside_point)
+                                {
+                                    let old = *outside_point;
+
+                                    let new_point = (
+                                        time_start,
+                                        interpolate_points(outside_point, inside_point, time_start),
+                                    );
+
+                                    if let Some(to_replace) = sensor.temp_data.get_mut(start_pos) {
+                                        *to_replace = new_point;
+                                        Some((start_pos, old))
+                                    } else {
+                                        None // Failed to get mutable reference.
+                                    }
+                                } else {
+                                    None // Point somehow doesn't exist in our data
+                                }
+                            } else {
+                                None // Point is already "leftmost", no need to interpolate.
+                            }
+                        } else {
+                            None // There is no point.
+                        }
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let graph_style = temp_graph_widget_state.graph_style;
+            let marker = get_graph_marker(graph_style);
+
+            // Temperatures don't add up, so stacked graphs are just filled.  Hidden sensors get no
+            // filled points, but keep their place so the points line up with the sensors.
+            let filled_points = if graph_style.is_filled() {
+                let series = temp_data
+                    .iter()
+                    .enumerate()
+                    .map(|(itx, sensor)| {
+                        if is_shown(itx) {
+                            &sensor.temp_data[..]
+                        } else {
+                            &[]
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                get_filled_points(
+                    &series,
+                    time_start,
+                    get_filled_sample_count(graph_style, draw_loc.width),
+                    false,
+                )
+            } else {
+                vec![]
+            };
+
+            let dataset_vector: Vec<Dataset<'_>> = temp_data
+                .iter()
+                .enumerate()
+                .skip(1) // Skip the "All" entry, it has no data of its own.
+                .filter(|(itx, _)| is_shown(*itx))
+                .rev()
+                .map(|(itx, sensor)| {
+                    Dataset::default()
+                        .marker(marker)
+                        .style(
+                            self.colours.cpu_colour_styles
+                                [(itx - 1) % self.colours.cpu_colour_styles.len()],
+                        )
+                        .data(get_drawn_points(&filled_points, itx, &sensor.temp_data))
+                        .graph_type(tui::widgets::GraphType::Line)
+                })
+                .collect();
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let border_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            let title_name = format!(
+                " {} ",
+                get_widget_name(&app_state.widget_map, widget_id, "Temperature History")
+            );
+            let title = if app_state.is_expanded {
+                let title_base = format!("{}── Esc to go back ", title_name);
+
+                Spans::from(vec![
+                    Span::styled(title_name.clone(), self.colours.widget_title_style),
+                    Span::styled(
+                        format!(
+                            "─{}─ Esc to go back ",
+                            "─".repeat(usize::from(draw_loc.width).saturating_sub(
+                                UnicodeSegmentation::graphemes(title_base.as_str(), true).count()
+                                    + 2
+                            ))
+                        ),
+                        border_style,
+                    ),
+                ])
+            } else {
+                Spans::from(vec![Span::styled(
+                    title_name,
+                    self.colours.widget_title_style,
+                )])
+            };
+
+            f.render_widget(
+                Chart::new(dataset_vector)
+                    .block(
+                        Block::default()
+                            .title(title)
+                            .borders(Borders::ALL)
+                            .border_style(border_style),
+                    )
+                    .x_axis(x_axis)
+                    .y_axis(y_axis),
+                draw_loc,
+            );
+
+            // Reset interpolated points
+            temp_data.iter_mut().zip(interpolated_temp_points).for_each(
+                |(sensor, interpolation)| {
+                    if let Some((index, old_value)) = interpolation {
+                        if let Some(to_replace) = sensor.temp_data.get_mut(index) {
+                            *to_replace = old_value;
+                        }
+                    }
+                },
+            );
+        }
+    }
+
+    fn draw_temp_graph_legend<B: Backend>(
+        &self, f: &mut Frame<'_, B>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(temp_graph_widget_state) = app_state
+            .temp_graph_state
+            .widget_states
+            .get_mut(&(widget_id - 1))
+        {
+            temp_graph_widget_state.is_legend_hidden = false;
+            let temp_data: &[ConvertedTempData] = &app_state.canvas_data.temp_graph_data;
+            let temp_table_state = &mut temp_graph_widget_state.scroll_state.table_state;
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
+                0
+            } else {
+                app_state.app_config_fields.table_gap
+            };
+            let start_position = get_start_position(
+                usize::from(
+                    (draw_loc.height + (1 - table_gap)).saturating_sub(self.table_height_offset),
+                ),
+                &temp_graph_widget_state.scroll_state.scroll_direction,
+                &mut temp_graph_widget_state
+                    .scroll_state
+                    .previous_scroll_position,
+                temp_graph_widget_state.scroll_state.current_scroll_position,
+                app_state.is_force_redraw,
+            );
+            temp_table_state.select(Some(
+                temp_graph_widget_state
+                    .scroll_state
+                    .current_scroll_position
+                    .saturating_sub(start_position),
+            ));
+
+            let sliced_temp_data = temp_data.get(start_position..).unwrap_or_default();
+
+            let offset_scroll_index = temp_graph_widget_state
+                .scroll_state
+                .current_scroll_position
+                .saturating_sub(start_position);
+
+            // Calculate widths
+            if recalculate_column_widths {
+                temp_graph_widget_state
+                    .table_width_state
+                    .desired_column_widths = vec![
+                    temp_data
+                        .iter()
+                        .map(|sensor| sensor.sensor_name.len() as u16)
+                        .max()
+                        .unwrap_or(0)
+                        .max(TEMP_LEGEND_HEADER_LENS[0]),
+                    5,
+                ];
+                temp_graph_widget_state
+                    .table_width_state
+                    .calculated_column_widths = get_column_widths(
+                    draw_loc.width,
+                    &[None, Some(5)],
+                    &(TEMP_LEGEND_HEADER_LENS
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>()),
+                    &[Some(0.7), None],
+                    &(temp_graph_widget_state
+                        .table_width_state
+                        .desired_column_widths
+                        .iter()
+                        .map(|width| Some(*width))
+                        .collect::<Vec<_>>()),
+                    false,
+                );
+            }
+
+            let temp_rows = sliced_temp_data.iter().enumerate().map(|(itx, sensor)| {
+                let temp_string_row = vec![
+                    Text::raw(&sensor.sensor_name),
+                    Text::raw(&sensor.legend_value),
+                ];
+
+                Row::new(temp_string_row).style(if itx == offset_scroll_index {
+                    self.colours.currently_selected_text_style
+                } else if itx + start_position == ALL_POSITION {
+                    self.colours.all_colour_style
+                } else {
+                    self.colours.cpu_colour_styles[(itx + start_position - ALL_POSITION - 1)
+                        % self.colours.cpu_colour_styles.len()]
+                })
+            });
+
+            // Note we don't set highlight_style, as it should always be shown for this widget.
+            let border_and_title_style = if is_on_widget {
+                self.colours.highlighted_border_style
+            } else {
+                self.colours.border_style
+            };
+
+            // Draw
+            f.render_stateful_widget(
+                Table::new(temp_rows)
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_style(border_and_title_style),
+                    )
+                    .header(
+                        Row::new(TEMP_LEGEND_HEADER.to_vec())
+                            .style(self.colours.table_header_style)
+                            .bottom_margin(table_gap),
+                    )
+                    .widths(
+                        &(temp_graph_widget_state
+                            .table_width_state
+                            .calculated_column_widths
+                            .iter()
+                            .map(|calculated_width| Constraint::Length(*calculated_width))
+                            .collect::<Vec<_>>()),
+                    ),
+                draw_loc,
+                temp_table_state,
+            );
+        }
+    }
+}
@@ -11,19 +11,20 @@ use tui::{
 use crate::{
     app,
     canvas::{
-        drawing_utils::{get_column_widths, get_start_position},
+        drawing_utils::{get_column_widths, get_start_position, get_widget_name},
+        widgets::TableSearchWidget,
         Painter,
     },
     constants::*,
+    data_conversion::ConvertedTempRow,
 };
 use unicode_segmentation::UnicodeSegmentation;
 
-const TEMP_HEADERS: [&str; 2] = ["Sensor", "Temp"];
-
 static TEMP_HEADERS_LENS: Lazy<Vec<u16>> = Lazy::new(|| {
     TEMP_HEADERS
         .iter()
-        .map(|entry| entry.len() as u16)
+        // Leave room for the sort arrow.
+        .map(|entry| entry.len() as u16 + 1)
         .collect::<Vec<_>>()
 });
 
@@ -41,6 +42,29 @@ impl TempTableWidget for Painter {
     ) {
         let recalculate_column_widths = app_state.should_get_widget_bounds();
         if let Some(temp_widget_state) = app_state.temp_state.widget_states.get_mut(&widget_id) {
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+            let is_search_enabled = temp_widget_state.search_state.is_search_enabled();
+            let draw_loc = if is_search_enabled {
+                let search_height = if draw_border { 5 } else { 3 };
+                let temp_chunk = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(search_height)])
+                    .split(draw_loc);
+
+                self.draw_table_search_field(
+                    f,
+                    &mut temp_widget_state.search_state,
+                    temp_chunk[1],
+                    draw_border,
+                    is_on_widget,
+                    app_state.is_force_redraw,
+                );
+
+                temp_chunk[0]
+            } else {
+                draw_loc
+            };
+
             let table_gap = if draw_loc.height < TABLE_GAP_HEIGHT_LIMIT {
                 0
             } else {
@@ -55,7 +79,6 @@ impl TempTableWidget for Painter {
                 temp_widget_state.scroll_state.current_scroll_position,
                 app_state.is_force_redraw,
             );
-            let is_on_widget = widget_id == app_state.current_widget.widget_id;
             let temp_table_state = &mut temp_widget_state.scroll_state.table_state;
             temp_table_state.select(Some(
                 temp_widget_state
@@ -63,15 +86,31 @@ impl TempTableWidget for Painter {
                     .current_scroll_position
                     .saturating_sub(start_position),
             ));
-            let sliced_vec = &app_state.canvas_data.temp_sensor_data[start_position..];
+            let temp_data: &[ConvertedTempRow] = app_state
+                .canvas_data
+                .temp_sensor_data
+                .get(&widget_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+
+            // Sanity check
+            let start_position = if start_position >= temp_data.len() {
+                temp_data.len().saturating_sub(1)
+            } else {
+                start_position
+            };
+            let sliced_vec = &temp_data[start_position..];
+            let temp_headers = temp_widget_state
+                .sort_state
+                .get_column_headers(&TEMP_HEADERS);
 
             // Calculate widths
             let hard_widths = [None, None];
             if recalculate_column_widths {
                 temp_widget_state.table_width_state.desired_column_widths = {
                     let mut column_widths = TEMP_HEADERS_LENS.clone();
-                    for row in sliced_vec {
-                        for (col, entry) in row.iter().enumerate() {
+                    for temp_row in sliced_vec {
+                        for (col, entry) in temp_row.row.iter().enumerate() {
                             if entry.len() as u16 > column_widths[col] {
                                 column_widths[col] = entry.len() as u16;
                             }
@@ -100,32 +139,28 @@ impl TempTableWidget for Painter {
 
             let dcw = &temp_widget_state.table_width_state.desired_column_widths;
             let ccw = &temp_widget_state.table_width_state.calculated_column_widths;
-            let temperature_rows =
-                sliced_vec.iter().map(|temp_row| {
-                    let truncated_data = temp_row.iter().zip(&hard_widths).enumerate().map(
-                        |(itx, (entry, width))| {
-                            if width.is_none() {
-                                if let (Some(desired_col_width), Some(calculated_col_width)) =
-                                    (dcw.get(itx), ccw.get(itx))
+            let temperature_rows = sliced_vec.iter().enumerate().map(|(row_itx, temp_row)| {
+                let truncated_data = temp_row.row.iter().zip(&hard_widths).enumerate().map(
+                    |(itx, (entry, width))| {
+                        if width.is_none() {
+                            if let (Some(desired_col_width), Some(calculated_col_width)) =
+                                (dcw.get(itx), ccw.get(itx))
+                            {
+                                if *desired_col_width > *calculated_col_width
+                                    && *calculated_col_width > 0
                                 {
-                                    if *desired_col_width > *calculated_col_width
-                                        && *calculated_col_width > 0
-                                    {
-                                        let graphemes =
-                                            UnicodeSegmentation::graphemes(entry.as_str(), true)
-                                                .collect::<Vec<&str>>();
+                                    let graphemes =
+                                        UnicodeSegmentation::graphemes(entry.as_str(), true)
+                                            .collect::<Vec<&str>>();
 
-                                        if graphemes.len() > *calculated_col_width as usize
-                                            && *calculated_col_width > 1
-                                        {
-                                            // Truncate with ellipsis
-                                            let first_n = graphemes
-                                                [..(*calculated_col_width as usize - 1)]
-                                                .concat();
-                                            Text::raw(format!("{}…", first_n))
-                                        } else {
-                                            Text::raw(entry)
-                                        }
+                                    if graphemes.len() > *calculated_col_width as usize
+                                        && *calculated_col_width > 1
+                                    {
+                                        // Truncate with ellipsis
+                                        let first_n = graphemes
+                                            [..(*calculated_col_width as usize - 1)]
+                                            .concat();
+                                        Text::raw(format!("{}…", first_n))
                                     } else {
                                         Text::raw(entry)
                                     }
@@ -135,11 +170,23 @@ impl TempTableWidget for Painter {
                             } else {
                                 Text::raw(entry)
                             }
-                        },
-                    );
+                        } else {
+                            Text::raw(entry)
+                        }
+                    },
+                );
 
-                    Row::new(truncated_data)
-                });
+                // Colour sensors that are running warm or hot.
+                let row_style = temp_row
+                    .celsius
+                    .and_then(|celsius| self.colours.get_temperature_style(celsius))
+                    .unwrap_or_default();
+
+                Row::new(truncated_data).style(
+                    self.colours
+                        .get_row_style(row_style, start_position + row_itx),
+                )
+            });
 
             let (border_style, highlight_style) = if is_on_widget {
                 (
@@ -150,26 +197,28 @@ impl TempTableWidget for Painter {
                 (self.colours.border_style, self.colours.text_style)
             };
 
+            let widget_name = get_widget_name(&app_state.widget_map, widget_id, "Temperatures");
             let title_base = if app_state.app_config_fields.show_table_scroll_position {
                 let title_string = format!(
-                    " Temperatures ({} of {}) ",
+                    " {} ({} of {}) ",
+                    widget_name,
                     temp_widget_state
                         .scroll_state
                         .current_scroll_position
                         .saturating_add(1),
-                    app_state.canvas_data.temp_sensor_data.len()
+                    temp_data.len()
                 );
 
                 if title_string.len() <= draw_loc.width as usize {
                     title_string
                 } else {
-                    " Temperatures ".to_string()
+                    format!(" {} ", widget_name)
                 }
             } else {
-                " Temperatures ".to_string()
+                format!(" {} ", widget_name)
             };
 
-            let title = if app_state.is_expanded {
+            let title = if app_state.is_expanded && !is_search_enabled {
                 const ESCAPE_ENDING: &str = "── Esc to go back ";
 
                 let (chosen_title_base, expanded_title_base) = {
@@ -177,8 +226,8 @@ impl TempTableWidget for Painter {
 
                     if temp_title_base.len() > draw_loc.width as usize {
                         (
-                            " Temperatures ".to_string(),
-                            format!("{}{}", " Temperatures ".to_string(), ESCAPE_ENDING),
+                            format!(" {} ", widget_name),
+                            format!(" {} {}", widget_name, ESCAPE_ENDING),
                         )
                     } else {
                         (title_base, temp_title_base)
@@ -231,7 +280,7 @@ impl TempTableWidget for Painter {
             f.render_stateful_widget(
                 Table::new(temperature_rows)
                     .header(
-                        Row::new(TEMP_HEADERS.to_vec())
+                        Row::new(temp_headers)
                             .style(self.colours.table_header_style)
                             .bottom_margin(table_gap),
                     )
@@ -250,6 +299,38 @@ impl TempTableWidget for Painter {
                 temp_table_state,
             );
 
+            // Check if we need to update columnar bounds...
+            if recalculate_column_widths
+                || temp_widget_state.sort_state.column_header_x_locs.is_none()
+                || temp_widget_state.sort_state.column_header_y_loc.is_none()
+            {
+                temp_widget_state.sort_state.column_header_y_loc =
+                    Some(draw_loc.y + if draw_border { 1 } else { 0 });
+
+                let mut current_x_left = draw_loc.x + 1;
+                let max_x_right = draw_loc.x + draw_loc.width - 1;
+
+                let mut x_locs = vec![];
+
+                for width in temp_widget_state
+                    .table_width_state
+                    .calculated_column_widths
+                    .iter()
+                {
+                    let right_bound = current_x_left + width;
+
+                    if right_bound < max_x_right {
+                        x_locs.push((current_x_left, right_bound));
+                        current_x_left = right_bound + 1;
+                    } else {
+                        x_locs.push((current_x_left, max_x_right));
+                        break;
+                    }
+                }
+
+                temp_widget_state.sort_state.column_header_x_locs = Some(x_locs);
+            }
+
             if app_state.should_get_widget_bounds() {
                 // Update draw loc in widget map
                 // Note there is no difference between this and using draw_loc, but I'm too lazy to fix it.
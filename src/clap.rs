@@ -42,6 +42,11 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|       temp_graph,        |
+|    temperature_graph     |
++--------------------------+
+|     sensor, sensors      |
++--------------------------+
 |           disk           |
 +--------------------------+
 |       batt, battery      |
@@ -75,6 +80,11 @@ Supported widget names:
 +--------------------------+
 |     temp, temperature    |
 +--------------------------+
+|       temp_graph,        |
+|    temperature_graph     |
++--------------------------+
+|     sensor, sensors      |
++--------------------------+
 |           disk           |
 +--------------------------+
 \n\n"
@@ -114,6 +124,15 @@ option.\n\n",
         );
 
     // All flags.  These are in alphabetical order
+    let all_filesystems = Arg::with_name("all_filesystems")
+        .long("all_filesystems")
+        .help("Shows network and virtual filesystems in the disk widget.")
+        .long_help(
+            "\
+Shows network and virtual filesystems, such as NFS, tmpfs, or
+overlay mounts, in the disk widget.  By default, only physical
+filesystems are shown.\n\n",
+        );
     let autohide_time = Arg::with_name("autohide_time")
         .long("autohide_time")
         .help("Temporarily shows the time scale in graphs.")
@@ -314,19 +333,20 @@ Use a pre-defined color scheme.  Currently supported values are:
 +------------------------------------------------------------+
 | nord-light (nord but for use with light backgrounds)       |
 +------------------------------------------------------------+
+| dracula (a dark theme with vivid purples and pinks)        |
++------------------------------------------------------------+
+| solarized (a low contrast, dark blue-green palette)        |
++------------------------------------------------------------+
+| solarized-light (solarized but for light backgrounds)      |
++------------------------------------------------------------+
+
+Any other value is treated as the name of a theme file in the
+\"themes\" directory next to the config file.  For example,
+\"--color mytheme\" loads \"themes/mytheme.toml\".
 
 Defaults to \"default\".
 \n\n",
-        )
-        .possible_values(&[
-            "default",
-            "default-light",
-            "gruvbox",
-            "gruvbox-light",
-            "nord",
-            "nord-light",
-        ])
-        .hide_possible_values(true);
+        );
     let mem_as_value = Arg::with_name("mem_as_value")
         .long("mem_as_value")
         .help("Defaults to showing process memory usage by value.")
@@ -433,6 +453,15 @@ Displays the network widget with a log scale.  Defaults to a non-log scale.\n\n"
 Displays the network widget with binary prefixes (i.e. kibibits, mebibits) rather than a decimal prefix (i.e. kilobits, megabits).  Defaults to decimal prefixes.\n\n\n",
         );
 
+    let normalize_load_avg = Arg::with_name("normalize_load_avg")
+        .long("normalize_load_avg")
+        .help("Divides the load average graph by the number of cores.")
+        .long_help(
+            "\
+Divides the values in the CPU widget's load average graph by the
+number of cores, so that a load of 1.0 means every core is busy.\n\n",
+        );
+
     let app = App::new(crate_name!())
         .setting(AppSettings::UnifiedHelpMessage)
         .version(crate_version!())
@@ -446,6 +475,7 @@ Displays the network widget with binary prefixes (i.e. kibibits, mebibits) rathe
         .arg(fahrenheit)
         .arg(celsius)
         .group(ArgGroup::with_name("TEMPERATURE_TYPE").args(&["kelvin", "fahrenheit", "celsius"]))
+        .arg(all_filesystems)
         .arg(autohide_time)
         .arg(basic)
         .arg(case_sensitive)
@@ -472,6 +502,7 @@ Displays the network widget with binary prefixes (i.e. kibibits, mebibits) rathe
         .arg(network_use_bytes)
         .arg(network_use_log)
         .arg(network_use_binary_prefix)
+        .arg(normalize_load_avg)
         .arg(current_usage)
         .arg(use_old_network_legend)
         .arg(whole_word);
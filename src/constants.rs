@@ -22,6 +22,52 @@ pub const MAX_KEY_TIMEOUT_IN_MILLISECONDS: u64 = 1000;
 pub const TABLE_GAP_HEIGHT_LIMIT: u16 = 7;
 pub const TIME_LABEL_HEIGHT_LIMIT: u16 = 7;
 
+// Column headers of the simpler tables; these are also what the tables can be sorted by
+pub const TEMP_HEADERS: [&str; 2] = ["Sensor", "Temp"];
+
+// The CPU time categories shown by the CPU widget's breakdown mode, in the order they're stacked
+pub const CPU_BREAKDOWN_CATEGORIES: usize = 5;
+pub const CPU_BREAKDOWN_HEADERS: [&str; CPU_BREAKDOWN_CATEGORIES] =
+    ["Usr", "Sys", "IOw", "IRQ", "Stl"];
+
+// The names of the lines in the CPU widget's load average mode
+pub const LOAD_AVG_GRAPH_NAMES: [&str; 3] = ["1m", "5m", "15m"];
+
+// Disk usage percentages (of either space or inodes) at which disk rows are highlighted
+pub const DISK_WARNING_USAGE: f64 = 80.0;
+pub const DISK_CRITICAL_USAGE: f64 = 95.0;
+
+// How long to keep disk usage samples for, used to estimate how quickly disks are filling up
+pub const DEFAULT_DISK_HISTORY_MILLISECONDS: u64 = 60 * 60 * 1000; // Defaults to 1 hour.
+pub const DISK_HISTORY_MIN_MILLISECONDS: u64 = 60 * 1000;
+pub const DISK_HISTORY_MAX_MILLISECONDS: u64 = 24 * 60 * 60 * 1000;
+
+// How many characters wide the disk usage sparkline is
+pub const DISK_HISTORY_SPARKLINE_WIDTH: usize = 10;
+
+// How many characters wide the process CPU and memory history sparklines are
+pub const PROCESS_HISTORY_SPARKLINE_WIDTH: usize = 10;
+
+// The number of cores per group when the CPU widget groups cores into buckets
+pub const DEFAULT_CPU_BUCKET_SIZE: usize = 8;
+
+// The widget types that the layout editor cycles through
+pub const LAYOUT_EDIT_WIDGET_TYPES: [&str; 10] = [
+    "cpu",
+    "mem",
+    "net",
+    "proc",
+    "temp",
+    "temp_graph",
+    "sensor",
+    "disk",
+    "battery",
+    "empty",
+];
+
+// Dragging a border scales ratios up to at least this, so that the border can move smoothly
+pub const MIN_LAYOUT_DRAG_RATIO: u32 = 20;
+
 // Side borders
 pub static SIDE_BORDERS: Lazy<tui::widgets::Borders> =
     Lazy::new(|| tui::widgets::Borders::from_bits_truncate(20));
@@ -104,6 +150,10 @@ pub static GRUVBOX_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColo
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#fabd2f".to_string()),
     low_battery_color: Some("#fb4934".to_string()),
+    disk_warning_color: Some("#fabd2f".to_string()),
+    disk_critical_color: Some("#fb4934".to_string()),
+    invalid_query_color: Some("#fb4934".to_string()),
+    ..ConfigColours::default()
 });
 
 pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -149,6 +199,10 @@ pub static GRUVBOX_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| Conf
     high_battery_color: Some("#98971a".to_string()),
     medium_battery_color: Some("#d79921".to_string()),
     low_battery_color: Some("#cc241d".to_string()),
+    disk_warning_color: Some("#d79921".to_string()),
+    disk_critical_color: Some("#cc241d".to_string()),
+    invalid_query_color: Some("#cc241d".to_string()),
+    ..ConfigColours::default()
 });
 
 pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -182,6 +236,10 @@ pub static NORD_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    disk_warning_color: Some("#ebcb8b".to_string()),
+    disk_critical_color: Some("#bf616a".to_string()),
+    invalid_query_color: Some("#bf616a".to_string()),
+    ..ConfigColours::default()
 });
 
 pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
@@ -215,10 +273,125 @@ pub static NORD_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigC
     high_battery_color: Some("#a3be8c".to_string()),
     medium_battery_color: Some("#ebcb8b".to_string()),
     low_battery_color: Some("#bf616a".to_string()),
+    disk_warning_color: Some("#ebcb8b".to_string()),
+    disk_critical_color: Some("#bf616a".to_string()),
+    invalid_query_color: Some("#bf616a".to_string()),
+    ..ConfigColours::default()
+});
+
+pub static DRACULA_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
+    table_header_color: Some("#bd93f9".to_string()),
+    all_cpu_color: Some("#50fa7b".to_string()),
+    avg_cpu_color: Some("#ff5555".to_string()),
+    cpu_core_colors: Some(vec![
+        "#ff79c6".to_string(),
+        "#8be9fd".to_string(),
+        "#50fa7b".to_string(),
+        "#ffb86c".to_string(),
+        "#bd93f9".to_string(),
+        "#f1fa8c".to_string(),
+        "#ff5555".to_string(),
+        "#6272a4".to_string(),
+    ]),
+    ram_color: Some("#8be9fd".to_string()),
+    swap_color: Some("#ffb86c".to_string()),
+    rx_color: Some("#8be9fd".to_string()),
+    tx_color: Some("#ffb86c".to_string()),
+    rx_total_color: Some("#6272a4".to_string()),
+    tx_total_color: Some("#ff79c6".to_string()),
+    border_color: Some("#6272a4".to_string()),
+    highlighted_border_color: Some("#bd93f9".to_string()),
+    disabled_text_color: Some("#44475a".to_string()),
+    text_color: Some("#f8f8f2".to_string()),
+    selected_text_color: Some("#282a36".to_string()),
+    selected_bg_color: Some("#bd93f9".to_string()),
+    widget_title_color: Some("#f8f8f2".to_string()),
+    graph_color: Some("#f8f8f2".to_string()),
+    high_battery_color: Some("#50fa7b".to_string()),
+    medium_battery_color: Some("#f1fa8c".to_string()),
+    low_battery_color: Some("#ff5555".to_string()),
+    disk_warning_color: Some("#f1fa8c".to_string()),
+    disk_critical_color: Some("#ff5555".to_string()),
+    invalid_query_color: Some("#ff5555".to_string()),
+    ..ConfigColours::default()
+});
+
+pub static SOLARIZED_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
+    table_header_color: Some("#268bd2".to_string()),
+    all_cpu_color: Some("#859900".to_string()),
+    avg_cpu_color: Some("#dc322f".to_string()),
+    cpu_core_colors: Some(vec![
+        "#b58900".to_string(),
+        "#cb4b16".to_string(),
+        "#dc322f".to_string(),
+        "#d33682".to_string(),
+        "#6c71c4".to_string(),
+        "#268bd2".to_string(),
+        "#2aa198".to_string(),
+        "#859900".to_string(),
+    ]),
+    ram_color: Some("#2aa198".to_string()),
+    swap_color: Some("#b58900".to_string()),
+    rx_color: Some("#2aa198".to_string()),
+    tx_color: Some("#b58900".to_string()),
+    rx_total_color: Some("#268bd2".to_string()),
+    tx_total_color: Some("#cb4b16".to_string()),
+    border_color: Some("#586e75".to_string()),
+    highlighted_border_color: Some("#268bd2".to_string()),
+    disabled_text_color: Some("#586e75".to_string()),
+    text_color: Some("#839496".to_string()),
+    selected_text_color: Some("#002b36".to_string()),
+    selected_bg_color: Some("#839496".to_string()),
+    widget_title_color: Some("#839496".to_string()),
+    graph_color: Some("#839496".to_string()),
+    high_battery_color: Some("#859900".to_string()),
+    medium_battery_color: Some("#b58900".to_string()),
+    low_battery_color: Some("#dc322f".to_string()),
+    disk_warning_color: Some("#b58900".to_string()),
+    disk_critical_color: Some("#dc322f".to_string()),
+    invalid_query_color: Some("#dc322f".to_string()),
+    ..ConfigColours::default()
+});
+
+pub static SOLARIZED_LIGHT_COLOUR_PALETTE: Lazy<ConfigColours> = Lazy::new(|| ConfigColours {
+    table_header_color: Some("#268bd2".to_string()),
+    all_cpu_color: Some("#859900".to_string()),
+    avg_cpu_color: Some("#dc322f".to_string()),
+    cpu_core_colors: Some(vec![
+        "#b58900".to_string(),
+        "#cb4b16".to_string(),
+        "#dc322f".to_string(),
+        "#d33682".to_string(),
+        "#6c71c4".to_string(),
+        "#268bd2".to_string(),
+        "#2aa198".to_string(),
+        "#859900".to_string(),
+    ]),
+    ram_color: Some("#2aa198".to_string()),
+    swap_color: Some("#b58900".to_string()),
+    rx_color: Some("#2aa198".to_string()),
+    tx_color: Some("#b58900".to_string()),
+    rx_total_color: Some("#268bd2".to_string()),
+    tx_total_color: Some("#cb4b16".to_string()),
+    border_color: Some("#93a1a1".to_string()),
+    highlighted_border_color: Some("#268bd2".to_string()),
+    disabled_text_color: Some("#93a1a1".to_string()),
+    text_color: Some("#657b83".to_string()),
+    selected_text_color: Some("#fdf6e3".to_string()),
+    selected_bg_color: Some("#657b83".to_string()),
+    widget_title_color: Some("#657b83".to_string()),
+    graph_color: Some("#657b83".to_string()),
+    high_battery_color: Some("#859900".to_string()),
+    medium_battery_color: Some("#b58900".to_string()),
+    low_battery_color: Some("#dc322f".to_string()),
+    disk_warning_color: Some("#b58900".to_string()),
+    disk_critical_color: Some("#dc322f".to_string()),
+    invalid_query_color: Some("#dc322f".to_string()),
+    ..ConfigColours::default()
 });
 
 // Help text
-pub const HELP_CONTENTS_TEXT: [&str; 8] = [
+pub const HELP_CONTENTS_TEXT: [&str; 10] = [
     "Press the corresponding numbers to jump to the section, or scroll:",
     "1 - General",
     "2 - CPU widget",
@@ -227,11 +400,13 @@ pub const HELP_CONTENTS_TEXT: [&str; 8] = [
     "5 - Process sort widget",
     "6 - Battery widget",
     "7 - Basic memory widget",
+    "8 - Temperature and disk widgets",
+    "9 - Temperature graph widget",
 ];
 
 // TODO [Help]: Search in help?
 // TODO [Help]: Move to using tables for easier formatting?
-pub const GENERAL_HELP_TEXT: [&str; 30] = [
+pub const GENERAL_HELP_TEXT: [&str; 33] = [
     "1 - General",
     "q, Ctrl-c        Quit",
     "Esc              Close dialog windows, search, widgets, or exit expanded mode",
@@ -257,16 +432,20 @@ pub const GENERAL_HELP_TEXT: [&str; 30] = [
     "gg               Jump to the first entry",
     "G                Jump to the last entry",
     "e                Toggle expanding the currently selected widget",
+    "E                Edit the layout",
     "+                Zoom in on chart (decrease time range)",
     "-                Zoom out on chart (increase time range)",
     "=                Reset zoom",
+    "[                Switch to the previous layout tab",
+    "]                Switch to the next layout tab",
     "Mouse scroll     Scroll through the tables or zoom in/out of charts by scrolling up/down",
     "Mouse click      Selects the clicked widget, table entry, dialog option, or tab",
 ];
 
-pub const CPU_HELP_TEXT: [&str; 2] = [
+pub const CPU_HELP_TEXT: [&str; 3] = [
     "2 - CPU widget\n",
     "Mouse scroll     Scrolling over an CPU core/average shows only that entry on the chart",
+    "m                Cycle the graph between usage, breakdown, frequency, load average, and heatmap",
 ];
 
 pub const PROCESS_HELP_TEXT: [&str; 15] = [
@@ -287,7 +466,7 @@ pub const PROCESS_HELP_TEXT: [&str; 15] = [
     "click on header  Sorts the entries by that column, click again to invert the sort",
 ];
 
-pub const SEARCH_HELP_TEXT: [&str; 48] = [
+pub const SEARCH_HELP_TEXT: [&str; 49] = [
     "4 - Process search widget",
     "Esc              Close the search widget (retains the filter)",
     "Ctrl-a           Skip to the start of the search query",
@@ -302,6 +481,7 @@ pub const SEARCH_HELP_TEXT: [&str; 48] = [
     "Alt-r, F3        Toggle using regex",
     "Left, Alt-h      Move cursor left",
     "Right, Alt-l     Move cursor right",
+    "Up, Down         Cycle through saved filters",
     "",
     "Supported search types:",
     "<by name/cmd>    ex: btm",
@@ -358,6 +538,26 @@ pub const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%                Toggle between values and percentages for memory usage",
 ];
 
+pub const TABLE_HELP_TEXT: [&str; 11] = [
+    "8 - Temperature and disk widgets",
+    "Ctrl-f, /        Open the search bar for the table",
+    "Esc              Close the search bar (retains the filter)",
+    "Ctrl-a, Ctrl-e   Skip to the start/end of the search query",
+    "Ctrl-u           Clear the current search query",
+    "Ctrl-w           Delete a word behind the cursor",
+    "Alt-c, F1        Toggle matching case",
+    "Alt-w, F2        Toggle matching the entire word",
+    "Alt-r, F3        Toggle using regex",
+    "s, F6            Sort by the next column, I to invert the current sort",
+    "click on header  Sorts the entries by that column, click again to invert the sort",
+];
+
+pub const TEMP_GRAPH_HELP_TEXT: [&str; 3] = [
+    "9 - Temperature graph widget",
+    "l                Toggle the legend",
+    "Mouse scroll     Scrolling over a sensor shows only that entry on the chart",
+];
+
 pub static HELP_TEXT: Lazy<Vec<Vec<&'static str>>> = Lazy::new(|| {
     vec![
         HELP_CONTENTS_TEXT.to_vec(),
@@ -368,6 +568,8 @@ pub static HELP_TEXT: Lazy<Vec<Vec<&'static str>>> = Lazy::new(|| {
         SORT_HELP_TEXT.to_vec(),
         BATTERY_HELP_TEXT.to_vec(),
         BASIC_MEM_HELP_TEXT.to_vec(),
+        TABLE_HELP_TEXT.to_vec(),
+        TEMP_GRAPH_HELP_TEXT.to_vec(),
     ]
 });
 
@@ -428,6 +630,8 @@ pub const DEFAULT_BATTERY_LAYOUT: &str = r##"
 
 // Config and flags
 pub const DEFAULT_CONFIG_FILE_PATH: &str = "bottom/bottom.toml";
+// The directory next to the config file that theme files are loaded from
+pub const THEMES_DIRECTORY: &str = "themes";
 
 // TODO: Eventually deprecate this.
 pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  All of the settings are commented
@@ -475,6 +679,8 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #default_widget_count = 1
 # Use basic mode
 #basic = false
+# The compact widgets shown along the top in basic mode, in order
+#basic_widgets = ["cpu", "mem", "net"]
 # Use the old network legend style
 #use_old_network_legend = false
 # Remove space in tables
@@ -483,7 +689,8 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #battery = false
 # Disable mouse clicks
 #disable_click = false
-# Built-in themes.  Valid values are "default", "default-light", "gruvbox", "gruvbox-light", "nord", "nord-light"
+# Built-in themes.  Valid values are "default", "default-light", "gruvbox", "gruvbox-light", "nord", "nord-light",
+# "dracula", "solarized", and "solarized-light".  Any other value loads that theme from the "themes" directory next to this file.
 #color = "default"
 # Show memory values in the processes widget as values by default
 #mem_as_value = false
@@ -501,6 +708,24 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #network_use_log = false
 # Hides advanced options to stop a process on Unix-like systems.
 #disable_advanced_kill = false
+# Divides the load average graph in the CPU widget by the number of cores.
+#normalize_load_avg = false
+# Groups cores in the CPU widget by "socket", "numa" node, or into buckets of "cpu_bucket_size" cores with "bucket".
+#cpu_grouping = "none"
+#cpu_bucket_size = 8
+# Extra columns shown in the CPU legend, from "freq", "min_freq", "max_freq", and "temp".
+#cpu_legend_columns = ["freq", "temp"]
+# The columns shown in the disk widget, in order.  Other columns are "type", "inodes", "inodes_free", "inodes_total",
+# "read_only", "options", "read_iops", "write_iops", "await", "util", "queue", "fill_rate", "eta_full", and "history".
+#disk_columns = ["disk", "mount", "used", "free", "total", "r/s", "w/s"]
+# Show network and virtual filesystems (i.e. NFS, tmpfs, overlay) in the disk widget.
+#all_filesystems = false
+# Only show network and virtual filesystems of these types in the disk widget.
+#include_filesystems = ["nfs4", "cifs"]
+# How long to keep disk usage samples for (in ms), used for the "fill_rate", "eta_full", and "history" disk columns.
+#disk_history_window = 3600000
+# Extra columns shown in the process widget, from "cpu_history", "mem_history", and "mem_growth".
+#process_history_columns = ["cpu_history", "mem_growth"]
 
 # These are all the components that support custom theming.  Note that colour support
 # will depend on terminal support.
@@ -534,10 +759,26 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #selected_bg_color="LightBlue"
 # Represents the colour of the lines and text of the graph.
 #graph_color="Gray"
+# Represents the colour of the graph axis labels, which defaults to the graph colour.
+#graph_label_color="Gray"
 # Represents the colours of the battery based on charge
 #high_battery_color="green"
 #medium_battery_color="yellow"
 #low_battery_color="red"
+# Represents the colours of disks that are running low on space or inodes
+#disk_warning_color="yellow"
+#disk_critical_color="red"
+# Represents the colour of search queries that are invalid.
+#invalid_query_color="red"
+# Represents the background colour of every other table row, which is unset by default.
+#alternate_row_color="#202020"
+# Represents the colours used to shade usage cells from low to high usage
+#gradient_colors=["green", "yellow", "red"]
+# Represents the colours and thresholds (in celsius) used to shade warm and hot temperature rows
+#temp_warm_color="yellow"
+#temp_hot_color="red"
+#temp_warm_threshold=70
+#temp_hot_threshold=85
 
 # Layout - layouts follow a pattern like this:
 # [[row]] represents a row in the application.
@@ -546,6 +787,14 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #
 # All widgets must have the type value set to one of ["cpu", "mem", "proc", "net", "temp", "disk", "empty"].
 # All layout components have a ratio value - if this is not set, then it defaults to 1. 
+# Instead of a ratio, a component can set length (in lines or columns), percentage, min_length, or max_length.
+# Graph widgets can set graph_style to one of ["braille", "dot", "block", "filled", "stacked"].
+# Widgets can also set title, default_time_value, left_legend, columns, default_sort, tree, group_processes,
+# network_use_bytes, and network_use_log to override the matching flags for just that widget.
+# Several named layouts can be defined with [[layout]] instead of [[row]], each with its own [[layout.row]] entries.
+# These are shown as tabs, which can be switched between with "[" and "]".
+# Layouts with the same name share a tab, which shows the first of them whose min_width and min_height fit the terminal.
+# Components can set a priority to be hidden when there isn't enough room, starting with the lowest priority.
 # The default widget layout:
 #[[row]]
 #  ratio=30
@@ -572,13 +821,16 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 
 
 # Filters - you can hide specific temperature sensors, network interfaces, and disks using filters.  This is admittedly
-# a bit hard to use as of now, and there is a planned in-app interface for managing this in the future:
+# a bit hard to use as of now, and there is a planned in-app interface for managing this in the future.
+# Setting "is_list_ignored" to false only keeps entries that match, and "field" changes what each entry is matched by
+# ("name" or "fs_type" for disks, "mount_point" or "options" for mounts, and "name" or "type" for networks):
 #[disk_filter]
 #is_list_ignored = true
 #list = ["/dev/sda\\d+", "/dev/nvme0n1p2"]
 #regex = true
 #case_sensitive = false
 #whole_word = false
+#field = "name"
 
 #[mount_filter]
 #is_list_ignored = true
@@ -586,6 +838,7 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #regex = true
 #case_sensitive = false
 #whole_word = false
+#field = "mount_point"
 
 #[temp_filter]
 #is_list_ignored = true
@@ -594,12 +847,28 @@ pub const CONFIG_TEXT: &str = r##"# This is a default config file for bottom.  A
 #case_sensitive = false
 #whole_word = false
 
+#[sensor_filter]
+#is_list_ignored = true
+#list = ["fan1"]
+#regex = false
+#case_sensitive = false
+#whole_word = false
+
 #[net_filter]
 #is_list_ignored = true
 #list = ["virbr0.*"]
 #regex = true
 #case_sensitive = false
 #whole_word = false
+#field = "name"
+
+# Saved process filters - these can be cycled through in the process search widget with the up and down keys,
+# or applied on startup by setting "default_filter" on a process widget in the layout.
+#[filters.high_cpu]
+#query = "cpu > 5"
+#regex = false
+#case_sensitive = false
+#whole_word = false
 "##;
 
 pub const CONFIG_TOP_HEAD: &str = r##"# This is bottom's config file.
@@ -631,6 +900,14 @@ pub const CONFIG_LAYOUT_HEAD: &str = r##"
 #
 # All widgets must have the valid type value set to one of ["cpu", "mem", "proc", "net", "temp", "disk", "empty"].
 # All layout components have a ratio value - if this is not set, then it defaults to 1. 
+# Instead of a ratio, a component can set length (in lines or columns), percentage, min_length, or max_length.
+# Graph widgets can set graph_style to one of ["braille", "dot", "block", "filled", "stacked"].
+# Widgets can also set title, default_time_value, left_legend, columns, default_sort, tree, group_processes,
+# network_use_bytes, and network_use_log to override the matching flags for just that widget.
+# Several named layouts can be defined with [[layout]] instead of [[row]], each with its own [[layout.row]] entries.
+# These are shown as tabs, which can be switched between with "[" and "]".
+# Layouts with the same name share a tab, which shows the first of them whose min_width and min_height fit the terminal.
+# Components can set a priority to be hidden when there isn't enough room, starting with the lowest priority.
 "##;
 
 pub const CONFIG_FILTER_HEAD: &str = r##"
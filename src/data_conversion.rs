@@ -2,13 +2,23 @@
 //! can actually handle.
 use crate::{app::AxisScaling, units::data_units::DataUnit, Pid};
 use crate::{
-    app::{data_farmer, data_harvester, App, ProcWidgetState},
+    app::{
+        data_farmer, data_harvester, App, CpuGrouping, DiskColumn, DiskWidgetState,
+        ProcWidgetState, TempWidgetState,
+    },
+    constants::{CPU_BREAKDOWN_CATEGORIES, DISK_HISTORY_SPARKLINE_WIDTH},
     utils::{self, gen_util::*},
 };
-use data_harvester::processes::ProcessSorting;
+use data_harvester::{
+    cpu::{CpuBreakdown, CpuFrequency},
+    processes::ProcessSorting,
+};
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Instant,
+};
 
 /// Point is of time, data
 type Point = (f64, f64);
@@ -23,6 +33,22 @@ pub struct ConvertedBatteryData {
     pub health: String,
 }
 
+#[derive(Default, Debug)]
+pub struct ConvertedDiskData {
+    pub row: Vec<String>,
+    /// The higher of the space and inode usage percentages, used to colour the row.
+    pub highest_usage: Option<f64>,
+    /// The usage percentage shown in each cell, if any, used to shade the cell.
+    pub cell_usage: Vec<Option<f64>>,
+}
+
+#[derive(Default, Debug)]
+pub struct ConvertedTempRow {
+    pub row: Vec<String>,
+    /// The temperature in Celsius, used to colour the row.
+    pub celsius: Option<f64>,
+}
+
 #[derive(Default, Debug)]
 pub struct ConvertedNetworkData {
     pub rx: Vec<Point>,
@@ -64,6 +90,14 @@ pub struct ConvertedProcessData {
     pub process_state: String,
     pub process_char: char,
     pub user: Option<String>,
+    /// Recent CPU usage, downsampled to the width of the history sparkline.
+    pub cpu_history: Vec<f64>,
+    /// Recent memory usage in bytes, downsampled to the width of the history sparkline.
+    pub mem_history: Vec<f64>,
+    pub cpu_history_average: f64,
+    pub mem_history_peak: u64,
+    /// Change in memory usage since the process was first seen, in bytes.
+    pub mem_growth_bytes: i64,
 
     /// Prefix printed before the process when displayed.
     pub process_description_prefix: Option<String>,
@@ -81,88 +115,556 @@ pub struct ConvertedCpuData {
     pub cpu_data: Vec<Point>,
     /// Represents the value displayed on the legend.
     pub legend_value: String,
+    /// The usage split into user, system, iowait, IRQ, and steal time, with each stacked on top
+    /// of the previous.  This is only filled in if a CPU widget is showing the breakdown.
+    pub stacked_breakdown_data: Vec<Vec<Point>>,
+    /// The breakdown values displayed on the legend, in the same order.
+    pub breakdown_legend_values: Vec<String>,
+    /// Tuple is time, frequency in MHz.  This is only filled in if a CPU widget is showing the
+    /// frequency.
+    pub frequency_data: Vec<Point>,
+    pub frequency: Option<CpuFrequency>,
+    pub temperature: Option<f32>,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ConvertedTempData {
+    pub sensor_name: String,
+    /// Tuple is time, value
+    pub temp_data: Vec<Point>,
+    /// Represents the value displayed on the legend.
+    pub legend_value: String,
+}
+
+/// Returns the suffix to display after a temperature value.
+pub fn get_temperature_unit(temp_type: &data_harvester::temperature::TemperatureType) -> &str {
+    match temp_type {
+        data_harvester::temperature::TemperatureType::Celsius => "°C",
+        data_harvester::temperature::TemperatureType::Kelvin => "K",
+        data_harvester::temperature::TemperatureType::Fahrenheit => "°F",
+    }
 }
 
-pub fn convert_temp_row(app: &App) -> Vec<Vec<String>> {
+/// Converts a temperature in the given unit back to Celsius, which the colour thresholds use.
+pub fn get_celsius(
+    temperature: f32, temp_type: &data_harvester::temperature::TemperatureType,
+) -> f64 {
+    let temperature = f64::from(temperature);
+    match temp_type {
+        data_harvester::temperature::TemperatureType::Celsius => temperature,
+        data_harvester::temperature::TemperatureType::Kelvin => temperature - 273.15,
+        data_harvester::temperature::TemperatureType::Fahrenheit => {
+            (temperature - 32.0) * (5.0 / 9.0)
+        }
+    }
+}
+
+pub fn convert_temp_row(app: &App, temp_widget_state: &TempWidgetState) -> Vec<ConvertedTempRow> {
     let current_data = &app.data_collection;
     let temp_type = &app.app_config_fields.temperature_type;
+    let search_state = &temp_widget_state.search_state;
+    let sort_state = &temp_widget_state.sort_state;
 
-    let mut sensor_vector: Vec<Vec<String>> = current_data
+    let mut temp_harvest = current_data
         .temp_harvest
         .iter()
-        .map(|temp_harvest| {
-            vec![
+        .filter(|temp_harvest| search_state.is_match(&[&temp_harvest.name]))
+        .collect::<Vec<_>>();
+
+    // Sort by name first, so sensors with the same temperature stay in a consistent order.
+    temp_harvest.sort_by(|a, b| {
+        get_ordering(
+            &a.name,
+            &b.name,
+            sort_state.sort_index == 0 && sort_state.is_sort_descending,
+        )
+    });
+    if sort_state.sort_index == 1 {
+        temp_harvest.sort_by(|a, b| {
+            get_ordering(a.temperature, b.temperature, sort_state.is_sort_descending)
+        });
+    }
+
+    let mut sensor_vector: Vec<ConvertedTempRow> = temp_harvest
+        .into_iter()
+        .map(|temp_harvest| ConvertedTempRow {
+            row: vec![
                 temp_harvest.name.clone(),
                 (temp_harvest.temperature.ceil() as u64).to_string()
-                    + match temp_type {
-                        data_harvester::temperature::TemperatureType::Celsius => "°C",
-                        data_harvester::temperature::TemperatureType::Kelvin => "K",
-                        data_harvester::temperature::TemperatureType::Fahrenheit => "°F",
-                    },
+                    + get_temperature_unit(temp_type),
+            ],
+            celsius: Some(get_celsius(temp_harvest.temperature, temp_type)),
+        })
+        .collect();
+
+    if sensor_vector.is_empty() {
+        sensor_vector.push(ConvertedTempRow {
+            row: vec!["No Sensors Found".to_string(), "".to_string()],
+            celsius: None,
+        });
+    }
+
+    sensor_vector
+}
+
+/// Converts the other hardware sensors into rows of chip, sensor, and value.  Readings are grouped
+/// by chip, with the chip name only shown on the first row of each group.
+pub fn convert_sensor_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
+    let mut previous_chip: Option<&str> = None;
+
+    let mut sensor_vector: Vec<Vec<String>> = current_data
+        .sensor_harvest
+        .iter()
+        .map(|sensor| {
+            let chip = if previous_chip == Some(sensor.chip.as_str()) {
+                String::new()
+            } else {
+                sensor.chip.clone()
+            };
+            previous_chip = Some(&sensor.chip);
+
+            let value = match sensor.sensor_type {
+                data_harvester::sensors::SensorType::Fan => format!("{:.0}", sensor.value),
+                data_harvester::sensors::SensorType::Power => format!("{:.1}", sensor.value),
+                _ => format!("{:.2}", sensor.value),
+            };
+
+            vec![
+                chip,
+                sensor.label.clone(),
+                format!("{} {}", value, sensor.sensor_type.get_unit()),
             ]
         })
         .collect();
 
     if sensor_vector.is_empty() {
-        sensor_vector.push(vec!["No Sensors Found".to_string(), "".to_string()]);
+        sensor_vector.push(vec![
+            "No Sensors Found".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]);
     }
 
     sensor_vector
 }
 
-pub fn convert_disk_row(current_data: &data_farmer::DataCollection) -> Vec<Vec<String>> {
-    let mut disk_vector: Vec<Vec<String>> = Vec::new();
+pub fn convert_disk_row(
+    current_data: &data_farmer::DataCollection, disk_widget_state: &DiskWidgetState,
+) -> Vec<ConvertedDiskData> {
+    let search_state = &disk_widget_state.search_state;
+    let sort_state = &disk_widget_state.sort_state;
 
-    current_data
+    let mut disks = current_data
         .disk_harvest
         .iter()
         .zip(&current_data.io_labels)
-        .for_each(|(disk, (io_read, io_write))| {
-            let free_space_fmt = if let Some(free_space) = disk.free_space {
-                let converted_free_space = get_decimal_bytes(free_space);
-                format!("{:.*}{}", 0, converted_free_space.0, converted_free_space.1)
-            } else {
-                "N/A".to_string()
-            };
-            let total_space_fmt = if let Some(total_space) = disk.total_space {
-                let converted_total_space = get_decimal_bytes(total_space);
-                format!(
-                    "{:.*}{}",
-                    0, converted_total_space.0, converted_total_space.1
+        .enumerate()
+        .filter(|(_itx, (disk, _io_labels))| {
+            search_state.is_match(&[&disk.name, &disk.mount_point])
+        })
+        .map(|(itx, (disk, io_labels))| {
+            let io_rates = current_data
+                .io_labels_and_prev
+                .get(itx)
+                .map(|(io_curr, _io_prev)| *io_curr)
+                .unwrap_or((0, 0));
+            let io_stats = current_data
+                .io_stats_and_prev
+                .get(itx)
+                .map(|(io_stats, _io_prev)| *io_stats)
+                .unwrap_or_default();
+            let usage_history = current_data.disk_usage_history.get(&disk.mount_point);
+            let fill_rate = usage_history.and_then(get_fill_rate);
+            (
+                disk,
+                io_labels,
+                io_rates,
+                io_stats,
+                usage_history,
+                fill_rate,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let is_descending = sort_state.is_sort_descending;
+    disks.sort_by(
+        |(a, _, a_io, a_stats, _, a_fill), (b, _, b_io, b_stats, _, b_fill)| match disk_widget_state
+            .get_sort_column()
+        {
+            DiskColumn::Disk => get_ordering(&a.name, &b.name, is_descending),
+            DiskColumn::Mount => get_ordering(&a.mount_point, &b.mount_point, is_descending),
+            DiskColumn::FsType => get_ordering(&a.fs_type, &b.fs_type, is_descending),
+            DiskColumn::Used => get_ordering(
+                get_disk_usage(a.used_space, a.total_space),
+                get_disk_usage(b.used_space, b.total_space),
+                is_descending,
+            ),
+            DiskColumn::Free => get_ordering(a.free_space, b.free_space, is_descending),
+            DiskColumn::Total => get_ordering(a.total_space, b.total_space, is_descending),
+            DiskColumn::InodesUsed => get_ordering(
+                get_disk_usage(a.used_inodes, a.total_inodes),
+                get_disk_usage(b.used_inodes, b.total_inodes),
+                is_descending,
+            ),
+            DiskColumn::InodesFree => get_ordering(a.free_inodes, b.free_inodes, is_descending),
+            DiskColumn::InodesTotal => get_ordering(a.total_inodes, b.total_inodes, is_descending),
+            DiskColumn::ReadOnly => get_ordering(a.is_read_only, b.is_read_only, is_descending),
+            DiskColumn::Options => get_ordering(&a.mount_options, &b.mount_options, is_descending),
+            DiskColumn::ReadPerSec => get_ordering(a_io.0, b_io.0, is_descending),
+            DiskColumn::WritePerSec => get_ordering(a_io.1, b_io.1, is_descending),
+            DiskColumn::ReadIops => {
+                get_ordering(a_stats.read_iops, b_stats.read_iops, is_descending)
+            }
+            DiskColumn::WriteIops => {
+                get_ordering(a_stats.write_iops, b_stats.write_iops, is_descending)
+            }
+            DiskColumn::Await => get_ordering(a_stats.await_ms, b_stats.await_ms, is_descending),
+            DiskColumn::Util => {
+                get_ordering(a_stats.util_percent, b_stats.util_percent, is_descending)
+            }
+            DiskColumn::Queue => {
+                get_ordering(a_stats.queue_size, b_stats.queue_size, is_descending)
+            }
+            DiskColumn::FillRate | DiskColumn::History => {
+                get_ordering(a_fill, b_fill, is_descending)
+            }
+            DiskColumn::EtaFull => get_ordering(
+                get_secs_until_full(a.free_space, *a_fill),
+                get_secs_until_full(b.free_space, *b_fill),
+                is_descending,
+            ),
+        },
+    );
+
+    let mut disk_vector: Vec<ConvertedDiskData> = disks
+        .into_iter()
+        .map(
+            |(disk, (io_read, io_write), _io_rates, io_stats, usage_history, fill_rate)| {
+                let space_usage = get_disk_usage(disk.used_space, disk.total_space);
+                let inode_usage = get_disk_usage(disk.used_inodes, disk.total_inodes);
+
+                let cell_usage = disk_widget_state
+                    .columns
+                    .iter()
+                    .map(|column| match column {
+                        DiskColumn::Used => space_usage,
+                        DiskColumn::InodesUsed => inode_usage,
+                        DiskColumn::Util => io_stats.util_percent,
+                        _ => None,
+                    })
+                    .collect();
+
+                let row = disk_widget_state
+                    .columns
+                    .iter()
+                    .map(|column| match column {
+                        DiskColumn::Disk => disk.name.to_string(),
+                        DiskColumn::Mount => disk.mount_point.to_string(),
+                        DiskColumn::FsType => disk.fs_type.to_string(),
+                        DiskColumn::Used => get_usage_string(space_usage),
+                        DiskColumn::Free => get_space_string(disk.free_space),
+                        DiskColumn::Total => get_space_string(disk.total_space),
+                        DiskColumn::InodesUsed => get_usage_string(inode_usage),
+                        DiskColumn::InodesFree => get_inode_string(disk.free_inodes),
+                        DiskColumn::InodesTotal => get_inode_string(disk.total_inodes),
+                        DiskColumn::ReadOnly => match disk.is_read_only {
+                            Some(true) => "Yes".to_string(),
+                            Some(false) => "No".to_string(),
+                            None => "N/A".to_string(),
+                        },
+                        DiskColumn::Options => disk.mount_options.to_string(),
+                        DiskColumn::ReadPerSec => io_read.to_string(),
+                        DiskColumn::WritePerSec => io_write.to_string(),
+                        DiskColumn::ReadIops => get_io_stat_string(io_stats.read_iops, 0, ""),
+                        DiskColumn::WriteIops => get_io_stat_string(io_stats.write_iops, 0, ""),
+                        DiskColumn::Await => get_io_stat_string(io_stats.await_ms, 1, "ms"),
+                        DiskColumn::Util => get_io_stat_string(io_stats.util_percent, 0, "%"),
+                        DiskColumn::Queue => get_io_stat_string(io_stats.queue_size, 2, ""),
+                        DiskColumn::FillRate => get_fill_rate_string(fill_rate),
+                        DiskColumn::EtaFull => {
+                            get_eta_string(get_secs_until_full(disk.free_space, fill_rate))
+                        }
+                        DiskColumn::History => usage_history
+                            .map(get_sparkline)
+                            .unwrap_or_else(|| "N/A".to_string()),
+                    })
+                    .collect();
+
+                ConvertedDiskData {
+                    row,
+                    cell_usage,
+                    highest_usage: match (space_usage, inode_usage) {
+                        (Some(space_usage), Some(inode_usage)) => {
+                            Some(space_usage.max(inode_usage))
+                        }
+                        (space_usage, inode_usage) => space_usage.or(inode_usage),
+                    },
+                }
+            },
+        )
+        .collect();
+
+    if disk_vector.is_empty() {
+        disk_vector.push(ConvertedDiskData {
+            row: vec!["No Disks Found".to_string(), "".to_string()],
+            highest_usage: None,
+            cell_usage: Vec::new(),
+        });
+    }
+
+    disk_vector
+}
+
+fn get_usage_string(usage: Option<f64>) -> String {
+    if let Some(usage) = usage {
+        format!("{:.0}%", usage)
+    } else {
+        "N/A".to_string()
+    }
+}
+
+fn get_space_string(space: Option<u64>) -> String {
+    if let Some(space) = space {
+        let converted_space = get_decimal_bytes(space);
+        format!("{:.*}{}", 0, converted_space.0, converted_space.1)
+    } else {
+        "N/A".to_string()
+    }
+}
+
+fn get_io_stat_string(value: Option<f64>, precision: usize, unit: &str) -> String {
+    if let Some(value) = value {
+        format!("{:.*}{}", precision, value, unit)
+    } else {
+        "N/A".to_string()
+    }
+}
+
+/// Estimates how quickly a disk is filling up, in bytes per second, from a least-squares
+/// linear fit of its used space samples.  Returns `None` if there aren't enough samples yet.
+fn get_fill_rate(samples: &VecDeque<(Instant, u64)>) -> Option<f64> {
+    let (first_instant, _) = samples.front()?;
+    if samples.len() < 2 {
+        return None;
+    }
+
+    let points = samples
+        .iter()
+        .map(|(instant, used)| {
+            (
+                instant.duration_since(*first_instant).as_secs_f64(),
+                *used as f64,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let num_points = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / num_points;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / num_points;
+
+    let (covariance, variance) =
+        points
+            .iter()
+            .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                (
+                    covariance + (x - mean_x) * (y - mean_y),
+                    variance + (x - mean_x) * (x - mean_x),
                 )
+            });
+
+    if variance > 0.0 {
+        Some(covariance / variance)
+    } else {
+        None
+    }
+}
+
+/// Returns how many seconds are left until a disk is full at its current fill rate.  A disk
+/// that isn't filling up will never be full, which is represented by infinity.
+fn get_secs_until_full(free_space: Option<u64>, fill_rate: Option<f64>) -> Option<f64> {
+    match (free_space, fill_rate) {
+        (Some(free_space), Some(fill_rate)) => {
+            if fill_rate > 0.0 {
+                Some(free_space as f64 / fill_rate)
             } else {
-                "N/A".to_string()
-            };
+                Some(f64::INFINITY)
+            }
+        }
+        _ => None,
+    }
+}
 
-            let usage_fmt = if let (Some(used_space), Some(total_space)) =
-                (disk.used_space, disk.total_space)
-            {
-                format!("{:.0}%", used_space as f64 / total_space as f64 * 100_f64)
+fn get_fill_rate_string(fill_rate: Option<f64>) -> String {
+    if let Some(fill_rate) = fill_rate {
+        let rate_per_hour = fill_rate * 3600.0;
+        let converted_rate = get_decimal_bytes(rate_per_hour.abs().round() as u64);
+        format!(
+            "{}{:.1}{}/h",
+            if rate_per_hour <= -0.5 { "-" } else { "" },
+            converted_rate.0,
+            converted_rate.1
+        )
+    } else {
+        "N/A".to_string()
+    }
+}
+
+fn get_eta_string(secs_until_full: Option<f64>) -> String {
+    if let Some(secs_until_full) = secs_until_full {
+        if secs_until_full.is_infinite() {
+            return "Never".to_string();
+        }
+
+        let secs_until_full = secs_until_full as u64;
+        let (days, hours, minutes) = (
+            secs_until_full / 86400,
+            (secs_until_full % 86400) / 3600,
+            (secs_until_full % 3600) / 60,
+        );
+
+        if days > 999 {
+            ">999d".to_string()
+        } else if days > 0 {
+            format!("{}d {}h", days, hours)
+        } else if hours > 0 {
+            format!("{}h {}m", hours, minutes)
+        } else if minutes > 0 {
+            format!("{}m", minutes)
+        } else {
+            "<1m".to_string()
+        }
+    } else {
+        "N/A".to_string()
+    }
+}
+
+const SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Takes the last sample of each of `width` evenly sized buckets, or every sample if there are
+/// no more than `width` of them.
+fn sample_evenly<T: Copy>(samples: &VecDeque<T>, width: usize) -> Vec<T> {
+    let num_samples = samples.len();
+    if num_samples <= width {
+        samples.iter().copied().collect()
+    } else {
+        (1..=width)
+            .map(|bucket| samples[bucket * num_samples / width - 1])
+            .collect()
+    }
+}
+
+/// Draws the values as a sparkline, scaled between `min` and `max`.
+fn draw_sparkline(values: &[f64], min: f64, max: f64) -> String {
+    values
+        .iter()
+        .map(|value| {
+            if max > min {
+                let ratio = ((value - min) / (max - min)).clamp(0.0, 1.0);
+                SPARKLINE_BARS[(ratio * (SPARKLINE_BARS.len() - 1) as f64).round() as usize]
             } else {
-                "N/A".to_string()
-            };
+                SPARKLINE_BARS[0]
+            }
+        })
+        .collect()
+}
 
-            disk_vector.push(vec![
-                disk.name.to_string(),
-                disk.mount_point.to_string(),
-                usage_fmt,
-                free_space_fmt,
-                total_space_fmt,
-                io_read.to_string(),
-                io_write.to_string(),
-            ]);
-        });
+/// Draws the used space samples as a sparkline, scaled between the lowest and highest samples.
+fn get_sparkline(samples: &VecDeque<(Instant, u64)>) -> String {
+    let values = sample_evenly(samples, DISK_HISTORY_SPARKLINE_WIDTH)
+        .into_iter()
+        .map(|(_, used)| used)
+        .collect::<Vec<_>>();
+
+    let min = values.iter().min().copied().unwrap_or(0);
+    let max = values.iter().max().copied().unwrap_or(0);
+
+    draw_sparkline(
+        &values
+            .into_iter()
+            .map(|used| used as f64)
+            .collect::<Vec<_>>(),
+        min as f64,
+        max as f64,
+    )
+}
 
-    if disk_vector.is_empty() {
-        disk_vector.push(vec!["No Disks Found".to_string(), "".to_string()]);
+fn get_inode_string(inodes: Option<u64>) -> String {
+    if let Some(inodes) = inodes {
+        let converted_inodes = get_decimal_prefix(inodes, "");
+        if converted_inodes.1.is_empty() {
+            format!("{}", inodes)
+        } else {
+            format!("{:.1}{}", converted_inodes.0, converted_inodes.1)
+        }
+    } else {
+        "N/A".to_string()
     }
+}
 
-    disk_vector
+/// Returns the used percentage of a disk's space or inodes, if both the used and total amounts
+/// are known.
+fn get_disk_usage(used: Option<u64>, total: Option<u64>) -> Option<f64> {
+    match (used, total) {
+        (Some(used), Some(total)) if total > 0 => Some(used as f64 / total as f64 * 100_f64),
+        _ => None,
+    }
+}
+
+/// Returns the cumulative sums of the breakdown's categories, which are plotted as stacked lines.
+fn get_stacked_breakdown(breakdown: &CpuBreakdown) -> [f64; CPU_BREAKDOWN_CATEGORIES] {
+    let user = breakdown.user;
+    let system = user + breakdown.system;
+    let iowait = system + breakdown.iowait;
+    let irq = iowait + breakdown.irq;
+    let steal = irq + breakdown.steal;
+
+    [user, system, iowait, irq, steal]
+}
+
+/// Averages the breakdowns of the individual cores, skipping the average CPU entry if there is one.
+fn get_average_breakdown(
+    current_data: &data_farmer::DataCollection, breakdowns: &[CpuBreakdown],
+) -> CpuBreakdown {
+    let core_breakdowns = breakdowns
+        .iter()
+        .enumerate()
+        .filter(|(itx, _)| {
+            current_data
+                .cpu_harvest
+                .get(*itx)
+                .map(|cpu_harvest| cpu_harvest.cpu_count.is_some())
+                .unwrap_or(true)
+        })
+        .map(|(_, breakdown)| breakdown)
+        .collect::<Vec<_>>();
+
+    average_breakdowns(&core_breakdowns)
+}
+
+fn average_breakdowns(breakdowns: &[&CpuBreakdown]) -> CpuBreakdown {
+    let num_cores = breakdowns.len().max(1) as f64;
+    let mut average = breakdowns
+        .iter()
+        .fold(CpuBreakdown::default(), |mut average, breakdown| {
+            average.user += breakdown.user;
+            average.system += breakdown.system;
+            average.iowait += breakdown.iowait;
+            average.irq += breakdown.irq;
+            average.steal += breakdown.steal;
+            average
+        });
+    average.user /= num_cores;
+    average.system /= num_cores;
+    average.iowait /= num_cores;
+    average.irq /= num_cores;
+    average.steal /= num_cores;
+
+    average
 }
 
 pub fn convert_cpu_data_points(
     current_data: &data_farmer::DataCollection, existing_cpu_data: &mut Vec<ConvertedCpuData>,
-    is_frozen: bool,
+    is_frozen: bool, include_breakdown: bool, include_frequency: bool, grouping: CpuGrouping,
 ) {
     let current_time = if is_frozen {
         if let Some(frozen_instant) = current_data.frozen_instant {
@@ -182,6 +684,11 @@ pub fn convert_cpu_data_points(
                 short_cpu_name: "All".to_string(),
                 cpu_data: vec![],
                 legend_value: String::new(),
+                stacked_breakdown_data: vec![],
+                breakdown_legend_values: vec![],
+                frequency_data: vec![],
+                frequency: None,
+                temperature: None,
             }];
 
             existing_cpu_data.extend(
@@ -210,6 +717,11 @@ pub fn convert_cpu_data_points(
                         },
                         legend_value: format!("{:.0}%", cpu_usage.round()),
                         cpu_data: vec![],
+                        stacked_breakdown_data: vec![],
+                        breakdown_legend_values: vec![],
+                        frequency_data: vec![],
+                        frequency: None,
+                        temperature: None,
                     })
                     .collect::<Vec<ConvertedCpuData>>(),
             );
@@ -223,6 +735,35 @@ pub fn convert_cpu_data_points(
                     cpu.legend_value = format!("{:.0}%", cpu_usage.round());
                 });
         }
+
+        existing_cpu_data
+            .iter_mut()
+            .skip(1)
+            .zip(&current_data.cpu_harvest)
+            .for_each(|(cpu, cpu_harvest)| {
+                cpu.frequency_data = vec![];
+                cpu.frequency = cpu_harvest.frequency;
+                cpu.temperature = cpu_harvest.temperature;
+            });
+
+        // The "All" entry shows the average breakdown of the cores.
+        let average_breakdown = get_average_breakdown(current_data, &data.cpu_breakdown_data);
+        existing_cpu_data
+            .iter_mut()
+            .zip(std::iter::once(&average_breakdown).chain(&data.cpu_breakdown_data))
+            .for_each(|(cpu, breakdown)| {
+                cpu.stacked_breakdown_data = vec![vec![]; CPU_BREAKDOWN_CATEGORIES];
+                cpu.breakdown_legend_values = [
+                    breakdown.user,
+                    breakdown.system,
+                    breakdown.iowait,
+                    breakdown.irq,
+                    breakdown.steal,
+                ]
+                .iter()
+                .map(|value| format!("{:.0}%", value.round()))
+                .collect();
+            });
     }
 
     for (time, data) in &current_data.timed_data_vec {
@@ -234,10 +775,340 @@ pub fn convert_cpu_data_points(
             }
         }
 
+        if include_frequency {
+            for (itx, frequency) in data.cpu_frequency_data.iter().enumerate() {
+                if let (Some(cpu_data), Some(frequency)) =
+                    (existing_cpu_data.get_mut(itx + 1), frequency)
+                {
+                    cpu_data.frequency_data.push((-time_from_start, *frequency));
+                }
+            }
+        }
+
+        if include_breakdown {
+            let average_breakdown = get_average_breakdown(current_data, &data.cpu_breakdown_data);
+            existing_cpu_data
+                .iter_mut()
+                .zip(std::iter::once(&average_breakdown).chain(&data.cpu_breakdown_data))
+                .for_each(|(cpu, breakdown)| {
+                    for (stacked_data, value) in cpu
+                        .stacked_breakdown_data
+                        .iter_mut()
+                        .zip(&get_stacked_breakdown(breakdown))
+                    {
+                        stacked_data.push((-time_from_start, *value));
+                    }
+                });
+        }
+
         if *time == current_time {
             break;
         }
     }
+
+    if grouping != CpuGrouping::None {
+        group_cpu_data(current_data, existing_cpu_data, grouping);
+    }
+}
+
+/// Replaces the rows of the individual cores with one row per group, showing the average of the
+/// cores in that group.  The "All" and average CPU rows are kept as they are.
+fn group_cpu_data(
+    current_data: &data_farmer::DataCollection, existing_cpu_data: &mut Vec<ConvertedCpuData>,
+    grouping: CpuGrouping,
+) {
+    let num_cores = current_data
+        .cpu_harvest
+        .iter()
+        .filter(|cpu| cpu.cpu_count.is_some())
+        .count();
+
+    // Each group is its name, short name, and the indices of its cores in the harvest.
+    let mut groups: Vec<(String, String, Vec<usize>)> = Vec::new();
+    for (itx, cpu_harvest) in current_data.cpu_harvest.iter().enumerate() {
+        let cpu_count = match cpu_harvest.cpu_count {
+            Some(cpu_count) => cpu_count,
+            None => continue,
+        };
+
+        let (name, short_name) = match grouping {
+            CpuGrouping::None => return,
+            CpuGrouping::Socket => match cpu_harvest.package_id {
+                Some(package_id) => (format!("Sock{}", package_id), format!("S{}", package_id)),
+                None => ("Other".to_string(), "?".to_string()),
+            },
+            CpuGrouping::NumaNode => match cpu_harvest.numa_node {
+                Some(numa_node) => (format!("Node{}", numa_node), format!("N{}", numa_node)),
+                None => ("Other".to_string(), "?".to_string()),
+            },
+            CpuGrouping::Bucket(bucket_size) => {
+                let start = cpu_count / bucket_size * bucket_size;
+                let end = (start + bucket_size).min(num_cores.max(start + 1)) - 1;
+                if start == end {
+                    (format!("CPU{}", start), start.to_string())
+                } else {
+                    (
+                        format!("CPU{}-{}", start, end),
+                        format!("{}-{}", start, end),
+                    )
+                }
+            }
+        };
+
+        if let Some((_, _, members)) = groups
+            .iter_mut()
+            .find(|(group_name, _, _)| *group_name == name)
+        {
+            members.push(itx);
+        } else {
+            groups.push((name, short_name, vec![itx]));
+        }
+    }
+
+    let latest_data = current_data.timed_data_vec.last().map(|(_time, data)| data);
+    let mut rows = std::mem::take(existing_cpu_data)
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+
+    // Keep "All", then the average CPU rows, as they are.
+    existing_cpu_data.extend(rows.get_mut(0).and_then(Option::take));
+    for (itx, cpu_harvest) in current_data.cpu_harvest.iter().enumerate() {
+        if cpu_harvest.cpu_count.is_none() {
+            existing_cpu_data.extend(rows.get_mut(itx + 1).and_then(Option::take));
+        }
+    }
+
+    for (name, short_name, members) in groups {
+        let member_rows = members
+            .iter()
+            .filter_map(|itx| rows.get(itx + 1).and_then(Option::as_ref))
+            .collect::<Vec<_>>();
+        let num_members = member_rows.len().max(1) as f64;
+
+        let average_usage = latest_data
+            .map(|data| {
+                members
+                    .iter()
+                    .filter_map(|itx| data.cpu_data.get(*itx))
+                    .sum::<f64>()
+                    / num_members
+            })
+            .unwrap_or(0.0);
+        let average_breakdown = latest_data
+            .map(|data| {
+                let member_breakdowns = members
+                    .iter()
+                    .filter_map(|itx| data.cpu_breakdown_data.get(*itx))
+                    .collect::<Vec<_>>();
+                average_breakdowns(&member_breakdowns)
+            })
+            .unwrap_or_default();
+
+        let temperatures = member_rows
+            .iter()
+            .filter_map(|cpu| cpu.temperature)
+            .collect::<Vec<_>>();
+        let frequencies = member_rows
+            .iter()
+            .filter_map(|cpu| cpu.frequency)
+            .collect::<Vec<_>>();
+
+        existing_cpu_data.push(ConvertedCpuData {
+            cpu_name: name,
+            short_cpu_name: short_name,
+            cpu_data: get_average_points(member_rows.iter().map(|cpu| &cpu.cpu_data)),
+            legend_value: format!("{:.0}%", average_usage.round()),
+            stacked_breakdown_data: (0..CPU_BREAKDOWN_CATEGORIES)
+                .map(|category| {
+                    get_average_points(
+                        member_rows
+                            .iter()
+                            .filter_map(|cpu| cpu.stacked_breakdown_data.get(category)),
+                    )
+                })
+                .collect(),
+            breakdown_legend_values: [
+                average_breakdown.user,
+                average_breakdown.system,
+                average_breakdown.iowait,
+                average_breakdown.irq,
+                average_breakdown.steal,
+            ]
+            .iter()
+            .map(|value| format!("{:.0}%", value.round()))
+            .collect(),
+            frequency_data: get_average_points(member_rows.iter().map(|cpu| &cpu.frequency_data)),
+            frequency: if frequencies.is_empty() {
+                None
+            } else {
+                Some(CpuFrequency {
+                    current: frequencies
+                        .iter()
+                        .map(|frequency| frequency.current)
+                        .sum::<f64>()
+                        / frequencies.len() as f64,
+                    min: frequencies
+                        .iter()
+                        .filter_map(|frequency| frequency.min)
+                        .fold(None, |lowest, min| {
+                            Some(lowest.map_or(min, |lowest: f64| lowest.min(min)))
+                        }),
+                    max: frequencies
+                        .iter()
+                        .filter_map(|frequency| frequency.max)
+                        .fold(None, |highest, max| {
+                            Some(highest.map_or(max, |highest: f64| highest.max(max)))
+                        }),
+                })
+            },
+            temperature: if temperatures.is_empty() {
+                None
+            } else {
+                Some(temperatures.iter().sum::<f32>() / temperatures.len() as f32)
+            },
+        });
+    }
+}
+
+/// Averages several series of points that were taken at the same times.  The result is as long as
+/// the shortest series.
+fn get_average_points<'a>(series: impl Iterator<Item = &'a Vec<Point>>) -> Vec<Point> {
+    let series = series.collect::<Vec<_>>();
+    let length = series.iter().map(|points| points.len()).min().unwrap_or(0);
+
+    (0..length)
+        .map(|itx| {
+            let time = series[0][itx].0;
+            let total = series.iter().map(|points| points[itx].1).sum::<f64>();
+            (time, total / series.len() as f64)
+        })
+        .collect()
+}
+
+/// Returns the core number and latest usage of each core, skipping the average CPU entry.
+pub fn convert_cpu_heatmap_data(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<(usize, f64)> {
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    current_data
+        .timed_data_vec
+        .iter()
+        .rev()
+        .find(|(time, _data)| *time <= current_time)
+        .map(|(_time, data)| {
+            data.cpu_data
+                .iter()
+                .zip(&current_data.cpu_harvest)
+                .filter_map(|(cpu_usage, cpu_harvest)| {
+                    cpu_harvest
+                        .cpu_count
+                        .map(|cpu_count| (cpu_count, *cpu_usage))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn convert_temp_data_points(
+    current_data: &data_farmer::DataCollection, existing_temp_data: &mut Vec<ConvertedTempData>,
+    temp_type: &data_harvester::temperature::TemperatureType, is_frozen: bool,
+) {
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    // Sensors may come and go, so unlike the CPU we always rebuild the names from the latest harvest.
+    *existing_temp_data = vec![ConvertedTempData {
+        sensor_name: "All".to_string(),
+        temp_data: vec![],
+        legend_value: String::new(),
+    }];
+
+    existing_temp_data.extend(current_data.temp_harvest.iter().map(|temp_harvest| {
+        ConvertedTempData {
+            sensor_name: temp_harvest.name.clone(),
+            temp_data: vec![],
+            legend_value: format!(
+                "{:.0}{}",
+                temp_harvest.temperature.ceil(),
+                get_temperature_unit(temp_type)
+            ),
+        }
+    }));
+
+    // The history is looked up by name and occurrence, so a sensor keeps its own points even if
+    // the order of the sensors changes.
+    let history_keys = data_farmer::get_temp_history_keys(&current_data.temp_harvest);
+    for (temp_data, key) in existing_temp_data.iter_mut().skip(1).zip(&history_keys) {
+        if let Some(samples) = current_data.temp_history.get(key) {
+            temp_data.temp_data = samples
+                .iter()
+                .take_while(|(time, _temp)| *time <= current_time)
+                .map(|(time, temp)| {
+                    let time_from_start: f64 =
+                        (current_time.duration_since(*time).as_millis() as f64).floor();
+                    (-time_from_start, *temp)
+                })
+                .collect();
+        }
+    }
+}
+
+/// Returns the 1, 5, and 15 minute load averages over time.  If `normalize` is set, these are
+/// divided by the number of cores.
+pub fn convert_load_avg_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool, normalize: bool,
+) -> [Vec<Point>; 3] {
+    let mut result: [Vec<Point>; 3] = Default::default();
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    let divisor = if normalize {
+        current_data
+            .cpu_harvest
+            .iter()
+            .filter(|cpu| cpu.cpu_count.is_some())
+            .count()
+            .max(1) as f64
+    } else {
+        1.0
+    };
+
+    for (time, data) in &current_data.timed_data_vec {
+        let time_from_start: f64 = (current_time.duration_since(*time).as_millis() as f64).floor();
+        for (points, load_avg) in result.iter_mut().zip(data.load_avg_data.iter()) {
+            points.push((-time_from_start, f64::from(*load_avg) / divisor));
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
 }
 
 pub fn convert_mem_data_points(
@@ -268,6 +1139,77 @@ pub fn convert_mem_data_points(
     result
 }
 
+/// Returns the memory usage of each NUMA node over time.  This is empty unless there is more than
+/// one node, as a single node would just repeat the overall memory usage.
+pub fn convert_numa_data_points(
+    current_data: &data_farmer::DataCollection, is_frozen: bool,
+) -> Vec<Vec<Point>> {
+    let num_nodes = current_data.numa_stats_and_prev.len();
+    if num_nodes < 2 {
+        return Vec::new();
+    }
+
+    let mut result: Vec<Vec<Point>> = vec![Vec::new(); num_nodes];
+    let current_time = if is_frozen {
+        if let Some(frozen_instant) = current_data.frozen_instant {
+            frozen_instant
+        } else {
+            current_data.current_instant
+        }
+    } else {
+        current_data.current_instant
+    };
+
+    for (time, data) in &current_data.timed_data_vec {
+        // Skip any entries from before a node appeared or disappeared.
+        if data.numa_mem_data.len() == num_nodes {
+            let time_from_start: f64 =
+                (current_time.duration_since(*time).as_millis() as f64).floor();
+            for (points, use_percent) in result.iter_mut().zip(&data.numa_mem_data) {
+                points.push((-time_from_start, *use_percent));
+            }
+        }
+
+        if *time == current_time {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Returns the legend label of each NUMA node, in the same order as [`convert_numa_data_points`].
+pub fn convert_numa_labels(current_data: &data_farmer::DataCollection) -> Vec<String> {
+    if current_data.numa_stats_and_prev.len() < 2 {
+        return Vec::new();
+    }
+
+    let get_rate_string = |rate: Option<f64>| match rate {
+        Some(rate) => format!("{:.0}", rate),
+        None => "N/A".to_string(),
+    };
+
+    current_data
+        .numa_stats_and_prev
+        .iter()
+        .map(|(stats, _prev)| {
+            let (unit, denominator) =
+                return_unit_and_denominator_for_mem_kib(stats.mem_total_in_kib);
+            format!(
+                "{:<4}{:3.0}%   {:.1}{}/{:.1}{}   miss {}/s   foreign {}/s",
+                format!("N{}:", stats.node),
+                stats.use_percent.unwrap_or(0.0),
+                stats.mem_used_in_kib as f64 / denominator,
+                unit,
+                stats.mem_total_in_kib as f64 / denominator,
+                unit,
+                get_rate_string(stats.numa_miss_per_sec),
+                get_rate_string(stats.numa_foreign_per_sec),
+            )
+        })
+        .collect()
+}
+
 pub fn convert_swap_data_points(
     current_data: &data_farmer::DataCollection, is_frozen: bool,
 ) -> Vec<Point> {
@@ -296,26 +1238,26 @@ pub fn convert_swap_data_points(
     result
 }
 
+/// Returns the unit type and denominator for given total amount of memory in kibibytes.
+fn return_unit_and_denominator_for_mem_kib(mem_total_kib: u64) -> (&'static str, f64) {
+    if mem_total_kib < 1024 {
+        // Stay with KiB
+        ("KiB", 1.0)
+    } else if mem_total_kib < MEBI_LIMIT {
+        // Use MiB
+        ("MiB", KIBI_LIMIT_F64)
+    } else if mem_total_kib < GIBI_LIMIT {
+        // Use GiB
+        ("GiB", MEBI_LIMIT_F64)
+    } else {
+        // Use TiB
+        ("TiB", GIBI_LIMIT_F64)
+    }
+}
+
 pub fn convert_mem_labels(
     current_data: &data_farmer::DataCollection,
 ) -> (Option<(String, String)>, Option<(String, String)>) {
-    /// Returns the unit type and denominator for given total amount of memory in kibibytes.
-    fn return_unit_and_denominator_for_mem_kib(mem_total_kib: u64) -> (&'static str, f64) {
-        if mem_total_kib < 1024 {
-            // Stay with KiB
-            ("KiB", 1.0)
-        } else if mem_total_kib < MEBI_LIMIT {
-            // Use MiB
-            ("MiB", KIBI_LIMIT_F64)
-        } else if mem_total_kib < GIBI_LIMIT {
-            // Use GiB
-            ("GiB", MEBI_LIMIT_F64)
-        } else {
-            // Use TiB
-            ("TiB", GIBI_LIMIT_F64)
-        }
-    }
-
     (
         if current_data.memory_harvest.mem_total_in_kib > 0 {
             Some((
@@ -623,6 +1565,9 @@ pub fn convert_process_data(
 
         let mem_usage_str = get_binary_bytes(process.mem_usage_bytes);
 
+        let (cpu_history, mem_history, cpu_history_average, mem_history_peak, mem_growth_bytes) =
+            get_process_history(current_data.process_history.get(&process.pid));
+
         let user = {
             #[cfg(target_family = "unix")]
             {
@@ -663,6 +1608,11 @@ pub fn convert_process_data(
                 process_entry.process_description_prefix = None;
                 process_entry.is_disabled_entry = false;
                 process_entry.user = user;
+                process_entry.cpu_history = cpu_history;
+                process_entry.mem_history = mem_history;
+                process_entry.cpu_history_average = cpu_history_average;
+                process_entry.mem_history_peak = mem_history_peak;
+                process_entry.mem_growth_bytes = mem_growth_bytes;
             } else {
                 // ...I hate that I can't combine if let and an if statement in one line...
                 *process_entry = ConvertedProcessData {
@@ -690,6 +1640,11 @@ pub fn convert_process_data(
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
                     user,
+                    cpu_history,
+                    mem_history,
+                    cpu_history_average,
+                    mem_history_peak,
+                    mem_growth_bytes,
                 };
             }
         } else {
@@ -720,6 +1675,11 @@ pub fn convert_process_data(
                     is_disabled_entry: false,
                     is_collapsed_entry: false,
                     user,
+                    cpu_history,
+                    mem_history,
+                    cpu_history_average,
+                    mem_history_peak,
+                    mem_growth_bytes,
                 },
             );
         }
@@ -731,6 +1691,68 @@ pub fn convert_process_data(
     })
 }
 
+/// Returns the recent CPU and memory history of a process, its average CPU usage and peak memory
+/// usage, and how much its memory usage has grown, all since it was first seen.
+fn get_process_history(
+    history: Option<&data_farmer::ProcessHistory>,
+) -> (Vec<f64>, Vec<f64>, f64, u64, i64) {
+    if let Some(history) = history {
+        let cpu_history = history.samples.iter().map(|(_, cpu, _)| *cpu).collect();
+        let mem_history = history
+            .samples
+            .iter()
+            .map(|(_, _, mem)| *mem as f64)
+            .collect();
+
+        let cpu_history_average = history.cpu_usage_sum / history.sample_count.max(1) as f64;
+        let mem_history_peak = history.peak_mem_bytes;
+        let mem_growth_bytes = history
+            .samples
+            .back()
+            .map(|(_, _, mem)| *mem as i64 - history.initial_mem_bytes as i64)
+            .unwrap_or(0);
+
+        (
+            cpu_history,
+            mem_history,
+            cpu_history_average,
+            mem_history_peak,
+            mem_growth_bytes,
+        )
+    } else {
+        (Vec::new(), Vec::new(), 0.0, 0, 0)
+    }
+}
+
+/// Adds the values of `other` to `history`, aligning both to their most recent value.
+fn add_history(history: &mut Vec<f64>, other: &[f64]) {
+    if other.len() > history.len() {
+        let mut padded = vec![0.0; other.len() - history.len()];
+        padded.append(history);
+        *history = padded;
+    }
+
+    let offset = history.len() - other.len();
+    for (value, other_value) in history[offset..].iter_mut().zip(other) {
+        *value += other_value;
+    }
+}
+
+fn get_growth_string(mem_growth_bytes: i64) -> String {
+    if mem_growth_bytes == 0 {
+        return "0B".to_string();
+    }
+
+    let sign = if mem_growth_bytes > 0 { '+' } else { '-' };
+    let abs_bytes = mem_growth_bytes.unsigned_abs();
+    let (value, unit) = get_binary_bytes(abs_bytes);
+    if abs_bytes <= GIBI_LIMIT {
+        format!("{}{:.0}{}", sign, value, unit)
+    } else {
+        format!("{}{:.1}{}", sign, value, unit)
+    }
+}
+
 const BRANCH_ENDING: char = '└';
 const BRANCH_VERTICAL: char = '│';
 const BRANCH_SPLIT: char = '├';
@@ -976,6 +1998,33 @@ pub fn tree_process_data(
                 (None, Some(_)) => std::cmp::Ordering::Greater,
                 (None, None) => std::cmp::Ordering::Less,
             }),
+            ProcessSorting::CpuHistory => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.cpu_history_average,
+                        b.1.cpu_history_average,
+                        is_sort_descending,
+                    )
+                });
+            }
+            ProcessSorting::MemHistory => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.mem_history_peak,
+                        b.1.mem_history_peak,
+                        is_sort_descending,
+                    )
+                });
+            }
+            ProcessSorting::MemGrowth => {
+                to_sort_vec.sort_by(|a, b| {
+                    utils::gen_util::get_ordering(
+                        a.1.mem_growth_bytes,
+                        b.1.mem_growth_bytes,
+                        is_sort_descending,
+                    )
+                });
+            }
             ProcessSorting::Count => {
                 // Should never occur in this case, tree mode explicitly disables grouping.
             }
@@ -1211,67 +2260,104 @@ pub fn stringify_process_data(
     let is_using_command = proc_widget_state.is_using_command;
     let is_tree = proc_widget_state.is_tree_mode;
     let mem_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::Mem);
+    #[cfg(target_family = "unix")]
+    let user_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::User);
+    let state_enabled = proc_widget_state.columns.is_enabled(&ProcessSorting::State);
+    let cpu_history_enabled = proc_widget_state
+        .columns
+        .is_enabled(&ProcessSorting::CpuHistory);
+    let mem_history_enabled = proc_widget_state
+        .columns
+        .is_enabled(&ProcessSorting::MemHistory);
+    let mem_growth_enabled = proc_widget_state
+        .columns
+        .is_enabled(&ProcessSorting::MemGrowth);
 
     finalized_process_data
         .iter()
         .map(|process| {
-            (
-                vec![
-                    (
-                        if is_proc_widget_grouped {
-                            process.group_pids.len().to_string()
-                        } else {
-                            process.pid.to_string()
-                        },
-                        None,
-                    ),
-                    (
-                        if is_tree {
-                            if let Some(prefix) = &process.process_description_prefix {
-                                prefix.clone()
-                            } else {
-                                String::default()
-                            }
-                        } else if is_using_command {
-                            process.command.clone()
+            let mut row = vec![
+                (
+                    if is_proc_widget_grouped {
+                        process.group_pids.len().to_string()
+                    } else {
+                        process.pid.to_string()
+                    },
+                    None,
+                ),
+                (
+                    if is_tree {
+                        if let Some(prefix) = &process.process_description_prefix {
+                            prefix.clone()
                         } else {
-                            process.name.clone()
-                        },
-                        None,
-                    ),
-                    (format!("{:.1}%", process.cpu_percent_usage), None),
-                    (
-                        if mem_enabled {
-                            if process.mem_usage_bytes <= GIBI_LIMIT {
-                                format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                            } else {
-                                format!("{:.1}{}", process.mem_usage_str.0, process.mem_usage_str.1)
-                            }
+                            String::default()
+                        }
+                    } else if is_using_command {
+                        process.command.clone()
+                    } else {
+                        process.name.clone()
+                    },
+                    None,
+                ),
+                (format!("{:.1}%", process.cpu_percent_usage), None),
+                (
+                    if mem_enabled {
+                        if process.mem_usage_bytes <= GIBI_LIMIT {
+                            format!("{:.0}{}", process.mem_usage_str.0, process.mem_usage_str.1)
                         } else {
-                            format!("{:.1}%", process.mem_percent_usage)
-                        },
-                        None,
-                    ),
-                    (process.read_per_sec.clone(), None),
-                    (process.write_per_sec.clone(), None),
-                    (process.total_read.clone(), None),
-                    (process.total_write.clone(), None),
-                    #[cfg(target_family = "unix")]
-                    (
+                            format!("{:.1}{}", process.mem_usage_str.0, process.mem_usage_str.1)
+                        }
+                    } else {
+                        format!("{:.1}%", process.mem_percent_usage)
+                    },
+                    None,
+                ),
+                (process.read_per_sec.clone(), None),
+                (process.write_per_sec.clone(), None),
+                (process.total_read.clone(), None),
+                (process.total_write.clone(), None),
+            ];
+
+            #[cfg(target_family = "unix")]
+            {
+                if user_enabled {
+                    row.push((
                         if let Some(user) = &process.user {
                             user.clone()
                         } else {
                             "N/A".to_string()
                         },
                         None,
-                    ),
-                    (
-                        process.process_state.clone(),
-                        Some(process.process_char.to_string()),
-                    ),
-                ],
-                process.is_disabled_entry,
-            )
+                    ));
+                }
+            }
+            if state_enabled {
+                row.push((
+                    process.process_state.clone(),
+                    Some(process.process_char.to_string()),
+                ));
+            }
+            if cpu_history_enabled {
+                // Scale to at least 100% so that an idle process doesn't look busy.
+                let max = process
+                    .cpu_history
+                    .iter()
+                    .fold(100.0, |a: f64, b| a.max(*b));
+                row.push((draw_sparkline(&process.cpu_history, 0.0, max), None));
+            }
+            if mem_history_enabled {
+                let min = process
+                    .mem_history
+                    .iter()
+                    .fold(f64::MAX, |a: f64, b| a.min(*b));
+                let max = process.mem_history.iter().fold(0.0, |a: f64, b| a.max(*b));
+                row.push((draw_sparkline(&process.mem_history, min, max), None));
+            }
+            if mem_growth_enabled {
+                row.push((get_growth_string(process.mem_growth_bytes), None));
+            }
+
+            (row, process.is_disabled_entry)
         })
         .collect()
 }
@@ -1294,6 +2380,11 @@ pub fn group_process_data(
         pub total_read: f64,
         pub total_write: f64,
         pub process_state: String,
+        pub cpu_history: Vec<f64>,
+        pub mem_history: Vec<f64>,
+        pub cpu_history_average: f64,
+        pub mem_history_peak: u64,
+        pub mem_growth_bytes: i64,
     }
 
     let mut grouped_hashmap: HashMap<String, SingleProcessData> = std::collections::HashMap::new();
@@ -1318,6 +2409,11 @@ pub fn group_process_data(
         (*entry).write_per_sec += process.wps_f64;
         (*entry).total_read += process.tr_f64;
         (*entry).total_write += process.tw_f64;
+        add_history(&mut entry.cpu_history, &process.cpu_history);
+        add_history(&mut entry.mem_history, &process.mem_history);
+        entry.cpu_history_average += process.cpu_history_average;
+        entry.mem_history_peak += process.mem_history_peak;
+        entry.mem_growth_bytes += process.mem_growth_bytes;
     });
 
     grouped_hashmap
@@ -1357,6 +2453,11 @@ pub fn group_process_data(
                 is_disabled_entry: false,
                 is_collapsed_entry: false,
                 user: None,
+                cpu_history: p.cpu_history,
+                mem_history: p.mem_history,
+                cpu_history_average: p.cpu_history_average,
+                mem_history_peak: p.mem_history_peak,
+                mem_growth_bytes: p.mem_growth_bytes,
             }
         })
         .collect::<Vec<_>>()
@@ -8,6 +8,7 @@ extern crate log;
 
 use std::{
     boxed::Box,
+    collections::HashMap,
     fs,
     io::{stdout, Write},
     panic::PanicInfo,
@@ -29,7 +30,7 @@ use crossterm::{
 use app::{
     data_harvester::{self, processes::ProcessSorting},
     layout_manager::{UsedWidgets, WidgetDirection},
-    App,
+    App, CpuGraphMode,
 };
 use constants::*;
 use data_conversion::*;
@@ -79,18 +80,78 @@ pub fn handle_mouse_event(event: MouseEvent, app: &mut App) {
             if !app.app_config_fields.disable_click {
                 match button {
                     crossterm::event::MouseButton::Left => {
-                        // Trigger left click widget activity
-                        app.on_left_mouse_up(x, y);
+                        if app.is_editing_layout() {
+                            app.start_layout_drag(x, y);
+                        } else {
+                            // Trigger left click widget activity
+                            app.on_left_mouse_up(x, y);
+                        }
                     }
                     crossterm::event::MouseButton::Right => {}
                     _ => {}
                 }
             }
         }
+        MouseEvent::Drag(crossterm::event::MouseButton::Left, x, y, _modifiers)
+            if app.is_editing_layout() =>
+        {
+            app.on_layout_drag(x, y)
+        }
+        MouseEvent::Up(crossterm::event::MouseButton::Left, _x, _y, _modifiers) => {
+            app.stop_layout_drag();
+        }
         _ => {}
     };
 }
 
+/// Handles keys while editing the layout, where most keys edit the selected widget.
+fn handle_layout_edit_key_event(event: KeyEvent, app: &mut App) -> bool {
+    match (event.modifiers, event.code) {
+        (KeyModifiers::NONE, KeyCode::Char('q')) | (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
+            return true;
+        }
+        (KeyModifiers::NONE, KeyCode::Esc) | (_, KeyCode::Char('E')) => app.toggle_layout_edit(),
+        (KeyModifiers::NONE, KeyCode::Left) | (KeyModifiers::NONE, KeyCode::Char('h')) => {
+            app.resize_layout_widget(&WidgetDirection::Left)
+        }
+        (KeyModifiers::NONE, KeyCode::Right) | (KeyModifiers::NONE, KeyCode::Char('l')) => {
+            app.resize_layout_widget(&WidgetDirection::Right)
+        }
+        (KeyModifiers::NONE, KeyCode::Up) | (KeyModifiers::NONE, KeyCode::Char('k')) => {
+            app.resize_layout_widget(&WidgetDirection::Up)
+        }
+        (KeyModifiers::NONE, KeyCode::Down) | (KeyModifiers::NONE, KeyCode::Char('j')) => {
+            app.resize_layout_widget(&WidgetDirection::Down)
+        }
+        (KeyModifiers::SHIFT, KeyCode::Left) | (_, KeyCode::Char('H')) => {
+            app.swap_layout_widget(&WidgetDirection::Left)
+        }
+        (KeyModifiers::SHIFT, KeyCode::Right) | (_, KeyCode::Char('L')) => {
+            app.swap_layout_widget(&WidgetDirection::Right)
+        }
+        (KeyModifiers::SHIFT, KeyCode::Up) | (_, KeyCode::Char('K')) => {
+            app.swap_layout_widget(&WidgetDirection::Up)
+        }
+        (KeyModifiers::SHIFT, KeyCode::Down) | (_, KeyCode::Char('J')) => {
+            app.swap_layout_widget(&WidgetDirection::Down)
+        }
+        (KeyModifiers::CONTROL, KeyCode::Left) => app.move_widget_selection(&WidgetDirection::Left),
+        (KeyModifiers::CONTROL, KeyCode::Right) => {
+            app.move_widget_selection(&WidgetDirection::Right)
+        }
+        (KeyModifiers::CONTROL, KeyCode::Up) => app.move_widget_selection(&WidgetDirection::Up),
+        (KeyModifiers::CONTROL, KeyCode::Down) => app.move_widget_selection(&WidgetDirection::Down),
+        (_, KeyCode::Char('t')) => app.cycle_layout_widget_type(true),
+        (_, KeyCode::Char('T')) => app.cycle_layout_widget_type(false),
+        (_, KeyCode::Char('w')) => app.save_layout(),
+        (_, KeyCode::Char('[')) => app.previous_layout_tab(),
+        (_, KeyCode::Char(']')) => app.next_layout_tab(),
+        _ => {}
+    }
+
+    false
+}
+
 pub fn handle_key_event_or_break(
     event: KeyEvent, app: &mut App, reset_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
 ) -> bool {
@@ -101,6 +162,10 @@ pub fn handle_key_event_or_break(
     // We can solve this (when we do paste probably) while keeping the throttle (mainly meant for movement)
     // by throttling after *bulk+singular* actions, not just singular ones.
 
+    if app.is_editing_layout() {
+        return handle_layout_edit_key_event(event, app);
+    }
+
     if event.modifiers.is_empty() {
         // Required catch for searching - otherwise you couldn't search with q.
         if event.code == KeyCode::Char('q') && !app.is_in_search_widget() {
@@ -242,6 +307,11 @@ pub fn try_drawing(
     terminal: &mut tui::terminal::Terminal<tui::backend::CrosstermBackend<std::io::Stdout>>,
     app: &mut App, painter: &mut canvas::Painter,
 ) -> error::Result<()> {
+    // Resizing can switch to another layout, whose widgets then need to be brought up to date.
+    if painter.select_layout_for_size(terminal.size()?, app) {
+        handle_force_redraws(app);
+    }
+
     if let Err(err) = painter.draw_data(terminal, app) {
         cleanup_terminal(terminal)?;
         return Err(err);
@@ -300,6 +370,158 @@ pub fn panic_hook(panic_info: &PanicInfo<'_>) {
     .unwrap();
 }
 
+/// Rebuilds the widgets from the config after the layout was edited.  Widgets that are still in
+/// the layout keep their state, even if their IDs changed.
+pub fn rebuild_layout(
+    matches: &::clap::ArgMatches<'static>, app: &mut App, painter: &mut canvas::Painter,
+    thread_ctrl_sender: &std::sync::mpsc::Sender<ThreadControlEvent>,
+) {
+    fn keep_widget_states<T>(
+        states: &mut HashMap<u64, T>, mut new_states: HashMap<u64, T>,
+        widget_id_map: &HashMap<u64, u64>,
+    ) {
+        for (widget_id, state) in states.drain() {
+            if let Some(new_state) = widget_id_map
+                .get(&widget_id)
+                .and_then(|new_widget_id| new_states.get_mut(new_widget_id))
+            {
+                *new_state = state;
+            }
+        }
+        *states = new_states;
+    }
+
+    app.layout_edit_state.needs_rebuild = false;
+    let widget_id_map = std::mem::take(&mut app.layout_edit_state.widget_id_map);
+    let widget_to_select = app.layout_edit_state.widget_to_select.take();
+
+    let mut config = app.config.clone();
+    let rebuilt = get_widget_layout(matches, &config)
+        .map_err(anyhow::Error::from)
+        .and_then(
+            |(widget_layouts, default_widget_id, default_widget_type_option)| {
+                let new_app = build_app(
+                    matches,
+                    &mut config,
+                    &widget_layouts,
+                    default_widget_id,
+                    &default_widget_type_option,
+                    app.config_path.clone(),
+                )?;
+                Ok((widget_layouts, new_app))
+            },
+        );
+    let (widget_layouts, new_app) = match rebuilt {
+        Ok(rebuilt) => rebuilt,
+        Err(err) => {
+            app.layout_edit_state.message = Some(format!("Failed to rebuild the layout: {}", err));
+            app.is_force_redraw = true;
+            return;
+        }
+    };
+
+    keep_widget_states(
+        &mut app.cpu_state.widget_states,
+        new_app.cpu_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.mem_state.widget_states,
+        new_app.mem_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.net_state.widget_states,
+        new_app.net_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.proc_state.widget_states,
+        new_app.proc_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.temp_state.widget_states,
+        new_app.temp_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.temp_graph_state.widget_states,
+        new_app.temp_graph_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.sensor_state.widget_states,
+        new_app.sensor_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.disk_state.widget_states,
+        new_app.disk_state.widget_states,
+        &widget_id_map,
+    );
+    keep_widget_states(
+        &mut app.battery_state.widget_states,
+        new_app.battery_state.widget_states,
+        &widget_id_map,
+    );
+    // Status bars only hold their settings, so they are taken from the new layout as they are.
+    app.status_bar_state = new_app.status_bar_state;
+
+    // Each layout keeps whichever widget was selected in it.
+    let current_tab = app.layout_tab_state.current_tab;
+    let current_layout = app.layout_tab_state.current_layout;
+    let mut layout_tab_state = new_app.layout_tab_state;
+    layout_tab_state.current_tab = current_tab;
+    layout_tab_state.current_layout = current_layout;
+    for (layout_widget_id, old_widget_id) in layout_tab_state
+        .layout_widget_ids
+        .iter_mut()
+        .zip(&app.layout_tab_state.layout_widget_ids)
+    {
+        if let Some(new_widget_id) = widget_id_map.get(old_widget_id) {
+            *layout_widget_id = *new_widget_id;
+        }
+    }
+    if let Some(widget_id) = widget_to_select {
+        layout_tab_state.layout_widget_ids[current_layout] = widget_id;
+    }
+
+    app.widget_map = new_app.widget_map;
+    if let Some(widget) = app
+        .widget_map
+        .get(&layout_tab_state.layout_widget_ids[current_layout])
+    {
+        app.current_widget = widget.clone();
+    }
+    app.layout_tab_state = layout_tab_state;
+
+    // The CPU widgets keep their graph modes, which decide whether frequencies are collected.
+    let old_used_widgets = std::mem::replace(&mut app.used_widgets, new_app.used_widgets);
+    app.update_used_cpu_details();
+    app.update_used_histories();
+    if app.used_widgets != old_used_widgets {
+        thread_ctrl_sender
+            .send(ThreadControlEvent::UpdateUsedWidgets(Box::new(
+                app.used_widgets.clone(),
+            )))
+            .ok();
+    }
+
+    painter.set_widget_layouts(widget_layouts, current_layout);
+
+    // Fill in the data for any new widgets straight away.
+    let widget_id = app.current_widget.widget_id;
+    app.cpu_state.force_update = Some(widget_id);
+    app.mem_state.force_update = Some(widget_id);
+    app.net_state.force_update = Some(widget_id);
+    app.temp_graph_state.force_update = Some(widget_id);
+    app.proc_state.force_update_all = true;
+    update_all_temp_tables(app);
+    update_all_disk_tables(app);
+    app.is_force_redraw = true;
+}
+
 pub fn handle_force_redraws(app: &mut App) {
     // Currently we use an Option... because we might want to future-proof this
     // if we eventually get widget-specific redrawing!
@@ -311,13 +533,56 @@ pub fn handle_force_redraws(app: &mut App) {
         app.proc_state.force_update = None;
     }
 
+    if let Some(widget_id) = app.temp_state.force_update {
+        update_temp_table(app, widget_id);
+        app.temp_state.force_update = None;
+    }
+
+    if app.temp_graph_state.force_update.is_some() {
+        convert_temp_data_points(
+            &app.data_collection,
+            &mut app.canvas_data.temp_graph_data,
+            &app.app_config_fields.temperature_type,
+            app.is_frozen,
+        );
+        app.temp_graph_state.force_update = None;
+    }
+
+    if let Some(widget_id) = app.disk_state.force_update {
+        update_disk_table(app, widget_id);
+        app.disk_state.force_update = None;
+    }
+
     if app.cpu_state.force_update.is_some() {
         convert_cpu_data_points(
             &app.data_collection,
             &mut app.canvas_data.cpu_data,
             app.is_frozen,
+            app.cpu_state.is_using_graph_mode(CpuGraphMode::Breakdown),
+            app.cpu_state.is_using_graph_mode(CpuGraphMode::Frequency),
+            app.app_config_fields.cpu_grouping,
         );
         app.canvas_data.load_avg_data = app.data_collection.load_avg_harvest;
+        app.canvas_data.load_avg_graph_data =
+            if app.cpu_state.is_using_graph_mode(CpuGraphMode::LoadAverage) {
+                convert_load_avg_data_points(
+                    &app.data_collection,
+                    app.is_frozen,
+                    app.app_config_fields.normalize_load_avg,
+                )
+            } else {
+                Default::default()
+            };
+        app.canvas_data.cpu_heatmap_data =
+            if app.cpu_state.is_using_graph_mode(CpuGraphMode::Heatmap) {
+                convert_cpu_heatmap_data(&app.data_collection, app.is_frozen)
+            } else {
+                Vec::new()
+            };
+        app.canvas_data.scheduler_stats = app
+            .data_collection
+            .scheduler_stats_and_prev
+            .map(|(stats, _prev)| stats);
         app.cpu_state.force_update = None;
     }
 
@@ -325,6 +590,7 @@ pub fn handle_force_redraws(app: &mut App) {
     if app.mem_state.force_update.is_some() {
         app.canvas_data.mem_data = convert_mem_data_points(&app.data_collection, app.is_frozen);
         app.canvas_data.swap_data = convert_swap_data_points(&app.data_collection, app.is_frozen);
+        app.canvas_data.numa_data = convert_numa_data_points(&app.data_collection, app.is_frozen);
         app.mem_state.force_update = None;
     }
 
@@ -338,10 +604,83 @@ pub fn handle_force_redraws(app: &mut App) {
         );
         app.canvas_data.network_data_rx = rx;
         app.canvas_data.network_data_tx = tx;
+        update_network_widget_data(app);
         app.net_state.force_update = None;
     }
 }
 
+/// Converts the network data separately for any network widget whose unit or scale differs from
+/// the app-wide one.
+pub fn update_network_widget_data(app: &mut App) {
+    let need_four_points =
+        app.app_config_fields.use_basic_mode || app.app_config_fields.use_old_network_legend;
+
+    app.canvas_data.network_widget_data = app
+        .net_state
+        .widget_states
+        .iter()
+        .filter(|(_widget_id, widget_state)| {
+            widget_state.unit_type != app.app_config_fields.network_unit_type
+                || widget_state.scale_type != app.app_config_fields.network_scale_type
+        })
+        .map(|(widget_id, widget_state)| {
+            (
+                *widget_id,
+                convert_network_data_points(
+                    &app.data_collection,
+                    app.is_frozen,
+                    need_four_points,
+                    &widget_state.scale_type,
+                    &widget_state.unit_type,
+                    app.app_config_fields.network_use_binary_prefix,
+                ),
+            )
+        })
+        .collect();
+}
+
+pub fn update_all_temp_tables(app: &mut App) {
+    let widget_ids = app
+        .temp_state
+        .widget_states
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for widget_id in widget_ids {
+        update_temp_table(app, widget_id);
+    }
+}
+
+fn update_temp_table(app: &mut App, widget_id: u64) {
+    if let Some(temp_widget_state) = app.temp_state.widget_states.get(&widget_id) {
+        let temp_data = convert_temp_row(app, temp_widget_state);
+        app.canvas_data
+            .temp_sensor_data
+            .insert(widget_id, temp_data);
+    }
+}
+
+pub fn update_all_disk_tables(app: &mut App) {
+    let widget_ids = app
+        .disk_state
+        .widget_states
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    for widget_id in widget_ids {
+        update_disk_table(app, widget_id);
+    }
+}
+
+fn update_disk_table(app: &mut App, widget_id: u64) {
+    if let Some(disk_widget_state) = app.disk_state.widget_states.get(&widget_id) {
+        let disk_data = convert_disk_row(&app.data_collection, disk_widget_state);
+        app.canvas_data.disk_data.insert(widget_id, disk_data);
+    }
+}
+
 #[allow(clippy::needless_collect)]
 pub fn update_all_process_lists(app: &mut App) {
     // According to clippy, I can avoid a collect... but if I follow it,
@@ -575,6 +914,33 @@ fn sort_process_data(
             (None, Some(_)) => std::cmp::Ordering::Greater,
             (None, None) => std::cmp::Ordering::Less,
         }),
+        ProcessSorting::CpuHistory => {
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.cpu_history_average,
+                    b.cpu_history_average,
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
+        ProcessSorting::MemHistory => {
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.mem_history_peak,
+                    b.mem_history_peak,
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
+        ProcessSorting::MemGrowth => {
+            to_sort_vec.sort_by(|a, b| {
+                utils::gen_util::get_ordering(
+                    a.mem_growth_bytes,
+                    b.mem_growth_bytes,
+                    proc_widget_state.is_process_sort_descending,
+                )
+            });
+        }
         ProcessSorting::Count => {
             if proc_widget_state.is_grouped {
                 to_sort_vec.sort_by(|a, b| {
@@ -644,6 +1010,7 @@ pub fn create_collection_thread(
     let temp_type = app_config_fields.temperature_type.clone();
     let use_current_cpu_total = app_config_fields.use_current_cpu_total;
     let show_average_cpu = app_config_fields.show_average_cpu;
+    let extra_filesystems = app_config_fields.extra_filesystems.clone();
     let update_rate_in_milliseconds = app_config_fields.update_rate_in_milliseconds;
 
     thread::spawn(move || {
@@ -653,6 +1020,7 @@ pub fn create_collection_thread(
         data_state.set_temperature_type(temp_type);
         data_state.set_use_current_cpu_total(use_current_cpu_total);
         data_state.set_show_average_cpu(show_average_cpu);
+        data_state.set_extra_filesystems(extra_filesystems);
 
         data_state.init();
 
@@ -678,6 +1046,7 @@ pub fn create_collection_thread(
                         data_state
                             .set_use_current_cpu_total(app_config_fields.use_current_cpu_total);
                         data_state.set_show_average_cpu(app_config_fields.show_average_cpu);
+                        data_state.set_extra_filesystems(app_config_fields.extra_filesystems);
                     }
                     ThreadControlEvent::UpdateUsedWidgets(used_widget_set) => {
                         data_state.set_collected_data(*used_widget_set);
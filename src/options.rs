@@ -2,7 +2,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     path::PathBuf,
     str::FromStr,
     time::Instant,
@@ -29,10 +29,13 @@ pub struct Config {
     pub flags: Option<ConfigFlags>,
     pub colors: Option<ConfigColours>,
     pub row: Option<Vec<Row>>,
+    pub layout: Option<Vec<ConfigLayout>>,
     pub disk_filter: Option<IgnoreList>,
     pub mount_filter: Option<IgnoreList>,
     pub temp_filter: Option<IgnoreList>,
+    pub sensor_filter: Option<IgnoreList>,
     pub net_filter: Option<IgnoreList>,
+    pub filters: Option<BTreeMap<String, ProcessFilter>>,
 }
 
 impl Config {
@@ -45,6 +48,117 @@ impl Config {
 
         Ok(config_string.concat().as_bytes().to_vec())
     }
+
+    /// Returns the rows of every layout in the config, for editing them.  If the config doesn't
+    /// set a layout, the default one is copied into it first.
+    pub fn get_layout_rows_mut(&mut self, use_battery: bool) -> Vec<&mut Vec<Row>> {
+        if self.row.is_none() && self.layout.is_none() {
+            self.row = toml::from_str::<Config>(if use_battery {
+                DEFAULT_BATTERY_LAYOUT
+            } else {
+                DEFAULT_LAYOUT
+            })
+            .ok()
+            .and_then(|config| config.row);
+        }
+
+        match &mut self.layout {
+            Some(layouts) => layouts.iter_mut().map(|layout| &mut layout.row).collect(),
+            None => self.row.iter_mut().collect(),
+        }
+    }
+
+    /// Returns the layout of the config as TOML, without any of its other settings.
+    pub fn get_layout_as_string(&self) -> anyhow::Result<String> {
+        let layout_config = Config {
+            row: self.row.clone(),
+            layout: self.layout.clone(),
+            ..Config::default()
+        };
+
+        Ok(toml::to_string_pretty(&layout_config)?)
+    }
+}
+
+/// Returns the name of the table that a line of TOML starts, such as "row.child" for
+/// "[[row.child]]", if it starts one.
+fn get_table_header_name(line: &str) -> Option<String> {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    let name = line
+        .strip_prefix("[[")
+        .and_then(|line| line.strip_suffix("]]"))
+        .or_else(|| {
+            line.strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+        })?
+        .trim();
+
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ' '))
+    {
+        Some(name.replace(' ', ""))
+    } else {
+        None
+    }
+}
+
+/// Replaces the `[[row]]` or `[[layout]]` tables in the text of a config file with the given
+/// layout, keeping the rest of the file as it was.  The layout goes where the old one started,
+/// or at the end if there wasn't one.  Comments inside the old layout are dropped with it.
+pub fn replace_layout_in_config_text(config_text: &str, layout_text: &str) -> String {
+    fn is_layout_table(name: &str) -> bool {
+        name == "row" || name.starts_with("row.") || name == "layout" || name.starts_with("layout.")
+    }
+
+    let mut kept_lines: Vec<&str> = Vec::new();
+    let mut layout_index = None;
+    let mut is_in_layout = false;
+    // Comments and blank lines at the end of a layout table, which are kept if they lead into
+    // a table that isn't part of the layout.
+    let mut trailing_lines: Vec<&str> = Vec::new();
+
+    for line in config_text.lines() {
+        if let Some(name) = get_table_header_name(line) {
+            is_in_layout = is_layout_table(&name);
+            if is_in_layout {
+                trailing_lines.clear();
+                if layout_index.is_none() {
+                    layout_index = Some(kept_lines.len());
+                }
+            } else {
+                kept_lines.append(&mut trailing_lines);
+            }
+        }
+
+        if is_in_layout {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                trailing_lines.push(line);
+            } else {
+                trailing_lines.clear();
+            }
+        } else {
+            kept_lines.push(line);
+        }
+    }
+    kept_lines.append(&mut trailing_lines);
+
+    let layout_text = layout_text.trim_end();
+    match layout_index {
+        Some(layout_index) => kept_lines.insert(layout_index, layout_text),
+        None => {
+            if kept_lines.last().map(|line| !line.trim().is_empty()) == Some(true) {
+                kept_lines.push("");
+            }
+            kept_lines.push(layout_text);
+        }
+    }
+
+    let mut new_config_text = kept_lines.join("\n");
+    new_config_text.push('\n');
+    new_config_text
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, TypedBuilder)]
@@ -82,6 +196,9 @@ pub struct ConfigFlags {
     #[builder(default, setter(strip_option))]
     pub basic: Option<bool>,
 
+    #[builder(default, setter(strip_option))]
+    pub basic_widgets: Option<Vec<String>>,
+
     #[builder(default, setter(strip_option))]
     pub default_time_value: Option<u64>,
 
@@ -166,6 +283,33 @@ pub struct ConfigFlags {
 
     #[builder(default, setter(strip_option))]
     pub network_use_binary_prefix: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub normalize_load_avg: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub cpu_grouping: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    pub cpu_bucket_size: Option<usize>,
+
+    #[builder(default, setter(strip_option))]
+    pub cpu_legend_columns: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option))]
+    pub disk_columns: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option))]
+    pub process_history_columns: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option))]
+    pub all_filesystems: Option<bool>,
+
+    #[builder(default, setter(strip_option))]
+    pub include_filesystems: Option<Vec<String>>,
+
+    #[builder(default, setter(strip_option))]
+    pub disk_history_window: Option<u64>,
 }
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
@@ -206,9 +350,19 @@ pub struct ConfigColours {
     pub selected_bg_color: Option<String>,
     pub widget_title_color: Option<String>,
     pub graph_color: Option<String>,
+    pub graph_label_color: Option<String>,
     pub high_battery_color: Option<String>,
     pub medium_battery_color: Option<String>,
     pub low_battery_color: Option<String>,
+    pub disk_warning_color: Option<String>,
+    pub disk_critical_color: Option<String>,
+    pub invalid_query_color: Option<String>,
+    pub alternate_row_color: Option<String>,
+    pub gradient_colors: Option<Vec<String>>,
+    pub temp_warm_color: Option<String>,
+    pub temp_hot_color: Option<String>,
+    pub temp_warm_threshold: Option<f64>,
+    pub temp_hot_threshold: Option<f64>,
 }
 
 impl ConfigColours {
@@ -223,6 +377,14 @@ impl ConfigColours {
     }
 }
 
+/// A standalone theme, loaded from a file in the `themes` directory next to the config file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ThemeFile {
+    /// The built-in colour scheme the theme's colours are applied on top of.
+    pub base: Option<String>,
+    pub colors: Option<ConfigColours>,
+}
+
 /// Workaround as per https://github.com/serde-rs/serde/issues/1030
 fn default_as_true() -> bool {
     true
@@ -241,10 +403,24 @@ pub struct IgnoreList {
     pub case_sensitive: bool,
     #[serde(default = "bool::default")]
     pub whole_word: bool,
+    /// What part of each entry to match against.  Defaults to the entry's name.
+    pub field: Option<String>,
+}
+
+/// A saved process search query.  These are keyed by name under the `[filters]` section.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProcessFilter {
+    pub query: String,
+    #[serde(default = "bool::default")]
+    pub regex: bool,
+    #[serde(default = "bool::default")]
+    pub case_sensitive: bool,
+    #[serde(default = "bool::default")]
+    pub whole_word: bool,
 }
 
 pub fn build_app(
-    matches: &clap::ArgMatches<'static>, config: &mut Config, widget_layout: &BottomLayout,
+    matches: &clap::ArgMatches<'static>, config: &mut Config, widget_layouts: &[BottomLayout],
     default_widget_id: u64, default_widget_type_option: &Option<BottomWidgetType>,
     config_path: Option<PathBuf>,
 ) -> Result<App> {
@@ -259,6 +435,23 @@ pub fn build_app(
     let is_case_sensitive = get_app_case_sensitive(matches, config);
     let is_match_whole_word = get_app_match_whole_word(matches, config);
     let is_use_regex = get_app_use_regex(matches, config);
+    let process_history_columns = get_process_history_columns(config)
+        .context("Update 'process_history_columns' in your config file.")?;
+
+    // For CPUs
+    let cpu_legend_columns = get_cpu_legend_columns(config)
+        .context("Update 'cpu_legend_columns' in your config file.")?;
+
+    // For disks
+    let disk_columns =
+        get_disk_columns(config).context("Update 'disk_columns' in your config file.")?;
+
+    // For graphs, where widgets without their own style fall back to the dot flag.
+    let default_graph_style = if get_use_dot(matches, config) {
+        GraphStyle::Dot
+    } else {
+        GraphStyle::Braille
+    };
 
     let mut widget_map = HashMap::new();
     let mut cpu_state_map: HashMap<u64, CpuWidgetState> = HashMap::new();
@@ -266,8 +459,11 @@ pub fn build_app(
     let mut net_state_map: HashMap<u64, NetWidgetState> = HashMap::new();
     let mut proc_state_map: HashMap<u64, ProcWidgetState> = HashMap::new();
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::new();
+    let mut temp_graph_state_map: HashMap<u64, TempGraphWidgetState> = HashMap::new();
+    let mut sensor_state_map: HashMap<u64, SensorWidgetState> = HashMap::new();
     let mut disk_state_map: HashMap<u64, DiskWidgetState> = HashMap::new();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::new();
+    let mut status_bar_state_map: HashMap<u64, StatusBarWidgetState> = HashMap::new();
 
     let autohide_timer = if autohide_time {
         Some(Instant::now())
@@ -277,7 +473,7 @@ pub fn build_app(
 
     let mut initial_widget_id: u64 = default_widget_id;
     let mut initial_widget_type = Proc;
-    let is_custom_layout = config.row.is_some();
+    let is_custom_layout = config.row.is_some() || config.layout.is_some();
     let mut used_widget_set = HashSet::new();
 
     let show_memory_as_values = get_mem_as_value(matches, config);
@@ -289,7 +485,9 @@ pub fn build_app(
     let network_scale_type = get_network_scale_type(matches, config);
     let network_use_binary_prefix = get_network_use_binary_prefix(matches, config);
 
-    for row in &widget_layout.rows {
+    let saved_filters = get_saved_filters(config);
+
+    for row in widget_layouts.iter().flat_map(|layout| &layout.rows) {
         for col in &row.children {
             for col_row in &col.children {
                 for widget in &col_row.children {
@@ -327,54 +525,158 @@ pub fn build_app(
 
                     used_widget_set.insert(widget.widget_type.clone());
 
+                    // Settings given to the widget in the layout take priority over the app-wide
+                    // ones.
+                    let options = &widget.options;
+                    let widget_time_value = match options.default_time_value {
+                        Some(widget_time_value) => {
+                            check_default_time_value(u128::from(widget_time_value))
+                                .context("Update 'default_time_value' in your layout.")?
+                        }
+                        None => default_time_value,
+                    };
+
                     match widget.widget_type {
                         Cpu => {
+                            let legend_columns = match &options.columns {
+                                Some(columns) => parse_cpu_legend_columns(columns)
+                                    .context("Update 'columns' in your layout.")?,
+                                None => cpu_legend_columns.clone(),
+                            };
+
                             cpu_state_map.insert(
                                 widget.widget_id,
-                                CpuWidgetState::init(default_time_value, autohide_timer),
+                                CpuWidgetState::init(
+                                    widget_time_value,
+                                    autohide_timer,
+                                    widget.graph_style.unwrap_or(default_graph_style),
+                                    legend_columns,
+                                ),
                             );
                         }
                         Mem => {
                             mem_state_map.insert(
                                 widget.widget_id,
-                                MemWidgetState::init(default_time_value, autohide_timer),
+                                MemWidgetState::init(
+                                    widget_time_value,
+                                    autohide_timer,
+                                    widget.graph_style.unwrap_or(default_graph_style),
+                                ),
                             );
                         }
                         Net => {
+                            let unit_type = match options.network_use_bytes {
+                                Some(true) => DataUnit::Byte,
+                                Some(false) => DataUnit::Bit,
+                                None => network_unit_type.clone(),
+                            };
+                            let scale_type = match options.network_use_log {
+                                Some(true) => AxisScaling::Log,
+                                Some(false) => AxisScaling::Linear,
+                                None => network_scale_type.clone(),
+                            };
+
                             net_state_map.insert(
                                 widget.widget_id,
                                 NetWidgetState::init(
-                                    default_time_value,
+                                    widget_time_value,
                                     autohide_timer,
-                                    // network_unit_type.clone(),
-                                    // network_scale_type.clone(),
+                                    widget.graph_style.unwrap_or(default_graph_style),
+                                    unit_type,
+                                    scale_type,
                                 ),
                             );
                         }
                         Proc => {
+                            let history_columns = match &options.columns {
+                                Some(columns) => parse_process_history_columns(columns)
+                                    .context("Update 'columns' in your layout.")?,
+                                None => process_history_columns.clone(),
+                            };
+                            let is_widget_grouped = options.group_processes.unwrap_or(is_grouped);
+                            let default_sort = options
+                                .default_sort
+                                .as_deref()
+                                .map(|default_sort| {
+                                    parse_process_sort(
+                                        default_sort,
+                                        show_memory_as_values,
+                                        is_widget_grouped,
+                                        is_default_command,
+                                    )
+                                })
+                                .transpose()
+                                .context("Update 'default_sort' in your layout.")?;
+
                             proc_state_map.insert(
                                 widget.widget_id,
                                 ProcWidgetState::init(
                                     is_case_sensitive,
                                     is_match_whole_word,
                                     is_use_regex,
-                                    is_grouped,
+                                    is_widget_grouped,
                                     show_memory_as_values,
-                                    is_default_tree,
+                                    options.tree.unwrap_or(is_default_tree),
                                     is_default_command,
+                                    &history_columns,
+                                    default_sort,
                                 ),
                             );
                         }
                         Disk => {
-                            disk_state_map.insert(widget.widget_id, DiskWidgetState::init());
+                            let disk_columns = match &options.columns {
+                                Some(columns) => parse_disk_columns(columns)
+                                    .context("Update 'columns' in your layout.")?,
+                                None => disk_columns.clone(),
+                            };
+
+                            disk_state_map.insert(
+                                widget.widget_id,
+                                DiskWidgetState::init(
+                                    is_case_sensitive,
+                                    is_match_whole_word,
+                                    is_use_regex,
+                                    disk_columns,
+                                ),
+                            );
                         }
                         Temp => {
-                            temp_state_map.insert(widget.widget_id, TempWidgetState::init());
+                            temp_state_map.insert(
+                                widget.widget_id,
+                                TempWidgetState::init(
+                                    is_case_sensitive,
+                                    is_match_whole_word,
+                                    is_use_regex,
+                                ),
+                            );
+                        }
+                        TempGraph => {
+                            temp_graph_state_map.insert(
+                                widget.widget_id,
+                                TempGraphWidgetState::init(
+                                    widget_time_value,
+                                    autohide_timer,
+                                    widget.graph_style.unwrap_or(default_graph_style),
+                                ),
+                            );
+                        }
+                        Sensors => {
+                            sensor_state_map.insert(widget.widget_id, SensorWidgetState::default());
                         }
                         Battery => {
                             battery_state_map
                                 .insert(widget.widget_id, BatteryWidgetState::default());
                         }
+                        StatusBar => {
+                            let status_items = match &options.columns {
+                                Some(columns) => parse_status_items(columns)
+                                    .context("Update 'columns' in your layout.")?,
+                                None => DEFAULT_STATUS_ITEMS.to_vec(),
+                            };
+
+                            status_bar_state_map
+                                .insert(widget.widget_id, StatusBarWidgetState::init(status_items));
+                        }
                         _ => {}
                     }
                 }
@@ -407,14 +709,30 @@ pub fn build_app(
         None
     };
 
+    // Each layout starts on its default widget, apart from the one the app starts on.
+    let layout_tab_state = LayoutTabState::init(
+        widget_layouts
+            .iter()
+            .map(|layout| {
+                if layout.has_widget(initial_widget_id) {
+                    (layout.name.clone(), initial_widget_id)
+                } else {
+                    (layout.name.clone(), layout.default_widget_id)
+                }
+            })
+            .collect(),
+        widget_layouts
+            .iter()
+            .position(|layout| layout.has_widget(initial_widget_id))
+            .unwrap_or_default(),
+    );
+
     let app_config_fields = AppConfigFields {
         update_rate_in_milliseconds: get_update_rate_in_milliseconds(matches, config)
             .context("Update 'rate' in your config file.")?,
         temperature_type: get_temperature(matches, config)
             .context("Update 'temperature_type' in your config file.")?,
         show_average_cpu: get_show_average_cpu(matches, config),
-        use_dot: get_use_dot(matches, config),
-        left_legend: get_use_left_legend(matches, config),
         use_current_cpu_total: get_use_current_cpu_total(matches, config),
         use_basic_mode,
         default_time_value,
@@ -436,28 +754,63 @@ pub fn build_app(
         network_scale_type,
         network_unit_type,
         network_use_binary_prefix,
+        normalize_load_avg: get_normalize_load_avg(matches, config),
+        cpu_grouping: get_cpu_grouping(config)
+            .context("Update 'cpu_grouping' in your config file.")?,
+        extra_filesystems: get_extra_filesystems(matches, config),
+        disk_history_window: get_disk_history_window(config)
+            .context("Update 'disk_history_window' in your config file.")?,
     };
 
     let used_widgets = UsedWidgets {
-        use_cpu: used_widget_set.get(&Cpu).is_some() || used_widget_set.get(&BasicCpu).is_some(),
-        use_mem: used_widget_set.get(&Mem).is_some() || used_widget_set.get(&BasicMem).is_some(),
-        use_net: used_widget_set.get(&Net).is_some() || used_widget_set.get(&BasicNet).is_some(),
-        use_proc: used_widget_set.get(&Proc).is_some(),
-        use_disk: used_widget_set.get(&Disk).is_some(),
-        use_temp: used_widget_set.get(&Temp).is_some(),
-        use_battery: used_widget_set.get(&Battery).is_some(),
+        use_cpu: used_widget_set.contains(&Cpu)
+            || used_widget_set.contains(&BasicCpu)
+            || used_widget_set.contains(&BasicLoadAvg),
+        use_mem: used_widget_set.contains(&Mem) || used_widget_set.contains(&BasicMem),
+        use_net: used_widget_set.contains(&Net) || used_widget_set.contains(&BasicNet),
+        use_proc: used_widget_set.contains(&Proc),
+        use_disk: used_widget_set.contains(&Disk) || used_widget_set.contains(&BasicDisk),
+        use_temp: used_widget_set.contains(&Temp)
+            || used_widget_set.contains(&TempGraph)
+            || used_widget_set.contains(&BasicTemp),
+        use_temp_graph: used_widget_set.contains(&TempGraph),
+        use_sensors: used_widget_set.contains(&Sensors),
+        use_battery: used_widget_set.contains(&Battery) || used_widget_set.contains(&BasicBattery),
+        use_host: used_widget_set.contains(&StatusBar),
+        // Set from the CPU widgets once the app is built.
+        ..UsedWidgets::default()
     };
 
-    let disk_filter =
-        get_ignore_list(&config.disk_filter).context("Update 'disk_filter' in your config file")?;
-    let mount_filter = get_ignore_list(&config.mount_filter)
-        .context("Update 'mount_filter' in your config file")?;
-    let temp_filter =
-        get_ignore_list(&config.temp_filter).context("Update 'temp_filter' in your config file")?;
-    let net_filter =
-        get_ignore_list(&config.net_filter).context("Update 'net_filter' in your config file")?;
-
-    // One more thing - we have to update the search settings of our proc_state_map, and create the hashmaps if needed!
+    let disk_filter = get_ignore_list(
+        &config.disk_filter,
+        &[
+            ("name", FilterField::Name),
+            ("fs_type", FilterField::FileSystem),
+        ],
+    )
+    .context("Update 'disk_filter' in your config file")?;
+    let mount_filter = get_ignore_list(
+        &config.mount_filter,
+        &[
+            ("mount_point", FilterField::Name),
+            ("options", FilterField::MountOptions),
+        ],
+    )
+    .context("Update 'mount_filter' in your config file")?;
+    let temp_filter = get_ignore_list(&config.temp_filter, &[("name", FilterField::Name)])
+        .context("Update 'temp_filter' in your config file")?;
+    let sensor_filter = get_ignore_list(&config.sensor_filter, &[("name", FilterField::Name)])
+        .context("Update 'sensor_filter' in your config file")?;
+    let net_filter = get_ignore_list(
+        &config.net_filter,
+        &[
+            ("name", FilterField::Name),
+            ("type", FilterField::InterfaceType),
+        ],
+    )
+    .context("Update 'net_filter' in your config file")?;
+
+    // One more thing - we have to update the search settings of our proc, temp and disk state maps, and create the hashmaps if needed!
     // Note that if you change your layout, this might not actually match properly... not sure if/where we should deal with that...
     if let Some(flags) = &mut config.flags {
         if flags.case_sensitive.is_none() && !matches.is_present("case_sensitive") {
@@ -466,6 +819,10 @@ pub fn build_app(
                 for widget in search_case_enabled_widgets {
                     if let Some(proc_widget) = proc_state_map.get_mut(&widget.id) {
                         proc_widget.process_search_state.is_ignoring_case = !widget.enabled;
+                    } else if let Some(temp_widget) = temp_state_map.get_mut(&widget.id) {
+                        temp_widget.search_state.is_ignoring_case = !widget.enabled;
+                    } else if let Some(disk_widget) = disk_state_map.get_mut(&widget.id) {
+                        disk_widget.search_state.is_ignoring_case = !widget.enabled;
                     }
                 }
                 flags.search_case_enabled_widgets_map = Some(mapping);
@@ -480,6 +837,10 @@ pub fn build_app(
                 for widget in search_whole_word_enabled_widgets {
                     if let Some(proc_widget) = proc_state_map.get_mut(&widget.id) {
                         proc_widget.process_search_state.is_searching_whole_word = widget.enabled;
+                    } else if let Some(temp_widget) = temp_state_map.get_mut(&widget.id) {
+                        temp_widget.search_state.is_searching_whole_word = widget.enabled;
+                    } else if let Some(disk_widget) = disk_state_map.get_mut(&widget.id) {
+                        disk_widget.search_state.is_searching_whole_word = widget.enabled;
                     }
                 }
                 flags.search_whole_word_enabled_widgets_map = Some(mapping);
@@ -492,6 +853,10 @@ pub fn build_app(
                 for widget in search_regex_enabled_widgets {
                     if let Some(proc_widget) = proc_state_map.get_mut(&widget.id) {
                         proc_widget.process_search_state.is_searching_with_regex = widget.enabled;
+                    } else if let Some(temp_widget) = temp_state_map.get_mut(&widget.id) {
+                        temp_widget.search_state.is_searching_with_regex = widget.enabled;
+                    } else if let Some(disk_widget) = disk_state_map.get_mut(&widget.id) {
+                        disk_widget.search_state.is_searching_with_regex = widget.enabled;
                     }
                 }
                 flags.search_regex_enabled_widgets_map = Some(mapping);
@@ -499,7 +864,26 @@ pub fn build_app(
         }
     }
 
-    Ok(App::builder()
+    // Apply any saved filters set in the layout last, as they also override the search settings above.
+    for widget in widget_map.values() {
+        if let Some(filter_name) = &widget.default_filter {
+            if let Some(proc_widget) = proc_state_map.get_mut(&widget.widget_id) {
+                let index = saved_filters
+                    .iter()
+                    .position(|saved_filter| saved_filter.name == *filter_name)
+                    .ok_or_else(|| {
+                        BottomError::ConfigError(format!(
+                            "\"{}\" is not a saved filter, add it under '[filters]' in your config file.",
+                            filter_name
+                        ))
+                    })?;
+                proc_widget.apply_saved_filter(index, &saved_filters[index]);
+                proc_widget.process_search_state.search_state.is_enabled = true;
+            }
+        }
+    }
+
+    let mut app = App::builder()
         .app_config_fields(app_config_fields)
         .cpu_state(CpuState::init(cpu_state_map))
         .mem_state(MemState::init(mem_state_map))
@@ -507,8 +891,12 @@ pub fn build_app(
         .proc_state(ProcState::init(proc_state_map))
         .disk_state(DiskState::init(disk_state_map))
         .temp_state(TempState::init(temp_state_map))
+        .temp_graph_state(TempGraphState::init(temp_graph_state_map))
+        .sensor_state(SensorState::init(sensor_state_map))
         .battery_state(BatteryState::init(battery_state_map))
+        .status_bar_state(StatusBarState::init(status_bar_state_map))
         .basic_table_widget_state(basic_table_widget_state)
+        .layout_tab_state(layout_tab_state)
         .current_widget(widget_map.get(&initial_widget_id).unwrap().clone()) // TODO: [UNWRAP] - many of the unwraps are fine (like this one) but do a once-over and/or switch to expect?
         .widget_map(widget_map)
         .used_widgets(used_widgets)
@@ -516,30 +904,57 @@ pub fn build_app(
             disk_filter,
             mount_filter,
             temp_filter,
+            sensor_filter,
             net_filter,
         })
+        .saved_filters(saved_filters)
         .config(config.clone())
         .config_path(config_path)
-        .build())
+        .build();
+    app.update_used_cpu_details();
+    app.update_used_histories();
+
+    Ok(app)
 }
 
 pub fn get_widget_layout(
     matches: &clap::ArgMatches<'static>, config: &Config,
-) -> error::Result<(BottomLayout, u64, Option<BottomWidgetType>)> {
+) -> error::Result<(Vec<BottomLayout>, u64, Option<BottomWidgetType>)> {
     let left_legend = get_use_left_legend(matches, config);
     let (default_widget_type, mut default_widget_count) =
         get_default_widget_and_count(matches, config)?;
     let mut default_widget_id = 1;
 
-    let bottom_layout = if get_use_basic_mode(matches, config) {
+    let bottom_layouts = if get_use_basic_mode(matches, config) {
         default_widget_id = DEFAULT_WIDGET_ID;
 
-        BottomLayout::init_basic_default(get_use_battery(matches, config))
+        vec![BottomLayout::init_basic(
+            &get_basic_widgets(config)?,
+            get_use_battery(matches, config),
+        )]
     } else {
         let ref_row: Vec<Row>; // Required to handle reference
-        let rows = match &config.row {
-            Some(r) => r,
-            None => {
+        let layouts: Vec<(Option<&ConfigLayout>, &Vec<Row>)> = match (&config.row, &config.layout) {
+            (Some(_), Some(_)) => {
+                return Err(error::BottomError::ConfigError(
+                    "use either '[[row]]' or '[[layout]]' for your layout, not both.".to_string(),
+                ));
+            }
+            (None, Some(layouts)) => {
+                if layouts.is_empty() {
+                    return Err(error::BottomError::ConfigError(
+                        "please have at least one layout under the '[[layout]]' section."
+                            .to_string(),
+                    ));
+                }
+
+                layouts
+                    .iter()
+                    .map(|layout| (Some(layout), &layout.row))
+                    .collect()
+            }
+            (Some(rows), None) => vec![(None, rows)],
+            (None, None) => {
                 // This cannot (like it really shouldn't) fail!
                 ref_row = toml::from_str::<Config>(if get_use_battery(matches, config) {
                     DEFAULT_BATTERY_LAYOUT
@@ -548,44 +963,92 @@ pub fn get_widget_layout(
                 })?
                 .row
                 .unwrap();
-                &ref_row
+                vec![(None, &ref_row)]
             }
         };
 
         let mut iter_id = 0; // A lazy way of forcing unique IDs *shrugs*
-        let mut total_height_ratio = 0;
+        let mut has_found_default = false;
+
+        let bottom_layouts = layouts
+            .into_iter()
+            .map(|(layout, rows)| {
+                let name = layout.map(|layout| &layout.name);
+                // IDs keep counting up across layouts, so every widget is unique and can keep
+                // its state while its layout is not shown.
+                let first_widget_id = iter_id + 1;
+                let mut total_height_ratio = 0;
+                let mut layout_default_widget_id = 0;
+
+                let mut ret_bottom_layout = BottomLayout {
+                    rows: rows
+                        .iter()
+                        .map(|row| {
+                            row.convert_row_to_bottom_row(
+                                &mut iter_id,
+                                &mut total_height_ratio,
+                                &mut layout_default_widget_id,
+                                &default_widget_type,
+                                &mut default_widget_count,
+                                left_legend,
+                            )
+                        })
+                        .collect::<error::Result<Vec<_>>>()?,
+                    total_row_height_ratio: total_height_ratio,
+                    name: name.cloned().unwrap_or_default(),
+                    default_widget_id: first_widget_id,
+                    min_width: layout
+                        .and_then(|layout| layout.min_width)
+                        .unwrap_or_default(),
+                    min_height: layout
+                        .and_then(|layout| layout.min_height)
+                        .unwrap_or_default(),
+                };
+
+                // Confirm that we have at least ONE widget left - if not, error out!
+                if iter_id < first_widget_id {
+                    return Err(error::BottomError::ConfigError(match name {
+                        Some(name) => format!(
+                            "please have at least one widget in the \"{}\" layout.",
+                            name
+                        ),
+                        None => "please have at least one widget under the '[[row]]' section."
+                            .to_string(),
+                    }));
+                }
 
-        let mut ret_bottom_layout = BottomLayout {
-            rows: rows
-                .iter()
-                .map(|row| {
-                    row.convert_row_to_bottom_row(
-                        &mut iter_id,
-                        &mut total_height_ratio,
-                        &mut default_widget_id,
-                        &default_widget_type,
-                        &mut default_widget_count,
-                        left_legend,
-                    )
-                })
-                .collect::<error::Result<Vec<_>>>()?,
-            total_row_height_ratio: total_height_ratio,
-        };
+                // Layouts without a default widget start on their first one that can be selected.
+                if let Some(first_selectable_widget_id) =
+                    ret_bottom_layout.get_first_selectable_widget_id()
+                {
+                    ret_bottom_layout.default_widget_id = first_selectable_widget_id;
+                }
 
-        // Confirm that we have at least ONE widget left - if not, error out!
-        if iter_id > 0 {
-            ret_bottom_layout.get_movement_mappings();
-            // debug!("Bottom layout: {:#?}", ret_bottom_layout);
+                // The app starts on the first layout with a default widget.
+                if layout_default_widget_id != 0 {
+                    ret_bottom_layout.default_widget_id = layout_default_widget_id;
+                    if !has_found_default {
+                        has_found_default = true;
+                        default_widget_id = layout_default_widget_id;
+                    }
+                }
 
-            ret_bottom_layout
-        } else {
-            return Err(error::BottomError::ConfigError(
-                "please have at least one widget under the '[[row]]' section.".to_string(),
-            ));
+                ret_bottom_layout.get_movement_mappings();
+                // debug!("Bottom layout: {:#?}", ret_bottom_layout);
+
+                Ok(ret_bottom_layout)
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+
+        // Otherwise, it starts on the first layout's first widget that can be selected.
+        if !has_found_default {
+            default_widget_id = bottom_layouts[0].default_widget_id;
         }
+
+        bottom_layouts
     };
 
-    Ok((bottom_layout, default_widget_id, default_widget_type))
+    Ok((bottom_layouts, default_widget_id, default_widget_type))
 }
 
 fn get_update_rate_in_milliseconds(
@@ -702,6 +1165,38 @@ fn get_use_basic_mode(matches: &clap::ArgMatches<'static>, config: &Config) -> b
     false
 }
 
+fn get_basic_widgets(config: &Config) -> error::Result<Vec<BottomWidgetType>> {
+    if let Some(flags) = &config.flags {
+        if let Some(basic_widgets) = &flags.basic_widgets {
+            return basic_widgets
+                .iter()
+                .map(|basic_widget| parse_basic_widget(basic_widget))
+                .collect();
+        }
+    }
+    Ok(vec![
+        BottomWidgetType::BasicCpu,
+        BottomWidgetType::BasicMem,
+        BottomWidgetType::BasicNet,
+    ])
+}
+
+fn parse_basic_widget(basic_widget: &str) -> error::Result<BottomWidgetType> {
+    match basic_widget.to_lowercase().as_str() {
+        "cpu" => Ok(BottomWidgetType::BasicCpu),
+        "mem" | "memory" => Ok(BottomWidgetType::BasicMem),
+        "net" | "network" => Ok(BottomWidgetType::BasicNet),
+        "temp" | "temperature" => Ok(BottomWidgetType::BasicTemp),
+        "disk" => Ok(BottomWidgetType::BasicDisk),
+        "load_avg" => Ok(BottomWidgetType::BasicLoadAvg),
+        "battery" | "batt" if cfg!(feature = "battery") => Ok(BottomWidgetType::BasicBattery),
+        _ => Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid basic mode widget, use \"<cpu|mem|net|temp|disk|load_avg|battery>\".",
+            basic_widget
+        ))),
+    }
+}
+
 fn get_default_time_value(
     matches: &clap::ArgMatches<'static>, config: &Config,
 ) -> error::Result<u64> {
@@ -717,6 +1212,11 @@ fn get_default_time_value(
         DEFAULT_TIME_MILLISECONDS as u128
     };
 
+    check_default_time_value(default_time)
+}
+
+/// Checks that a time window is within the range that graphs can show.
+fn check_default_time_value(default_time: u128) -> error::Result<u64> {
     if default_time < 30000 {
         return Err(BottomError::ConfigError(
             "set your default value to be at least 30000 milliseconds.".to_string(),
@@ -934,8 +1434,34 @@ fn get_no_write(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     false
 }
 
-fn get_ignore_list(ignore_list: &Option<IgnoreList>) -> error::Result<Option<Filter>> {
+/// Converts an [`IgnoreList`] from the config into a [`Filter`].  `valid_fields` are the
+/// `field` values the filter accepts; the first one is used if `field` is not set.
+fn get_ignore_list(
+    ignore_list: &Option<IgnoreList>, valid_fields: &[(&str, FilterField)],
+) -> error::Result<Option<Filter>> {
     if let Some(ignore_list) = ignore_list {
+        let field = match &ignore_list.field {
+            Some(field_name) => valid_fields
+                .iter()
+                .find(|(name, _)| name == field_name)
+                .map(|(_, field)| *field)
+                .ok_or_else(|| {
+                    BottomError::ConfigError(format!(
+                        "\"{}\" is an invalid field, use \"<{}>\".",
+                        field_name,
+                        valid_fields
+                            .iter()
+                            .map(|(name, _)| *name)
+                            .collect::<Vec<_>>()
+                            .join("|")
+                    ))
+                })?,
+            None => valid_fields
+                .first()
+                .map(|(_, field)| *field)
+                .unwrap_or_default(),
+        };
+
         let list: Result<Vec<_>, _> = ignore_list
             .list
             .iter()
@@ -965,18 +1491,36 @@ fn get_ignore_list(ignore_list: &Option<IgnoreList>) -> error::Result<Option<Fil
         Ok(Some(Filter {
             list: list?,
             is_list_ignored: ignore_list.is_list_ignored,
+            field,
         }))
     } else {
         Ok(None)
     }
 }
 
+fn get_saved_filters(config: &Config) -> Vec<SavedFilter> {
+    if let Some(filters) = &config.filters {
+        filters
+            .iter()
+            .map(|(name, filter)| SavedFilter {
+                name: name.clone(),
+                query: filter.query.clone(),
+                is_ignoring_case: !filter.case_sensitive,
+                is_searching_whole_word: filter.whole_word,
+                is_searching_with_regex: filter.regex,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
 pub fn get_color_scheme(
-    matches: &clap::ArgMatches<'static>, config: &Config,
+    matches: &clap::ArgMatches<'static>, config: &Config, config_path: &Option<PathBuf>,
 ) -> error::Result<ColourScheme> {
     if let Some(color) = matches.value_of("color") {
         // Highest priority is always command line flags...
-        return ColourScheme::from_str(color);
+        return get_color_scheme_from_name(color, config_path);
     } else if let Some(colors) = &config.colors {
         if !colors.is_empty() {
             // Then, give priority to custom colours...
@@ -984,13 +1528,13 @@ pub fn get_color_scheme(
         } else if let Some(flags) = &config.flags {
             // Last priority is config file flags...
             if let Some(color) = &flags.color {
-                return ColourScheme::from_str(color);
+                return get_color_scheme_from_name(color, config_path);
             }
         }
     } else if let Some(flags) = &config.flags {
         // Last priority is config file flags...
         if let Some(color) = &flags.color {
-            return ColourScheme::from_str(color);
+            return get_color_scheme_from_name(color, config_path);
         }
     }
 
@@ -998,6 +1542,75 @@ pub fn get_color_scheme(
     Ok(ColourScheme::Default)
 }
 
+/// Returns the built-in colour scheme with the given name, or failing that, the theme file with
+/// the given name in the themes directory next to the config file.
+fn get_color_scheme_from_name(
+    name: &str, config_path: &Option<PathBuf>,
+) -> error::Result<ColourScheme> {
+    if let Ok(colour_scheme) = ColourScheme::from_str(name) {
+        return Ok(colour_scheme);
+    }
+
+    // Theme names are file names, and can't reach outside of the themes directory.
+    if name.contains(['/', '\\']) || name.contains("..") {
+        return Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid theme name, as it can't contain path separators or \"..\".",
+            name
+        )));
+    }
+
+    let theme_path = config_path.as_ref().and_then(|config_path| {
+        config_path.parent().map(|config_dir| {
+            config_dir
+                .join(THEMES_DIRECTORY)
+                .join(format!("{}.toml", name))
+        })
+    });
+
+    if let Some(theme_path) = theme_path {
+        let theme_string = match std::fs::read_to_string(&theme_path) {
+            Ok(theme_string) => theme_string,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(BottomError::ConfigError(format!(
+                    "\"{}\" is not a built-in color scheme, and no theme file was found at {}.",
+                    name,
+                    theme_path.display()
+                )));
+            }
+            Err(err) => {
+                return Err(BottomError::ConfigError(format!(
+                    "failed to read the theme file at {}: {}",
+                    theme_path.display(),
+                    err
+                )));
+            }
+        };
+
+        let theme: ThemeFile = toml::from_str(&theme_string)?;
+        let base = if let Some(base) = &theme.base {
+            ColourScheme::from_str(base).map_err(|_| {
+                BottomError::ConfigError(format!(
+                    "\"{}\" in {} is not a built-in color scheme, and so cannot be used as a base.",
+                    base,
+                    theme_path.display()
+                ))
+            })?
+        } else {
+            ColourScheme::Default
+        };
+
+        Ok(ColourScheme::Theme(
+            Box::new(base),
+            Box::new(theme.colors.unwrap_or_default()),
+        ))
+    } else {
+        Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid built-in color scheme.",
+            name
+        )))
+    }
+}
+
 fn get_mem_as_value(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
     if matches.is_present("mem_as_value") {
         return true;
@@ -1053,6 +1666,191 @@ fn get_is_advanced_kill_disabled(matches: &clap::ArgMatches<'static>, config: &C
     false
 }
 
+fn get_cpu_legend_columns(config: &Config) -> error::Result<Vec<CpuLegendColumn>> {
+    if let Some(flags) = &config.flags {
+        if let Some(cpu_legend_columns) = &flags.cpu_legend_columns {
+            return parse_cpu_legend_columns(cpu_legend_columns);
+        }
+    }
+    Ok(Vec::new())
+}
+
+fn parse_cpu_legend_columns(cpu_legend_columns: &[String]) -> error::Result<Vec<CpuLegendColumn>> {
+    cpu_legend_columns
+        .iter()
+        .map(|column| column.parse::<CpuLegendColumn>())
+        .collect()
+}
+
+fn get_cpu_grouping(config: &Config) -> error::Result<CpuGrouping> {
+    if let Some(flags) = &config.flags {
+        if let Some(cpu_grouping) = &flags.cpu_grouping {
+            return match cpu_grouping.to_lowercase().as_str() {
+                "none" => Ok(CpuGrouping::None),
+                "socket" => Ok(CpuGrouping::Socket),
+                "numa" => Ok(CpuGrouping::NumaNode),
+                "bucket" => match flags.cpu_bucket_size.unwrap_or(DEFAULT_CPU_BUCKET_SIZE) {
+                    0 => Err(BottomError::ConfigError(
+                        "set your CPU bucket size to be at least 1.".to_string(),
+                    )),
+                    bucket_size => Ok(CpuGrouping::Bucket(bucket_size)),
+                },
+                _ => Err(BottomError::ConfigError(format!(
+                    "\"{}\" is an invalid CPU grouping, use \"<none|socket|numa|bucket>\".",
+                    cpu_grouping
+                ))),
+            };
+        }
+    }
+    Ok(CpuGrouping::None)
+}
+
+fn get_disk_columns(config: &Config) -> error::Result<Vec<DiskColumn>> {
+    if let Some(flags) = &config.flags {
+        if let Some(disk_columns) = &flags.disk_columns {
+            return parse_disk_columns(disk_columns);
+        }
+    }
+    Ok(DEFAULT_DISK_COLUMNS.to_vec())
+}
+
+fn parse_disk_columns(disk_columns: &[String]) -> error::Result<Vec<DiskColumn>> {
+    if disk_columns.is_empty() {
+        return Err(BottomError::ConfigError(
+            "please have at least one disk column.".to_string(),
+        ));
+    }
+
+    disk_columns
+        .iter()
+        .map(|column| column.parse::<DiskColumn>())
+        .collect()
+}
+
+fn parse_status_items(status_items: &[String]) -> error::Result<Vec<StatusItem>> {
+    if status_items.is_empty() {
+        return Err(BottomError::ConfigError(
+            "please have at least one status bar item.".to_string(),
+        ));
+    }
+
+    status_items
+        .iter()
+        .map(|item| item.parse::<StatusItem>())
+        .collect()
+}
+
+/// Parses the column a process widget is sorted by, where memory is sorted by whichever of its
+/// columns is shown.
+/// Parses the column a process widget is sorted by.  As only one of the PID and count columns is
+/// shown, depending on whether processes are grouped, either one sorts by whichever is shown, and
+/// likewise for the name and command columns.
+fn parse_process_sort(
+    default_sort: &str, show_memory_as_values: bool, is_grouped: bool, is_using_command: bool,
+) -> error::Result<data_harvester::processes::ProcessSorting> {
+    use data_harvester::processes::ProcessSorting;
+
+    match default_sort.to_lowercase().as_str() {
+        "pid" | "count" if is_grouped => Ok(ProcessSorting::Count),
+        "pid" | "count" => Ok(ProcessSorting::Pid),
+        "name" | "command" if is_using_command => Ok(ProcessSorting::Command),
+        "name" | "command" => Ok(ProcessSorting::ProcessName),
+        "user" | "state" if is_grouped => Err(BottomError::ConfigError(format!(
+            "\"{}\" can't be used to sort grouped processes, as that column isn't shown.",
+            default_sort
+        ))),
+        "cpu" => Ok(ProcessSorting::CpuPercent),
+        "mem" if show_memory_as_values => Ok(ProcessSorting::Mem),
+        "mem" => Ok(ProcessSorting::MemPercent),
+        "read" => Ok(ProcessSorting::ReadPerSecond),
+        "write" => Ok(ProcessSorting::WritePerSecond),
+        "total_read" => Ok(ProcessSorting::TotalRead),
+        "total_write" => Ok(ProcessSorting::TotalWrite),
+        "user" => Ok(ProcessSorting::User),
+        "state" => Ok(ProcessSorting::State),
+        "cpu_history" => Ok(ProcessSorting::CpuHistory),
+        "mem_history" => Ok(ProcessSorting::MemHistory),
+        "mem_growth" => Ok(ProcessSorting::MemGrowth),
+        _ => Err(BottomError::ConfigError(format!(
+            "\"{}\" is an invalid process sort column, use \"<pid|count|name|command|cpu|mem|read|write|total_read|total_write|user|state|cpu_history|mem_history|mem_growth>\".",
+            default_sort
+        ))),
+    }
+}
+
+fn get_process_history_columns(
+    config: &Config,
+) -> error::Result<Vec<data_harvester::processes::ProcessSorting>> {
+    if let Some(flags) = &config.flags {
+        if let Some(process_history_columns) = &flags.process_history_columns {
+            return parse_process_history_columns(process_history_columns);
+        }
+    }
+    Ok(Vec::new())
+}
+
+fn parse_process_history_columns(
+    process_history_columns: &[String],
+) -> error::Result<Vec<data_harvester::processes::ProcessSorting>> {
+    use data_harvester::processes::ProcessSorting;
+
+    process_history_columns
+        .iter()
+        .map(|column| match column.to_lowercase().as_str() {
+            "cpu_history" => Ok(ProcessSorting::CpuHistory),
+            "mem_history" => Ok(ProcessSorting::MemHistory),
+            "mem_growth" => Ok(ProcessSorting::MemGrowth),
+            _ => Err(BottomError::ConfigError(format!(
+                "\"{}\" is an invalid process history column, use \"<cpu_history|mem_history|mem_growth>\".",
+                column
+            ))),
+        })
+        .collect()
+}
+
+fn get_extra_filesystems(
+    matches: &clap::ArgMatches<'static>, config: &Config,
+) -> data_harvester::disks::ExtraFilesystems {
+    use data_harvester::disks::ExtraFilesystems;
+
+    if matches.is_present("all_filesystems") {
+        return ExtraFilesystems::All;
+    } else if let Some(flags) = &config.flags {
+        if let Some(true) = flags.all_filesystems {
+            return ExtraFilesystems::All;
+        } else if let Some(include_filesystems) = &flags.include_filesystems {
+            if !include_filesystems.is_empty() {
+                return ExtraFilesystems::Types(include_filesystems.clone());
+            }
+        }
+    }
+    ExtraFilesystems::None
+}
+
+fn get_disk_history_window(config: &Config) -> error::Result<u64> {
+    let disk_history_window = if let Some(flags) = &config.flags {
+        flags
+            .disk_history_window
+            .unwrap_or(DEFAULT_DISK_HISTORY_MILLISECONDS)
+    } else {
+        DEFAULT_DISK_HISTORY_MILLISECONDS
+    };
+
+    if disk_history_window < DISK_HISTORY_MIN_MILLISECONDS {
+        return Err(BottomError::ConfigError(format!(
+            "set your disk history window to be at least {} milliseconds.",
+            DISK_HISTORY_MIN_MILLISECONDS
+        )));
+    } else if disk_history_window > DISK_HISTORY_MAX_MILLISECONDS {
+        return Err(BottomError::ConfigError(format!(
+            "set your disk history window to be at most {} milliseconds.",
+            DISK_HISTORY_MAX_MILLISECONDS
+        )));
+    }
+
+    Ok(disk_history_window)
+}
+
 fn get_network_unit_type(matches: &clap::ArgMatches<'static>, config: &Config) -> DataUnit {
     if matches.is_present("network_use_bytes") {
         return DataUnit::Byte;
@@ -1091,3 +1889,14 @@ fn get_network_use_binary_prefix(matches: &clap::ArgMatches<'static>, config: &C
     }
     false
 }
+
+fn get_normalize_load_avg(matches: &clap::ArgMatches<'static>, config: &Config) -> bool {
+    if matches.is_present("normalize_load_avg") {
+        return true;
+    } else if let Some(flags) = &config.flags {
+        if let Some(normalize_load_avg) = flags.normalize_load_avg {
+            return normalize_load_avg;
+        }
+    }
+    false
+}
@@ -1,6 +1,18 @@
-use crate::app::layout_manager::*;
-use crate::error::Result;
+use crate::app::{layout_manager::*, GraphStyle};
+use crate::error::{BottomError, Result};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Represents a named layout, which can be switched to as a tab.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ConfigLayout {
+    pub name: String,
+    /// The smallest terminal width this layout is used for, if other layouts share its name.
+    pub min_width: Option<u16>,
+    /// The smallest terminal height this layout is used for, if other layouts share its name.
+    pub min_height: Option<u16>,
+    pub row: Vec<Row>,
+}
 
 /// Represents a row.  This has a length of some sort (optional) and a vector
 /// of children.
@@ -8,21 +20,70 @@ use serde::{Deserialize, Serialize};
 #[serde(rename = "row")]
 pub struct Row {
     pub ratio: Option<u32>,
+    #[serde(flatten)]
+    pub size: SizeOptions,
+    pub priority: Option<u32>,
     pub child: Option<Vec<RowChildren>>,
 }
 
+/// Sizes for a row, column, or widget that are used instead of its ratio.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SizeOptions {
+    /// A fixed number of lines or columns.
+    pub length: Option<u16>,
+    /// A percentage of the parent's lines or columns.
+    pub percentage: Option<u16>,
+    /// Takes up at least this many lines or columns, sharing any space left like a ratio of 1.
+    pub min_length: Option<u16>,
+    /// Takes up at most this many lines or columns, sharing any space left like a ratio of 1.
+    pub max_length: Option<u16>,
+}
+
+impl SizeOptions {
+    /// Returns the size to use instead of a ratio, if any.  Only one of the ratio and the sizes
+    /// can be set.
+    fn get_layout_size(&self, ratio: Option<u32>) -> Result<Option<LayoutSize>> {
+        let sizes = [
+            self.length.map(LayoutSize::Length),
+            self.percentage.map(LayoutSize::Percentage),
+            self.min_length.map(LayoutSize::Min),
+            self.max_length.map(LayoutSize::Max),
+        ];
+        let mut set_sizes = sizes.iter().flatten();
+        let size = set_sizes.next().copied();
+
+        if set_sizes.next().is_some() || (size.is_some() && ratio.is_some()) {
+            Err(BottomError::ConfigError(
+                "please only set one of 'ratio', 'length', 'percentage', 'min_length', or 'max_length' for each part of the layout.".to_string(),
+            ))
+        } else if matches!(size, Some(LayoutSize::Percentage(percentage)) if percentage > 100) {
+            Err(BottomError::ConfigError(
+                "please set a 'percentage' between 0 and 100.".to_string(),
+            ))
+        } else {
+            Ok(size)
+        }
+    }
+
+    /// Whether this sets a size that doesn't share the space left over with ratios.
+    pub fn is_fixed(&self) -> bool {
+        self.length.is_some() || self.percentage.is_some()
+    }
+}
+
 impl Row {
     pub fn convert_row_to_bottom_row(
         &self, iter_id: &mut u64, total_height_ratio: &mut u32, default_widget_id: &mut u64,
         default_widget_type: &Option<BottomWidgetType>, default_widget_count: &mut u64,
         left_legend: bool,
     ) -> Result<BottomRow> {
-        // TODO: In the future we want to also add percentages.
-        // But for MVP, we aren't going to bother.
         let row_ratio = self.ratio.unwrap_or(1);
+        let row_size = self.size.get_layout_size(self.ratio)?;
         let mut children = Vec::new();
 
-        *total_height_ratio += row_ratio;
+        if row_size.is_none() {
+            *total_height_ratio += row_ratio;
+        }
 
         let mut total_col_ratio = 0;
         if let Some(row_children) = &self.child {
@@ -31,8 +92,17 @@ impl Row {
                     RowChildren::Widget(widget) => {
                         *iter_id += 1;
                         let width_ratio = widget.ratio.unwrap_or(1);
-                        total_col_ratio += width_ratio;
+                        let width_size = widget.size.get_layout_size(widget.ratio)?;
+                        if width_size.is_none() {
+                            total_col_ratio += width_ratio;
+                        }
                         let widget_type = widget.widget_type.parse::<BottomWidgetType>()?;
+                        let graph_style = widget
+                            .graph_style
+                            .as_ref()
+                            .map(|graph_style| graph_style.parse::<GraphStyle>())
+                            .transpose()?;
+                        let widget_left_legend = widget.left_legend.unwrap_or(left_legend);
 
                         if let Some(default_widget_type_val) = default_widget_type {
                             if *default_widget_type_val == widget_type && *default_widget_count > 0
@@ -52,18 +122,22 @@ impl Row {
                         }
 
                         children.push(match widget_type {
-                            BottomWidgetType::Cpu => {
-                                let cpu_id = *iter_id;
+                            BottomWidgetType::Cpu | BottomWidgetType::TempGraph => {
+                                let graph_id = *iter_id;
+                                let legend_type = widget_type.get_legend_type().unwrap_or_default();
                                 *iter_id += 1;
                                 BottomCol::builder()
                                     .col_width_ratio(width_ratio)
-                                    .children(if left_legend {
+                                    .size(width_size)
+                                    .priority(widget.priority)
+                                    .children(if widget_left_legend {
                                         vec![BottomColRow::builder()
                                             .total_widget_ratio(20)
                                             .children(vec![
                                                 BottomWidget::builder()
                                                     .width_ratio(3)
-                                                    .widget_type(BottomWidgetType::CpuLegend)
+                                                    .widget_type(legend_type.clone())
+                                                    .left_legend(widget_left_legend)
                                                     .widget_id(*iter_id)
                                                     .canvas_handle_width(true)
                                                     .parent_reflector(Some((
@@ -73,9 +147,12 @@ impl Row {
                                                     .build(),
                                                 BottomWidget::builder()
                                                     .width_ratio(17)
-                                                    .widget_type(BottomWidgetType::Cpu)
-                                                    .widget_id(cpu_id)
+                                                    .widget_type(widget_type.clone())
+                                                    .widget_id(graph_id)
                                                     .flex_grow(true)
+                                                    .graph_style(graph_style)
+                                                    .left_legend(widget_left_legend)
+                                                    .options(widget.options.clone())
                                                     .build(),
                                             ])
                                             .build()]
@@ -85,13 +162,17 @@ impl Row {
                                             .children(vec![
                                                 BottomWidget::builder()
                                                     .width_ratio(17)
-                                                    .widget_type(BottomWidgetType::Cpu)
-                                                    .widget_id(cpu_id)
+                                                    .widget_type(widget_type.clone())
+                                                    .widget_id(graph_id)
                                                     .flex_grow(true)
+                                                    .graph_style(graph_style)
+                                                    .left_legend(widget_left_legend)
+                                                    .options(widget.options.clone())
                                                     .build(),
                                                 BottomWidget::builder()
                                                     .width_ratio(3)
-                                                    .widget_type(BottomWidgetType::CpuLegend)
+                                                    .widget_type(legend_type.clone())
+                                                    .left_legend(widget_left_legend)
                                                     .widget_id(*iter_id)
                                                     .canvas_handle_width(true)
                                                     .parent_reflector(Some((
@@ -111,6 +192,8 @@ impl Row {
                                 BottomCol::builder()
                                     .total_col_row_ratio(2)
                                     .col_width_ratio(width_ratio)
+                                    .size(width_size)
+                                    .priority(widget.priority)
                                     .children(vec![
                                         BottomColRow::builder()
                                             .children(vec![
@@ -128,6 +211,8 @@ impl Row {
                                                     .widget_type(BottomWidgetType::Proc)
                                                     .widget_id(proc_id)
                                                     .width_ratio(2)
+                                                    .default_filter(widget.default_filter.clone())
+                                                    .options(widget.options.clone())
                                                     .build(),
                                             ])
                                             .total_widget_ratio(3)
@@ -146,18 +231,30 @@ impl Row {
                             }
                             _ => BottomCol::builder()
                                 .col_width_ratio(width_ratio)
+                                .size(width_size)
+                                .priority(widget.priority)
                                 .children(vec![BottomColRow::builder()
                                     .children(vec![BottomWidget::builder()
                                         .widget_type(widget_type)
                                         .widget_id(*iter_id)
+                                        .graph_style(graph_style)
+                                        .options(widget.options.clone())
                                         .build()])
                                     .build()])
                                 .build(),
                         });
                     }
-                    RowChildren::Col { ratio, child } => {
+                    RowChildren::Col {
+                        ratio,
+                        size,
+                        priority,
+                        child,
+                    } => {
                         let col_width_ratio = ratio.unwrap_or(1);
-                        total_col_ratio += col_width_ratio;
+                        let col_width_size = size.get_layout_size(*ratio)?;
+                        if col_width_size.is_none() {
+                            total_col_ratio += col_width_ratio;
+                        }
                         let mut total_col_row_ratio = 0;
                         let mut contains_proc = false;
 
@@ -165,9 +262,18 @@ impl Row {
 
                         for widget in child {
                             let widget_type = widget.widget_type.parse::<BottomWidgetType>()?;
+                            let graph_style = widget
+                                .graph_style
+                                .as_ref()
+                                .map(|graph_style| graph_style.parse::<GraphStyle>())
+                                .transpose()?;
+                            let widget_left_legend = widget.left_legend.unwrap_or(left_legend);
                             *iter_id += 1;
                             let col_row_height_ratio = widget.ratio.unwrap_or(1);
-                            total_col_row_ratio += col_row_height_ratio;
+                            let col_row_height_size = widget.size.get_layout_size(widget.ratio)?;
+                            if col_row_height_size.is_none() {
+                                total_col_row_ratio += col_row_height_ratio;
+                            }
 
                             if let Some(default_widget_type_val) = default_widget_type {
                                 if *default_widget_type_val == widget_type
@@ -188,18 +294,23 @@ impl Row {
                             }
 
                             match widget_type {
-                                BottomWidgetType::Cpu => {
-                                    let cpu_id = *iter_id;
+                                BottomWidgetType::Cpu | BottomWidgetType::TempGraph => {
+                                    let graph_id = *iter_id;
+                                    let legend_type =
+                                        widget_type.get_legend_type().unwrap_or_default();
                                     *iter_id += 1;
-                                    if left_legend {
+                                    if widget_left_legend {
                                         col_row_children.push(
                                             BottomColRow::builder()
                                                 .col_row_height_ratio(col_row_height_ratio)
+                                                .size(col_row_height_size)
+                                                .priority(widget.priority)
                                                 .total_widget_ratio(20)
                                                 .children(vec![
                                                     BottomWidget::builder()
                                                         .width_ratio(3)
-                                                        .widget_type(BottomWidgetType::CpuLegend)
+                                                        .widget_type(legend_type.clone())
+                                                        .left_legend(widget_left_legend)
                                                         .widget_id(*iter_id)
                                                         .canvas_handle_width(true)
                                                         .parent_reflector(Some((
@@ -209,9 +320,12 @@ impl Row {
                                                         .build(),
                                                     BottomWidget::builder()
                                                         .width_ratio(17)
-                                                        .widget_type(BottomWidgetType::Cpu)
-                                                        .widget_id(cpu_id)
+                                                        .widget_type(widget_type.clone())
+                                                        .widget_id(graph_id)
                                                         .flex_grow(true)
+                                                        .graph_style(graph_style)
+                                                        .left_legend(widget_left_legend)
+                                                        .options(widget.options.clone())
                                                         .build(),
                                                 ])
                                                 .build(),
@@ -220,17 +334,23 @@ impl Row {
                                         col_row_children.push(
                                             BottomColRow::builder()
                                                 .col_row_height_ratio(col_row_height_ratio)
+                                                .size(col_row_height_size)
+                                                .priority(widget.priority)
                                                 .total_widget_ratio(20)
                                                 .children(vec![
                                                     BottomWidget::builder()
                                                         .width_ratio(17)
-                                                        .widget_type(BottomWidgetType::Cpu)
-                                                        .widget_id(cpu_id)
+                                                        .widget_type(widget_type.clone())
+                                                        .widget_id(graph_id)
                                                         .flex_grow(true)
+                                                        .graph_style(graph_style)
+                                                        .left_legend(widget_left_legend)
+                                                        .options(widget.options.clone())
                                                         .build(),
                                                     BottomWidget::builder()
                                                         .width_ratio(3)
-                                                        .widget_type(BottomWidgetType::CpuLegend)
+                                                        .widget_type(legend_type.clone())
+                                                        .left_legend(widget_left_legend)
                                                         .widget_id(*iter_id)
                                                         .canvas_handle_width(true)
                                                         .parent_reflector(Some((
@@ -265,9 +385,13 @@ impl Row {
                                                     .widget_type(BottomWidgetType::Proc)
                                                     .widget_id(proc_id)
                                                     .width_ratio(2)
+                                                    .default_filter(widget.default_filter.clone())
+                                                    .options(widget.options.clone())
                                                     .build(),
                                             ])
                                             .col_row_height_ratio(col_row_height_ratio)
+                                            .size(col_row_height_size)
+                                            .priority(widget.priority)
                                             .total_widget_ratio(3)
                                             .build(),
                                     );
@@ -286,9 +410,13 @@ impl Row {
                                 _ => col_row_children.push(
                                     BottomColRow::builder()
                                         .col_row_height_ratio(col_row_height_ratio)
+                                        .size(col_row_height_size)
+                                        .priority(widget.priority)
                                         .children(vec![BottomWidget::builder()
                                             .widget_type(widget_type)
                                             .widget_id(*iter_id)
+                                            .graph_style(graph_style)
+                                            .options(widget.options.clone())
                                             .build()])
                                         .build(),
                                 ),
@@ -313,6 +441,8 @@ impl Row {
                             BottomCol::builder()
                                 .total_col_row_ratio(total_col_row_ratio)
                                 .col_width_ratio(col_width_ratio)
+                                .size(col_width_size)
+                                .priority(*priority)
                                 .children(col_row_children)
                                 .build(),
                         );
@@ -324,6 +454,8 @@ impl Row {
         Ok(BottomRow::builder()
             .total_col_ratio(total_col_ratio)
             .row_height_ratio(row_ratio)
+            .size(row_size)
+            .priority(self.priority)
             .children(children)
             .build())
     }
@@ -340,6 +472,9 @@ pub enum RowChildren {
     Widget(FinalWidget),
     Col {
         ratio: Option<u32>,
+        #[serde(flatten)]
+        size: SizeOptions,
+        priority: Option<u32>,
         child: Vec<FinalWidget>,
     },
 }
@@ -348,7 +483,149 @@ pub enum RowChildren {
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct FinalWidget {
     pub ratio: Option<u32>,
+    #[serde(flatten)]
+    pub size: SizeOptions,
+    /// Lets the widget be hidden when there isn't enough room, with lower priorities hidden first.
+    pub priority: Option<u32>,
     #[serde(rename = "type")]
     pub widget_type: String,
     pub default: Option<bool>,
+    /// The name of a saved filter to apply on startup.  Only used by process widgets.
+    pub default_filter: Option<String>,
+    /// How to draw the graph.  Only used by graph widgets.
+    pub graph_style: Option<String>,
+    /// Whether to draw the legend on the left.  Only used by graph widgets with legends.
+    pub left_legend: Option<bool>,
+    #[serde(flatten)]
+    pub options: WidgetOptions,
+}
+
+/// Where a widget is in the rows of a layout, and the IDs it was given.
+#[derive(Clone, Debug)]
+pub struct WidgetPosition {
+    pub row: usize,
+    pub child: usize,
+    /// Which widget of the column it is, if the child is a column.
+    pub col_child: Option<usize>,
+    pub widget_ids: Range<u64>,
+}
+
+impl FinalWidget {
+    /// Creates a widget of the given type, which takes up the same space as this one.
+    pub fn replace_type(&self, widget_type: &str) -> FinalWidget {
+        FinalWidget {
+            ratio: self.ratio,
+            size: self.size.clone(),
+            priority: self.priority,
+            widget_type: widget_type.to_string(),
+            default: self.default,
+            default_filter: None,
+            graph_style: None,
+            left_legend: None,
+            options: WidgetOptions::default(),
+        }
+    }
+
+    /// Returns how many IDs this widget takes up, as some widgets are made of multiple parts.
+    fn get_id_count(&self) -> u64 {
+        match self.widget_type.parse::<BottomWidgetType>() {
+            Ok(BottomWidgetType::Cpu) | Ok(BottomWidgetType::TempGraph) => 2,
+            Ok(BottomWidgetType::Proc) => 3,
+            _ => 1,
+        }
+    }
+}
+
+/// Returns where each widget is in the given rows, giving out IDs the same way as
+/// [`Row::convert_row_to_bottom_row`].
+pub fn get_widget_positions(rows: &[Row], iter_id: &mut u64) -> Vec<WidgetPosition> {
+    let mut positions = Vec::new();
+    let mut add_position =
+        |row: usize, child: usize, col_child: Option<usize>, widget: &FinalWidget| {
+            let first_id = *iter_id + 1;
+            *iter_id += widget.get_id_count();
+            positions.push(WidgetPosition {
+                row,
+                child,
+                col_child,
+                widget_ids: first_id..*iter_id + 1,
+            });
+        };
+
+    for (row_index, row) in rows.iter().enumerate() {
+        for (child_index, row_child) in row.child.iter().flatten().enumerate() {
+            match row_child {
+                RowChildren::Widget(widget) => add_position(row_index, child_index, None, widget),
+                RowChildren::Col { child, .. } => {
+                    for (col_child_index, widget) in child.iter().enumerate() {
+                        add_position(row_index, child_index, Some(col_child_index), widget);
+                    }
+                }
+            }
+        }
+    }
+
+    positions
+}
+
+/// Returns the widget at a position.
+pub fn get_widget_mut<'a>(
+    rows: &'a mut [Row], position: &WidgetPosition,
+) -> Option<&'a mut FinalWidget> {
+    let row_child = rows
+        .get_mut(position.row)?
+        .child
+        .as_mut()?
+        .get_mut(position.child)?;
+
+    match (row_child, position.col_child) {
+        (RowChildren::Widget(widget), None) => Some(widget),
+        (RowChildren::Col { child, .. }, Some(col_child)) => child.get_mut(col_child),
+        _ => None,
+    }
+}
+
+/// Returns the ratios and sizes that a widget is sized against, along with which of them is the
+/// widget's own.  Widths are set by the row's children, while heights are set by the widgets of the
+/// column if it shares one, or otherwise by the rows.
+pub fn get_sizes_mut<'a>(
+    rows: &'a mut [Row], position: &WidgetPosition, is_vertical: bool,
+) -> Option<(Vec<(&'a mut Option<u32>, &'a mut SizeOptions)>, usize)> {
+    if !is_vertical {
+        let sizes = rows
+            .get_mut(position.row)?
+            .child
+            .as_mut()?
+            .iter_mut()
+            .map(|row_child| match row_child {
+                RowChildren::Widget(widget) => (&mut widget.ratio, &mut widget.size),
+                RowChildren::Col { ratio, size, .. } => (ratio, size),
+            })
+            .collect();
+        return Some((sizes, position.child));
+    }
+
+    let is_sharing_col = matches!(
+        rows.get(position.row)?.child.as_ref()?.get(position.child)?,
+        RowChildren::Col { child, .. } if child.len() > 1
+    );
+
+    if is_sharing_col {
+        if let RowChildren::Col { child, .. } =
+            rows[position.row].child.as_mut()?.get_mut(position.child)?
+        {
+            let sizes = child
+                .iter_mut()
+                .map(|widget| (&mut widget.ratio, &mut widget.size))
+                .collect();
+            return Some((sizes, position.col_child?));
+        }
+        None
+    } else {
+        let sizes = rows
+            .iter_mut()
+            .map(|row| (&mut row.ratio, &mut row.size))
+            .collect();
+        Some((sizes, position.row))
+    }
 }
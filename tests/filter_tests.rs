@@ -0,0 +1,203 @@
+//! Tests for how data filters decide which entries are kept.
+
+use bottom::app::{Filter, FilterField};
+use regex::Regex;
+
+fn new_filter(is_list_ignored: bool, list: &[&str], field: FilterField) -> Filter {
+    Filter {
+        is_list_ignored,
+        list: list
+            .iter()
+            .map(|pattern| Regex::new(pattern).unwrap())
+            .collect(),
+        field,
+    }
+}
+
+#[test]
+/// Tests that a denylist only keeps entries that don't match.
+fn test_denylist() {
+    let filter = new_filter(true, &["^nvme", "loop"], FilterField::Name);
+
+    assert!(!filter.keep_entry("nvme0n1"));
+    assert!(!filter.keep_entry("loop0"));
+    assert!(filter.keep_entry("sda1"));
+}
+
+#[test]
+/// Tests that an allowlist only keeps entries that match.
+fn test_allowlist() {
+    let filter = new_filter(false, &["^nvme", "loop"], FilterField::Name);
+
+    assert!(filter.keep_entry("nvme0n1"));
+    assert!(filter.keep_entry("loop0"));
+    assert!(!filter.keep_entry("sda1"));
+}
+
+#[test]
+/// Tests that an empty allowlist keeps nothing, while an empty denylist keeps everything.
+fn test_empty_lists() {
+    let allow_filter = new_filter(false, &[], FilterField::Name);
+    let deny_filter = new_filter(true, &[], FilterField::Name);
+
+    assert!(!allow_filter.keep_entry("sda1"));
+    assert!(deny_filter.keep_entry("sda1"));
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+mod disk_filters {
+    use super::new_filter;
+    use bottom::app::{data_harvester::disks::keep_disk_entry, FilterField};
+
+    #[test]
+    /// Tests that disks are kept when there are no filters.
+    fn test_no_filters() {
+        assert!(keep_disk_entry(
+            &None,
+            &None,
+            "/dev/sda1",
+            "ext4",
+            "/",
+            "rw,relatime"
+        ));
+    }
+
+    #[test]
+    /// Tests that a disk allowlist drops disks that it doesn't match.
+    fn test_disk_allowlist() {
+        let disk_filter = Some(new_filter(false, &["nvme"], FilterField::Name));
+
+        assert!(keep_disk_entry(
+            &disk_filter,
+            &None,
+            "/dev/nvme0n1p1",
+            "ext4",
+            "/",
+            "rw"
+        ));
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &None,
+            "/dev/sda1",
+            "ext4",
+            "/",
+            "rw"
+        ));
+    }
+
+    #[test]
+    /// Tests that an allowing mount filter wins over a denying disk filter.
+    fn test_mount_allow_beats_disk_deny() {
+        let disk_filter = Some(new_filter(true, &["sda"], FilterField::Name));
+        let mount_filter = Some(new_filter(false, &["^/home$"], FilterField::Name));
+
+        assert!(keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sda1",
+            "ext4",
+            "/home",
+            "rw"
+        ));
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sda2",
+            "ext4",
+            "/boot",
+            "rw"
+        ));
+    }
+
+    #[test]
+    /// Tests that an allowing disk filter wins over a denying mount filter.
+    fn test_disk_allow_beats_mount_deny() {
+        let disk_filter = Some(new_filter(false, &["nvme"], FilterField::Name));
+        let mount_filter = Some(new_filter(true, &["^/boot"], FilterField::Name));
+
+        assert!(keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/nvme0n1p1",
+            "vfat",
+            "/boot/efi",
+            "rw"
+        ));
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sda1",
+            "ext4",
+            "/data",
+            "rw"
+        ));
+    }
+
+    #[test]
+    /// Tests that a denylist still drops disks when the other filter doesn't allow them.
+    fn test_deny_without_allow() {
+        let disk_filter = Some(new_filter(true, &["loop"], FilterField::Name));
+        let mount_filter = Some(new_filter(true, &["^/snap"], FilterField::Name));
+
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/loop0",
+            "squashfs",
+            "/var/lib/loop",
+            "ro"
+        ));
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sdb1",
+            "squashfs",
+            "/snap/core",
+            "ro"
+        ));
+        assert!(keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sda1",
+            "ext4",
+            "/",
+            "rw"
+        ));
+    }
+
+    #[test]
+    /// Tests that filters match against the filesystem type and mount options if set to.
+    fn test_filter_fields() {
+        let disk_filter = Some(new_filter(true, &["^tmpfs$"], FilterField::FileSystem));
+        let mount_filter = Some(new_filter(
+            true,
+            &["(^|,)ro(,|$)"],
+            FilterField::MountOptions,
+        ));
+
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "tmpfs",
+            "tmpfs",
+            "/tmp",
+            "rw"
+        ));
+        assert!(!keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sdb1",
+            "ext4",
+            "/mnt",
+            "ro,relatime"
+        ));
+        assert!(keep_disk_entry(
+            &disk_filter,
+            &mount_filter,
+            "/dev/sda1",
+            "ext4",
+            "/",
+            "rw,relatime"
+        ));
+    }
+}
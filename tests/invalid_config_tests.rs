@@ -38,6 +38,16 @@ fn test_invalid_layout_widget_type() {
         .stderr(predicate::str::contains("invalid widget name"));
 }
 
+#[test]
+fn test_invalid_graph_style() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_graph_style.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid graph style"));
+}
+
 /// This test isn't really needed as this is technically covered by TOML spec.
 /// However, I feel like it's worth checking anyways - not like it takes long.
 #[test]
@@ -94,6 +104,16 @@ fn test_invalid_colour_name() {
         .stderr(predicate::str::contains("invalid named colour"));
 }
 
+#[test]
+fn test_invalid_gradient_colour() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_gradient_colour.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid named colour"));
+}
+
 #[test]
 fn test_invalid_colour_rgb() {
     Command::new(get_binary_location())
@@ -143,3 +163,179 @@ fn test_invalid_default_widget_count() {
         .failure()
         .stderr(predicate::str::contains("invalid number"));
 }
+
+#[test]
+fn test_invalid_default_filter() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_default_filter.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a saved filter"));
+}
+
+#[test]
+fn test_invalid_filter_field() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_filter_field.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid field"));
+}
+
+#[test]
+fn test_invalid_disk_column() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_disk_column.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid disk column"));
+}
+
+#[test]
+fn test_invalid_cpu_legend_column() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_cpu_legend_column.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid CPU legend column"));
+}
+
+#[test]
+fn test_invalid_cpu_grouping() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_cpu_grouping.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid CPU grouping"));
+}
+
+#[test]
+fn test_invalid_process_history_column() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_process_history_column.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is an invalid process history column",
+        ));
+}
+
+#[test]
+fn test_theme_invalid_base() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/theme_invalid_base.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used as a base"));
+}
+
+#[test]
+fn test_theme_missing() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/theme_missing.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no theme file was found"));
+}
+
+#[test]
+fn test_theme_invalid_name() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/theme_invalid_name.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid theme name"));
+}
+
+#[test]
+fn test_invalid_widget_default_sort() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_widget_default_sort.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid process sort column"));
+}
+
+#[test]
+fn test_invalid_grouped_default_sort() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_grouped_default_sort.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "can't be used to sort grouped processes",
+        ));
+}
+
+#[test]
+fn test_row_and_layout() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/row_and_layout.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not both"));
+}
+
+#[test]
+fn test_empty_named_layout() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/empty_named_layout.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "at least one widget in the \"Processes\" layout",
+        ));
+}
+
+#[test]
+fn test_ratio_and_length() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/ratio_and_length.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("please only set one of"));
+}
+
+#[test]
+fn test_invalid_percentage() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_percentage.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("between 0 and 100"));
+}
+
+#[test]
+fn test_invalid_basic_widget() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_basic_widget.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid basic mode widget"));
+}
+
+#[test]
+fn test_invalid_status_item() {
+    Command::new(get_binary_location())
+        .arg("-C")
+        .arg("./tests/invalid_configs/invalid_status_item.toml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is an invalid status bar item"));
+}
@@ -1,10 +1,16 @@
 //! Mocks layout management, so we can check if we broke anything.
 
-use bottom::app::layout_manager::{BottomLayout, BottomWidgetType};
+use bottom::app::{
+    layout_manager::{BottomLayout, BottomWidgetType},
+    LayoutTabState,
+};
 #[cfg(feature = "battery")]
 use bottom::constants::DEFAULT_BATTERY_LAYOUT;
 use bottom::constants::{DEFAULT_LAYOUT, DEFAULT_WIDGET_ID};
-use bottom::options::{layout_options::Row, Config};
+use bottom::options::{
+    layout_options::{get_widget_positions, Row},
+    replace_layout_in_config_text, Config,
+};
 use bottom::utils::error;
 
 const PROC_LAYOUT: &str = r##"
@@ -48,6 +54,10 @@ fn test_create_layout(
             .collect::<error::Result<Vec<_>>>()
             .unwrap(),
         total_row_height_ratio: total_height_ratio,
+        name: String::default(),
+        default_widget_id,
+        min_width: 0,
+        min_height: 0,
     };
     ret_bottom_layout.get_movement_mappings();
 
@@ -264,6 +274,10 @@ fn test_default_widget_in_layout() {
             .collect::<error::Result<Vec<_>>>()
             .unwrap(),
         total_row_height_ratio: total_height_ratio,
+        name: String::default(),
+        default_widget_id,
+        min_width: 0,
+        min_height: 0,
     };
     ret_bottom_layout.get_movement_mappings();
 
@@ -297,6 +311,10 @@ fn test_default_widget_by_option() {
             .collect::<error::Result<Vec<_>>>()
             .unwrap(),
         total_row_height_ratio: total_height_ratio,
+        name: String::default(),
+        default_widget_id,
+        min_width: 0,
+        min_height: 0,
     };
     ret_bottom_layout.get_movement_mappings();
 
@@ -470,3 +488,287 @@ fn test_proc_custom_layout() {
         Some(13)
     );
 }
+
+#[test]
+/// Tests that a temperature graph gets a legend, just like the CPU widget.
+fn test_temp_graph_layout() {
+    let temp_graph_layout = r##"
+    [[row]]
+        [[row.child]]
+            type="temp_graph"
+        [[row.child]]
+            type="temp"
+    "##;
+    let rows = toml::from_str::<Config>(temp_graph_layout)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    // Graph
+    let graph = &ret_bottom_layout.rows[0].children[0].children[0].children[0];
+    assert_eq!(graph.widget_type, BottomWidgetType::TempGraph);
+    assert_eq!(graph.widget_id, 1);
+    assert_eq!(graph.right_neighbour, Some(2));
+
+    // Legend
+    let legend = &ret_bottom_layout.rows[0].children[0].children[0].children[1];
+    assert_eq!(legend.widget_type, BottomWidgetType::TempGraphLegend);
+    assert_eq!(legend.widget_id, 2);
+    assert_eq!(legend.left_neighbour, Some(1));
+    assert_eq!(legend.right_neighbour, Some(3));
+
+    // Table
+    assert_eq!(
+        ret_bottom_layout.rows[0].children[1].children[0].children[0].left_neighbour,
+        Some(2)
+    );
+}
+
+#[test]
+/// Tests that the layout editor finds the same widget IDs as the ones the layout is built with.
+fn test_widget_positions() {
+    let rows = toml::from_str::<Config>(DEFAULT_LAYOUT)
+        .unwrap()
+        .row
+        .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let mut widget_ids: Vec<u64> = ret_bottom_layout
+        .rows
+        .iter()
+        .flat_map(|row| &row.children)
+        .flat_map(|col| &col.children)
+        .flat_map(|col_row| &col_row.children)
+        .map(|widget| widget.widget_id)
+        .collect();
+    widget_ids.sort_unstable();
+
+    let mut iter_id = 0;
+    let positions = get_widget_positions(&rows, &mut iter_id);
+    let position_ids: Vec<u64> = positions
+        .iter()
+        .flat_map(|position| position.widget_ids.clone())
+        .collect();
+
+    assert_eq!(positions.len(), 6);
+    assert_eq!(widget_ids, position_ids);
+}
+
+#[test]
+/// Tests that layouts sharing a name are shown under one tab.
+fn test_layouts_sharing_tabs() {
+    let layout_tab_state = LayoutTabState::init(
+        vec![
+            ("Main".to_string(), 1),
+            ("Processes".to_string(), 4),
+            ("Main".to_string(), 7),
+        ],
+        2,
+    );
+
+    assert_eq!(layout_tab_state.tab_names, vec!["Main", "Processes"]);
+    assert_eq!(layout_tab_state.layout_tabs, vec![0, 1, 0]);
+    assert_eq!(layout_tab_state.current_tab, 0);
+    assert_eq!(
+        layout_tab_state.get_tab_layouts(0).collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+}
+
+#[test]
+/// Tests that priorities are given to the parts of the layout they are set on.
+fn test_layout_priorities() {
+    let rows = toml::from_str::<Config>(
+        r##"
+[[row]]
+priority=1
+    [[row.child]]
+    type="cpu"
+    priority=2
+    [[row.child]]
+    type="mem"
+[[row]]
+    [[row.child]]
+        [[row.child.child]]
+        type="temp"
+        priority=3
+        [[row.child.child]]
+        type="proc"
+        priority=4
+"##,
+    )
+    .unwrap()
+    .row
+    .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let row_priorities: Vec<_> = ret_bottom_layout
+        .rows
+        .iter()
+        .map(|row| row.priority)
+        .collect();
+    let col_priorities: Vec<_> = ret_bottom_layout.rows[0]
+        .children
+        .iter()
+        .map(|col| col.priority)
+        .collect();
+    let col_row_priorities: Vec<_> = ret_bottom_layout.rows[1].children[0]
+        .children
+        .iter()
+        .map(|col_row| col_row.priority)
+        .collect();
+
+    assert_eq!(row_priorities, vec![Some(1), None]);
+    assert_eq!(col_priorities, vec![Some(2), None]);
+    // The process search is part of the process widget, so it is hidden along with it.
+    assert_eq!(col_row_priorities, vec![Some(3), Some(4), None]);
+}
+
+#[test]
+/// Tests that the basic mode header gives the CPU widget its own row and pairs up the rest.
+fn test_basic_header_rows() {
+    let ret_bottom_layout = BottomLayout::init_basic(
+        &[
+            BottomWidgetType::BasicMem,
+            BottomWidgetType::BasicCpu,
+            BottomWidgetType::BasicNet,
+            BottomWidgetType::BasicTemp,
+            BottomWidgetType::BasicLoadAvg,
+        ],
+        false,
+    );
+
+    let rows: Vec<Vec<_>> = ret_bottom_layout
+        .rows
+        .iter()
+        .map(|row| {
+            row.children
+                .iter()
+                .flat_map(|col| &col.children)
+                .flat_map(|col_row| &col_row.children)
+                .collect()
+        })
+        .collect();
+    let header_types: Vec<Vec<_>> = rows
+        .iter()
+        .take(4)
+        .map(|widgets| {
+            widgets
+                .iter()
+                .map(|widget| widget.widget_type.clone())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(
+        header_types,
+        vec![
+            vec![BottomWidgetType::BasicMem],
+            vec![BottomWidgetType::BasicCpu],
+            vec![BottomWidgetType::BasicNet, BottomWidgetType::BasicTemp],
+            vec![BottomWidgetType::BasicLoadAvg],
+        ]
+    );
+    assert_eq!(rows[4][0].widget_type, BottomWidgetType::BasicTables);
+
+    // Moving between rows goes to the first widget of each.
+    let net = rows[2][0];
+    let temp = rows[2][1];
+    assert_eq!(net.up_neighbour, Some(rows[1][0].widget_id));
+    assert_eq!(temp.down_neighbour, Some(rows[3][0].widget_id));
+    assert_eq!(net.right_neighbour, Some(temp.widget_id));
+    assert_eq!(temp.left_neighbour, Some(net.widget_id));
+    assert_eq!(rows[3][0].down_neighbour, Some(rows[4][0].widget_id));
+    assert_eq!(rows[4][0].up_neighbour, Some(rows[3][0].widget_id));
+}
+
+#[test]
+/// Tests that status bars can't be moved to or start selected.
+fn test_status_bar_not_selectable() {
+    let rows = toml::from_str::<Config>(
+        r##"
+[[row]]
+length=1
+    [[row.child]]
+    type="status_bar"
+[[row]]
+    [[row.child]]
+    type="cpu"
+    [[row.child]]
+    type="status"
+[[row]]
+    [[row.child]]
+    type="proc"
+"##,
+    )
+    .unwrap()
+    .row
+    .unwrap();
+    let ret_bottom_layout = test_create_layout(&rows, DEFAULT_WIDGET_ID, None, 1, false);
+
+    let widgets: Vec<_> = ret_bottom_layout
+        .rows
+        .iter()
+        .flat_map(|row| &row.children)
+        .flat_map(|col| &col.children)
+        .flat_map(|col_row| &col_row.children)
+        .collect();
+    let status_bar = widgets[0];
+    let cpu = widgets[1];
+
+    assert_eq!(status_bar.widget_type, BottomWidgetType::StatusBar);
+    assert_eq!(status_bar.down_neighbour, None);
+    assert_eq!(cpu.widget_type, BottomWidgetType::Cpu);
+    assert_eq!(cpu.up_neighbour, None);
+    assert_eq!(
+        ret_bottom_layout.get_first_selectable_widget_id(),
+        Some(cpu.widget_id)
+    );
+}
+
+#[test]
+/// Tests that saving a layout only replaces the layout in the config file.
+fn test_replace_layout_in_config_text() {
+    let config_text = r##"# My config
+[flags]
+# Keep this comment.
+rate = 500
+
+[[row]]
+  [[row.child]]
+  type="cpu"
+# This comment is inside the old layout.
+[[row]]
+  [[row.child]]
+  type="proc"
+
+# Filters
+[disk_filter]
+is_list_ignored = true
+"##;
+    let layout_text = "[[row]]\n[[row.child]]\ntype = \"mem\"\n";
+
+    assert_eq!(
+        replace_layout_in_config_text(config_text, layout_text),
+        r##"# My config
+[flags]
+# Keep this comment.
+rate = 500
+
+[[row]]
+[[row.child]]
+type = "mem"
+
+# Filters
+[disk_filter]
+is_list_ignored = true
+"##
+    );
+
+    // A config without a layout gets it added to the end.
+    assert_eq!(
+        replace_layout_in_config_text("[flags]\nrate = 500\n", layout_text),
+        "[flags]\nrate = 500\n\n[[row]]\n[[row.child]]\ntype = \"mem\"\n"
+    );
+}